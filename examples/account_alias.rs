@@ -1,30 +1,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
 use hedera::{
     AccountBalanceQuery, AccountId, AccountInfoQuery, Client, Hbar, PrivateKey, TransferTransaction
 };
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
+    let operator_account_id = client.get_operator_account_id().unwrap();
 
     // Hiero supports a form of auto account creation.
     //
@@ -72,7 +56,7 @@ async fn main() -> anyhow::Result<()> {
 
     println!("Transferring some Hbar to the new account");
     let _ = TransferTransaction::new()
-        .hbar_transfer(args.operator_account_id, Hbar::new(-10))
+        .hbar_transfer(operator_account_id, Hbar::new(-10))
         .hbar_transfer(alias_account_id, Hbar::new(10))
         .execute(&client)
         .await?
@@ -1,22 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
 use hedera::{
     AccountAllowanceApproveTransaction, AccountBalanceQuery, AccountCreateTransaction, AccountDeleteTransaction, AccountId, Client, Hbar, PrivateKey, TransactionId, TransferTransaction
 };
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[derive(Clone, Debug)]
 struct Account {
     key: PrivateKey,
@@ -218,19 +205,15 @@ async fn clean_up(
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key);
+    let client = Client::from_env()?;
+    let operator_account_id = client.get_operator_account_id().unwrap();
 
     let accounts = create_accounts(&client).await?;
 
     print_balances(&client, &accounts).await?;
 
     demonstrate_allowances(&client, &accounts).await?;
-    clean_up(&client, args.operator_account_id, accounts).await?;
+    clean_up(&client, operator_account_id, accounts).await?;
 
     println!("End of example");
 
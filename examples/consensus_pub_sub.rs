@@ -7,20 +7,12 @@ use std::time::{Duration, Instant};
 use clap::Parser;
 // `use futures::TryStreamExt`, this is better practice though.
 use futures_util::TryStreamExt;
-use hedera::{
-    AccountId, Client, PrivateKey, TopicId, TopicMessageQuery, TopicMessageSubmitTransaction
-};
+use hedera::{Client, TopicId, TopicMessageQuery, TopicMessageSubmitTransaction};
 use parking_lot::RwLock;
 use tokio::time::sleep;
 
 #[derive(Parser, Debug)]
 struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
     #[clap(long, env, default_value = "0.0.34945875")]
     topic: TopicId,
 }
@@ -30,9 +22,7 @@ async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
     let args = Args::parse();
 
-    let client = Client::for_testnet();
-
-    client.set_operator(args.operator_account_id, args.operator_key);
+    let client = Client::from_env()?;
 
     let message_send_times = Arc::new(RwLock::new(HashMap::new()));
 
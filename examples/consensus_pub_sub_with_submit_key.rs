@@ -1,32 +1,14 @@
 use std::time::Duration;
 
-use clap::Parser;
 use futures_util::StreamExt;
 use hedera::{
-    AccountId, Client, PrivateKey, TopicCreateTransaction, TopicMessageQuery, TopicMessageSubmitTransaction
+    Client, PrivateKey, TopicCreateTransaction, TopicMessageQuery, TopicMessageSubmitTransaction
 };
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key);
+    let client = Client::from_env()?;
 
     // generate a submit key to use with the topic.
     let submit_key = PrivateKey::generate_ed25519();
@@ -1,26 +1,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use assert_matches::assert_matches;
-use clap::Parser;
-use hedera::{AccountCreateTransaction, AccountId, Client, PrivateKey};
-
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-}
+use hedera::{AccountCreateTransaction, Client, PrivateKey};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-    let args = Args::parse();
-
-    let client = Client::for_testnet();
-
-    client.set_operator(args.operator_account_id, args.operator_key);
+    let client = Client::from_env()?;
 
     let new_key = PrivateKey::generate_ed25519();
 
@@ -1,31 +1,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
 use hedera::{
     AccountBalanceQuery, AccountCreateTransaction, AccountId, Client, Hbar, Key, KeyList, PrivateKey, TransferTransaction
 };
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key);
+    let client = Client::from_env()?;
 
     // Generate three Ed25519::new private, public key pairs.
     // You do not need the private keys to create the Threshold Key List,
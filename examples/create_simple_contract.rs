@@ -2,39 +2,23 @@
 
 mod resources;
 
-use clap::Parser;
 use hedera::{
-    AccountId, Client, ContractCallQuery, ContractCreateTransaction, ContractDeleteTransaction, ContractFunctionParameters, FileCreateTransaction, PrivateKey
+    Client, ContractCallQuery, ContractCreateTransaction, ContractDeleteTransaction, ContractFunctionParameters, FileCreateTransaction
 };
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
+    let operator_account_id = client.get_operator_account_id().unwrap();
+    let operator_public_key = client.get_operator_public_key().unwrap();
 
     let bytecode = resources::simple_bytecode();
 
     // create the contract's bytecode file
     let file_transaction_response = FileCreateTransaction::new()
         // Use the same key as the operator to "own" this file
-        .keys([args.operator_key.public_key()])
+        .keys([operator_public_key])
         .contents(bytecode)
         .execute(&client)
         .await?;
@@ -47,7 +31,7 @@ async fn main() -> anyhow::Result<()> {
     let contract_transaction_response = ContractCreateTransaction::new()
         .bytecode_file_id(new_file_id)
         .gas(500_000)
-        .admin_key(args.operator_key.public_key())
+        .admin_key(operator_public_key)
         .constructor_parameters(
             ContractFunctionParameters::new()
                 .add_string("hello from hedera!")
@@ -78,7 +62,7 @@ async fn main() -> anyhow::Result<()> {
     // now delete the contract
     let _contract_delete_result = ContractDeleteTransaction::new()
         .contract_id(new_contract_id)
-        .transfer_account_id(args.operator_account_id)
+        .transfer_account_id(operator_account_id)
         .execute(&client)
         .await?
         .get_receipt(&client)
@@ -2,39 +2,22 @@
 
 mod resources;
 
-use clap::Parser;
 use hedera::{
-    AccountId, Client, ContractCallQuery, ContractCreateTransaction, ContractExecuteTransaction, ContractFunctionParameters, FileCreateTransaction, PrivateKey
+    Client, ContractCallQuery, ContractCreateTransaction, ContractExecuteTransaction, ContractFunctionParameters, FileCreateTransaction
 };
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
+    let operator_public_key = client.get_operator_public_key().unwrap();
 
     let bytecode = resources::stateful_bytecode();
 
     // create the contract's bytecode file
     let file_transaction_response = FileCreateTransaction::new()
         // Use the same key as the operator to "own" this file
-        .keys([args.operator_key.public_key()])
+        .keys([operator_public_key])
         .contents(bytecode)
         .execute(&client)
         .await?;
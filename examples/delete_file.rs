@@ -2,33 +2,17 @@
 
 use std::iter;
 
-use clap::Parser;
-use hedera::{AccountId, Client, FileCreateTransaction, FileDeleteTransaction, PrivateKey};
-
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
+use hedera::{Client, FileCreateTransaction, FileDeleteTransaction};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
+    let operator_public_key = client.get_operator_public_key().unwrap();
 
     let receipt = FileCreateTransaction::new()
         .contents(&b"Hiero is great!"[..])
-        .keys(iter::once(args.operator_key.public_key()))
+        .keys(iter::once(operator_public_key))
         .execute(&client)
         .await?
         .get_receipt(&client)
@@ -1,36 +1,18 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
-use hedera::{
-    AccountId, FileAppendTransaction, FileContentsQuery, FileCreateTransaction, Hbar, PrivateKey
-};
+use hedera::{FileAppendTransaction, FileContentsQuery, FileCreateTransaction, Hbar};
 
 mod resources;
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv().ok();
 
-    let args = Args::parse();
-
-    let client = hedera::Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
+    let operator_public_key = client.get_operator_public_key().unwrap();
 
     let response = FileCreateTransaction::new()
-        .keys([args.operator_key.public_key()])
+        .keys([operator_public_key])
         .contents("[sdk::rust::example::file_append_chunked]\n\n")
         .max_transaction_fee(Hbar::new(2))
         .execute(&client)
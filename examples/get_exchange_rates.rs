@@ -1,19 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use clap::Parser;
-use hedera::{AccountId, Client, ExchangeRates, FileContentsQuery, FileId, PrivateKey};
+use hedera::{Client, ExchangeRates, FileContentsQuery, FileId};
 
 #[derive(Parser, Debug)]
 struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-
     #[clap(long, env, default_value_t = FileId::EXCHANGE_RATES)]
     hedera_exchange_rates_file: FileId,
 }
@@ -23,9 +14,7 @@ async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
     let args = Args::parse();
 
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
 
     let response = FileContentsQuery::new()
         .file_id(args.hedera_exchange_rates_file)
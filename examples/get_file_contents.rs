@@ -1,16 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use clap::Parser;
-use hedera::{AccountId, Client, FileContentsQuery, FileId, PrivateKey};
+use hedera::{Client, FileContentsQuery, FileId};
 
 #[derive(Parser, Debug)]
 struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
     #[clap(long, env, default_value = "0.0.34945328")]
     file: FileId,
 }
@@ -20,9 +14,7 @@ async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
     let args = Args::parse();
 
-    let client = Client::for_testnet();
-
-    client.set_operator(args.operator_account_id, args.operator_key);
+    let client = Client::from_env()?;
 
     let cr = FileContentsQuery::new()
         .file_id(args.file)
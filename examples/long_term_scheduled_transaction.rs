@@ -1,35 +1,20 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
 use hedera::{
-    AccountCreateTransaction, AccountId, AccountInfoQuery, AccountUpdateTransaction, Client, Hbar, Key, KeyList, PrivateKey, ScheduleInfoQuery, ScheduleSignTransaction, TransferTransaction
+    AccountCreateTransaction, AccountInfoQuery, AccountUpdateTransaction, Client, Hbar, Key, KeyList, PrivateKey, ScheduleInfoQuery, ScheduleSignTransaction, TransferTransaction
 };
 use time::{Duration, OffsetDateTime};
 use tokio::time::sleep;
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
 
-    let args = Args::parse();
-
     /*
      * Step 0: Create and configure the client
      */
-    let client = Client::for_name(&args.hedera_network)?;
-    client.set_operator(args.operator_account_id, args.operator_key);
+    let client = Client::from_env()?;
+    let operator_account_id = client.get_operator_account_id().unwrap();
 
     /*
      * Step 1: Create key pairs
@@ -71,7 +56,7 @@ async fn main() -> anyhow::Result<()> {
     let mut transfer = TransferTransaction::new();
     transfer
         .hbar_transfer(alice_id, Hbar::new(-1))
-        .hbar_transfer(args.operator_account_id, Hbar::new(1));
+        .hbar_transfer(operator_account_id, Hbar::new(1));
 
     let schedule_id = transfer
         .schedule()
@@ -153,7 +138,7 @@ async fn main() -> anyhow::Result<()> {
     let mut transfer = TransferTransaction::new();
     transfer
         .hbar_transfer(alice_id, Hbar::new(-1))
-        .hbar_transfer(args.operator_account_id, Hbar::new(1));
+        .hbar_transfer(operator_account_id, Hbar::new(1));
 
     let schedule_id = transfer
         .schedule()
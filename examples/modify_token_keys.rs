@@ -1,31 +1,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
 use hedera::{
-    AccountId, Client, KeyList, PrivateKey, PublicKey, TokenCreateTransaction, TokenInfoQuery, TokenKeyValidation, TokenUpdateTransaction
+    Client, KeyList, PrivateKey, PublicKey, TokenCreateTransaction, TokenInfoQuery, TokenKeyValidation, TokenUpdateTransaction
 };
 use time::{Duration, OffsetDateTime};
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_id, args.operator_key.clone());
+    let client = Client::from_env()?;
 
     // Generate a higher-privileged key.
     let admin_key = PrivateKey::generate_ed25519();
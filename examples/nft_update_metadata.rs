@@ -1,32 +1,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
 use hedera::{
-    AccountCreateTransaction, AccountId, Client, Hbar, NftId, PrivateKey, TokenCreateTransaction, TokenInfoQuery, TokenMintTransaction, TokenNftInfoQuery, TokenType, TokenUpdateNftsTransaction, TransferTransaction
+    AccountCreateTransaction, Client, Hbar, NftId, PrivateKey, TokenCreateTransaction, TokenInfoQuery, TokenMintTransaction, TokenNftInfoQuery, TokenType, TokenUpdateNftsTransaction, TransferTransaction
 };
 use time::{Duration, OffsetDateTime};
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
 
     // Generate a supply key
     let supply_key = PrivateKey::generate_ed25519();
@@ -45,7 +27,6 @@ async fn main() -> anyhow::Result<()> {
         .supply_key(client.get_operator_public_key().unwrap())
         .metadata_key(metadata_key.public_key())
         .expiration_time(OffsetDateTime::now_utc() + Duration::minutes(5))
-        .sign(args.operator_key.clone())
         .execute(&client)
         .await?
         .get_receipt(&client)
@@ -97,7 +78,6 @@ async fn main() -> anyhow::Result<()> {
         .max_automatic_token_associations(10)
         .initial_balance(Hbar::new(100))
         .freeze_with(&client)?
-        .sign(args.operator_key.clone())
         .execute(&client)
         .await?
         .get_receipt(&client)
@@ -114,7 +94,6 @@ async fn main() -> anyhow::Result<()> {
             account_id,
         )
         .freeze_with(&client)?
-        .sign(args.operator_key.clone())
         .execute(&client)
         .await?;
 
@@ -1,31 +1,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
 use hedera::{
-    AccountCreateTransaction, AccountId, Client, Hbar, KeyList, PrivateKey, ScheduleInfoQuery, ScheduleSignTransaction, TransferTransaction
+    AccountCreateTransaction, Client, Hbar, KeyList, PrivateKey, ScheduleInfoQuery, ScheduleSignTransaction, TransferTransaction
 };
 use time::OffsetDateTime;
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
+    let operator_account_id = client.get_operator_account_id().unwrap();
 
     // Generate a Ed25519 private, public key pair
     let key1 = PrivateKey::generate_ed25519();
@@ -51,7 +35,7 @@ async fn main() -> anyhow::Result<()> {
     let mut tx = TransferTransaction::new();
 
     tx.hbar_transfer(new_account_id, -Hbar::new(1))
-        .hbar_transfer(args.operator_account_id, Hbar::new(1));
+        .hbar_transfer(operator_account_id, Hbar::new(1));
 
     let response = tx
         .schedule()
@@ -98,8 +82,8 @@ async fn main() -> anyhow::Result<()> {
     println!("The following link should query the mirror node for the scheduled transaction:");
 
     println!(
-        "https://{}.mirrornode.hedera.com/api/v1/transactions/{}",
-        args.hedera_network,
+        "https://{}/api/v1/transactions/{}",
+        client.mirror_network()[0].trim_end_matches(":443"),
         format_args!(
             "{}-{}-{}",
             transaction_id.account_id,
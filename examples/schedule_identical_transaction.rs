@@ -1,30 +1,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
 use hedera::{
-    AccountCreateTransaction, AccountDeleteTransaction, AccountId, Client, Hbar, Key, KeyList, PrivateKey, ScheduleCreateTransaction, ScheduleId, ScheduleInfoQuery, ScheduleSignTransaction, Status, TransactionReceiptQuery, TransferTransaction
+    AccountCreateTransaction, AccountDeleteTransaction, Client, Hbar, Key, KeyList, PrivateKey, ScheduleCreateTransaction, ScheduleId, ScheduleInfoQuery, ScheduleSignTransaction, Status, TransactionReceiptQuery, TransferTransaction
 };
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
+    let operator_account_id = client.get_operator_account_id().unwrap();
 
     println!("threshold key example");
     println!("keys:");
@@ -52,7 +36,7 @@ async fn main() -> anyhow::Result<()> {
 
         let account_id = receipt.account_id.unwrap();
 
-        let client = Client::for_name(&args.hedera_network)?;
+        let client = Client::from_env()?;
 
         client.set_operator(account_id, private_key.clone());
 
@@ -163,7 +147,7 @@ async fn main() -> anyhow::Result<()> {
 
     threshold_delete_tx
         .account_id(threshold_account)
-        .transfer_account_id(args.operator_account_id)
+        .transfer_account_id(operator_account_id)
         .freeze_with(&client)?;
 
     for (key, account) in private_keys.into_iter().zip(accounts) {
@@ -171,7 +155,7 @@ async fn main() -> anyhow::Result<()> {
 
         AccountDeleteTransaction::new()
             .account_id(account)
-            .transfer_account_id(args.operator_account_id)
+            .transfer_account_id(operator_account_id)
             .freeze_with(&client)?
             .sign(key)
             .execute(&client)
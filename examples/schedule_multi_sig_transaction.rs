@@ -1,30 +1,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
 use hedera::{
     AccountCreateTransaction, AccountId, Client, Hbar, KeyList, PrivateKey, ScheduleInfoQuery, ScheduleSignTransaction, TransactionId, TransferTransaction
 };
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
+    let operator_account_id = client.get_operator_account_id().unwrap();
+    let operator_public_key = client.get_operator_public_key().unwrap();
 
     // Generate 3 random keys
     let key1 = PrivateKey::generate_ed25519();
@@ -62,7 +47,7 @@ async fn main() -> anyhow::Result<()> {
 
     // Generate a `TransactionId`. This id is used to query the inner scheduled transaction
     // after we expect it to have been executed
-    let transaction_id = TransactionId::generate(args.operator_account_id);
+    let transaction_id = TransactionId::generate(operator_account_id);
 
     println!("transaction_id for scheduled transaction = {transaction_id}");
 
@@ -71,13 +56,13 @@ async fn main() -> anyhow::Result<()> {
 
     transfer
         .hbar_transfer(account_id, -Hbar::new(1))
-        .hbar_transfer(args.operator_account_id, Hbar::new(1));
+        .hbar_transfer(operator_account_id, Hbar::new(1));
 
     // Schedule the transaction
     let receipt = transfer
         .schedule()
-        .payer_account_id(args.operator_account_id)
-        .admin_key(args.operator_key.public_key())
+        .payer_account_id(operator_account_id)
+        .admin_key(operator_public_key)
         .freeze_with(&client)?
         .sign(key2.clone())
         .execute(&client)
@@ -110,7 +95,7 @@ async fn main() -> anyhow::Result<()> {
     anyhow::ensure!(transfers.len() == 2, "more transfers than expected");
 
     anyhow::ensure!(transfers[&account_id] == -Hbar::new(1));
-    anyhow::ensure!(transfers[&args.operator_account_id] == Hbar::new(1));
+    anyhow::ensure!(transfers[&operator_account_id] == Hbar::new(1));
 
     println!("sending schedule sign transaction");
 
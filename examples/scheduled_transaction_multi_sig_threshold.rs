@@ -1,30 +1,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
 use hedera::{
-    AccountBalanceQuery, AccountCreateTransaction, AccountId, Client, Hbar, Key, KeyList, PrivateKey, ScheduleInfoQuery, ScheduleSignTransaction, TransactionRecordQuery, TransferTransaction
+    AccountBalanceQuery, AccountCreateTransaction, Client, Hbar, Key, KeyList, PrivateKey, ScheduleInfoQuery, ScheduleSignTransaction, TransactionRecordQuery, TransferTransaction
 };
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
+    let operator_account_id = client.get_operator_account_id().unwrap();
     // Generate four new Ed25519 private, public key pairs.
 
     let private_keys: [_; 4] = std::array::from_fn(|_| PrivateKey::generate_ed25519());
@@ -71,7 +55,7 @@ async fn main() -> anyhow::Result<()> {
     let mut transfer_transaction = TransferTransaction::new();
     transfer_transaction
         .hbar_transfer(multi_sig_account_id, Hbar::from_tinybars(-1))
-        .hbar_transfer(args.operator_account_id, Hbar::from_tinybars(1));
+        .hbar_transfer(operator_account_id, Hbar::from_tinybars(1));
 
     let tx_schedule_receipt = transfer_transaction
         .schedule()
@@ -1,33 +1,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
 use hedera::{
-    AccountBalanceQuery, AccountCreateTransaction, AccountDeleteTransaction, AccountId, Client, Hbar, PrivateKey, ScheduleCreateTransaction, ScheduleInfoQuery, ScheduleSignTransaction, TransferTransaction
+    AccountBalanceQuery, AccountCreateTransaction, AccountDeleteTransaction, Client, Hbar, PrivateKey, ScheduleCreateTransaction, ScheduleInfoQuery, ScheduleSignTransaction, TransferTransaction
 };
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
 
     env_logger::init();
 
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
+    let operator_account_id = client.get_operator_account_id().unwrap();
 
     // A scheduled transaction is a transaction that has been proposed by an account,
     // but which requires more signatures before it will actually execute on the Hiero network.
@@ -69,7 +53,7 @@ async fn main() -> anyhow::Result<()> {
 
     println!(
         "Alice's ID: {}",
-        args.operator_account_id.to_string_with_checksum(&client)?
+        operator_account_id.to_string_with_checksum(&client)?
     );
     println!("Bob's ID: {}", bobs_id.to_string_with_checksum(&client)?);
 
@@ -84,7 +68,7 @@ async fn main() -> anyhow::Result<()> {
     let mut transfer_to_schedule = TransferTransaction::new();
 
     transfer_to_schedule
-        .hbar_transfer(args.operator_account_id, Hbar::new(-10))
+        .hbar_transfer(operator_account_id, Hbar::new(-10))
         .hbar_transfer(bobs_id, Hbar::new(10));
 
     println!("Transfer to be scheduled:");
@@ -176,7 +160,7 @@ async fn main() -> anyhow::Result<()> {
 
     // Clean up
     AccountDeleteTransaction::new()
-        .transfer_account_id(args.operator_account_id)
+        .transfer_account_id(operator_account_id)
         .account_id(bobs_id)
         .freeze_with(&client)?
         .sign(bobs_key)
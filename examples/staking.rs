@@ -1,28 +1,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
-use hedera::{AccountCreateTransaction, AccountId, AccountInfoQuery, Client, Hbar, PrivateKey};
-
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
+use hedera::{AccountCreateTransaction, AccountInfoQuery, Client, Hbar, PrivateKey};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-    let args = Args::parse();
-
-    let client = Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
+    let operator_public_key = client.get_operator_public_key().unwrap();
 
     // Create Alice account
     let new_key = PrivateKey::generate_ed25519();
@@ -56,10 +40,7 @@ async fn main() -> anyhow::Result<()> {
         new_key.public_key()
     );
 
-    println!(
-        "fee payer aka operator key: {}",
-        args.operator_key.public_key()
-    );
+    println!("fee payer aka operator key: {}", operator_public_key);
 
     // Query the account info, it should show the staked account ID
     // to be 0.0.3 just like what we set it to
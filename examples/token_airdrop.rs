@@ -2,36 +2,17 @@
 
 use std::iter::repeat;
 
-use clap::Parser;
 use hedera::{
-    AccountBalanceQuery, AccountCreateTransaction, AccountId, Client, Hbar, PrivateKey, TokenAirdropTransaction, TokenCancelAirdropTransaction, TokenClaimAirdropTransaction, TokenCreateTransaction, TokenMintTransaction, TokenRejectTransaction
+    AccountBalanceQuery, AccountCreateTransaction, Client, Hbar, PrivateKey, TokenAirdropTransaction, TokenCancelAirdropTransaction, TokenClaimAirdropTransaction, TokenCreateTransaction, TokenMintTransaction, TokenRejectTransaction
 };
 use time::{Duration, OffsetDateTime};
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-    let Args {
-        operator_account_id,
-        operator_key,
-        hedera_network,
-    } = Args::parse();
-
-    let client = Client::for_name(&hedera_network)?;
+    let client = Client::from_env()?;
+    let operator_public_key = client.get_operator_public_key().unwrap();
 
-    client.set_operator(operator_account_id, operator_key.clone());
     let private_key_1 = PrivateKey::generate_ecdsa();
     let alice_id = AccountCreateTransaction::new()
         .key(private_key_1.public_key())
@@ -89,10 +70,10 @@ async fn main() -> anyhow::Result<()> {
         .max_supply(100)
         .treasury_account_id(treasury_account_id)
         .token_supply_type(hedera::TokenSupplyType::Finite)
-        .admin_key(operator_key.clone().public_key())
-        .freeze_key(operator_key.clone().public_key())
-        .supply_key(operator_key.clone().public_key())
-        .pause_key(operator_key.clone().public_key())
+        .admin_key(operator_public_key.clone())
+        .freeze_key(operator_public_key.clone())
+        .supply_key(operator_public_key.clone())
+        .pause_key(operator_public_key.clone())
         .expiration_time(OffsetDateTime::now_utc() + Duration::hours(2))
         .freeze_with(&client)?
         .sign(treasury_key.clone())
@@ -110,10 +91,10 @@ async fn main() -> anyhow::Result<()> {
         .treasury_account_id(treasury_account_id)
         .token_supply_type(hedera::TokenSupplyType::Finite)
         .token_type(hedera::TokenType::NonFungibleUnique)
-        .admin_key(operator_key.clone().public_key())
-        .freeze_key(operator_key.clone().public_key())
-        .supply_key(operator_key.clone().public_key())
-        .pause_key(operator_key.clone().public_key())
+        .admin_key(operator_public_key.clone())
+        .freeze_key(operator_public_key.clone())
+        .supply_key(operator_public_key.clone())
+        .pause_key(operator_public_key.clone())
         .expiration_time(OffsetDateTime::now_utc() + Duration::hours(2))
         .freeze_with(&client)?
         .sign(treasury_key.clone())
@@ -1,16 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use clap::Parser;
-use hedera::{AccountId, Client, Hbar, PrivateKey, TransferTransaction};
+use hedera::{AccountId, Client, Hbar, TransferTransaction};
 
 #[derive(Parser, Debug)]
 struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
     #[clap(long)]
     sender: Option<AccountId>,
 
@@ -26,11 +20,10 @@ async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
     let args = Args::parse();
 
-    let client = Client::for_testnet();
-
-    client.set_operator(args.operator_account_id, args.operator_key);
+    let client = Client::from_env()?;
+    let operator_account_id = client.get_operator_account_id().unwrap();
 
-    let sender = args.sender.unwrap_or(args.operator_account_id);
+    let sender = args.sender.unwrap_or(operator_account_id);
 
     let _ = TransferTransaction::new()
         .hbar_transfer(sender, -args.amount)
@@ -1,35 +1,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::Parser;
 use hedera::{
     AccountCreateTransaction, AccountDeleteTransaction, AccountId, Client, Hbar, PrivateKey, TokenAssociateTransaction, TokenCreateTransaction, TokenDeleteTransaction, TokenGrantKycTransaction, TokenWipeTransaction, TransferTransaction
 };
 use time::{Duration, OffsetDateTime};
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv();
-    let Args {
-        operator_account_id,
-        operator_key,
-        hedera_network,
-    } = Args::parse();
-
-    let client = Client::for_name(&hedera_network)?;
-
-    client.set_operator(operator_account_id, operator_key.clone());
+    let client = Client::from_env()?;
+    let operator_account_id = client.get_operator_account_id().unwrap();
+    let operator_public_key = client.get_operator_public_key().unwrap();
     let (private_key1, account_id1) = create_account(&client, 1).await?;
     let (private_key2, account_id2) = create_account(&client, 2).await?;
 
@@ -39,11 +20,11 @@ async fn main() -> anyhow::Result<()> {
         .decimals(3)
         .initial_supply(1_000_000)
         .treasury_account_id(operator_account_id)
-        .admin_key(operator_key.clone().public_key())
-        .freeze_key(operator_key.clone().public_key())
-        .wipe_key(operator_key.clone().public_key())
-        .kyc_key(operator_key.clone().public_key())
-        .supply_key(operator_key.clone().public_key())
+        .admin_key(operator_public_key.clone())
+        .freeze_key(operator_public_key.clone())
+        .wipe_key(operator_public_key.clone())
+        .kyc_key(operator_public_key.clone())
+        .supply_key(operator_public_key.clone())
         .expiration_time(OffsetDateTime::now_utc() + Duration::hours(2))
         .freeze_default(false)
         .execute(&client)
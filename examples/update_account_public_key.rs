@@ -1,29 +1,12 @@
-use clap::Parser;
 use hedera::{
-    AccountCreateTransaction, AccountId, AccountInfoQuery, AccountUpdateTransaction, Hbar, PrivateKey
+    AccountCreateTransaction, AccountInfoQuery, AccountUpdateTransaction, Hbar, PrivateKey
 };
 
-#[derive(Parser, Debug)]
-struct Args {
-    #[clap(long, env)]
-    operator_account_id: AccountId,
-
-    #[clap(long, env)]
-    operator_key: PrivateKey,
-
-    #[clap(long, env, default_value = "testnet")]
-    hedera_network: String,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let _ = dotenvy::dotenv().ok();
 
-    let args = Args::parse();
-
-    let client = hedera::Client::for_name(&args.hedera_network)?;
-
-    client.set_operator(args.operator_account_id, args.operator_key.clone());
+    let client = Client::from_env()?;
 
     // First, we create a new account so we don't affect our account
 
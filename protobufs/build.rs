@@ -253,7 +253,7 @@ fn main() -> anyhow::Result<()> {
         .services_same("VirtualAddress");
 
     cfg.out_dir(&sdk_out_dir).compile_protos(
-        &["./sdk/transaction_list.proto"],
+        &["./sdk/transaction_list.proto", "./sdk/transaction_bundle.proto"],
         &["./sdk/", services_tmp_path.as_os_str().to_str().unwrap()],
     )?;
 
@@ -20,6 +20,7 @@ use crate::{
     Error,
     Hbar,
     NftId,
+    RequiredSigner,
     ToProtobuf,
     TokenId,
     Transaction,
@@ -202,7 +203,15 @@ pub struct NftAllowance {
     pub delegating_spender_account_id: Option<AccountId>,
 }
 
-impl TransactionData for AccountAllowanceApproveTransactionData {}
+impl TransactionData for AccountAllowanceApproveTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        let hbar_owners = self.hbar_allowances.iter().map(|it| it.owner_account_id);
+        let token_owners = self.token_allowances.iter().map(|it| it.owner_account_id);
+        let nft_owners = self.nft_allowances.iter().map(|it| it.owner_account_id);
+
+        hbar_owners.chain(token_owners).chain(nft_owners).map(RequiredSigner::Account).collect()
+    }
+}
 
 impl TransactionExecute for AccountAllowanceApproveTransactionData {
     fn execute(
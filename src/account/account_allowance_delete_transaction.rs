@@ -22,6 +22,7 @@ use crate::{
     BoxGrpcFuture,
     Error,
     NftId,
+    RequiredSigner,
     TokenId,
     Transaction,
     ValidateChecksums,
@@ -82,7 +83,11 @@ impl AccountAllowanceDeleteTransaction {
     }
 }
 
-impl TransactionData for AccountAllowanceDeleteTransactionData {}
+impl TransactionData for AccountAllowanceDeleteTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.nft_allowances.iter().map(|it| RequiredSigner::Account(it.owner_account_id)).collect()
+    }
+}
 
 impl TransactionExecute for AccountAllowanceDeleteTransactionData {
     fn execute(
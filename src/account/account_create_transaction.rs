@@ -26,6 +26,7 @@ use crate::{
     EvmAddress,
     Hbar,
     Key,
+    RequiredSigner,
     Transaction,
     ValidateChecksums,
 };
@@ -255,7 +256,17 @@ impl AccountCreateTransaction {
     }
 }
 
-impl TransactionData for AccountCreateTransactionData {}
+impl TransactionData for AccountCreateTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        // the new key only has to sign if it'll have to sign for transfers into the account too.
+        match &self.key {
+            Some(key) if self.receiver_signature_required => {
+                Vec::from([RequiredSigner::Key(key.clone())])
+            }
+            _ => Vec::new(),
+        }
+    }
+}
 
 impl TransactionExecute for AccountCreateTransactionData {
     fn execute(
@@ -21,6 +21,7 @@ use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     Transaction,
     ValidateChecksums,
 };
@@ -67,7 +68,11 @@ impl AccountDeleteTransaction {
     }
 }
 
-impl TransactionData for AccountDeleteTransactionData {}
+impl TransactionData for AccountDeleteTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.account_id.map(RequiredSigner::Account).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for AccountDeleteTransactionData {
     fn execute(
@@ -27,6 +27,7 @@ use crate::{
     BoxGrpcFuture,
     Error,
     Key,
+    RequiredSigner,
     Transaction,
     ValidateChecksums,
 };
@@ -273,7 +274,15 @@ impl AccountUpdateTransaction {
     }
 }
 
-impl TransactionData for AccountUpdateTransactionData {}
+impl TransactionData for AccountUpdateTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        // both the old and the new key have to sign a key change.
+        let account = self.account_id.map(RequiredSigner::Account);
+        let key = self.key.clone().map(RequiredSigner::Key);
+
+        account.into_iter().chain(key).collect()
+    }
+}
 
 impl TransactionExecute for AccountUpdateTransactionData {
     fn execute(
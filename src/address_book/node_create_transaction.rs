@@ -22,6 +22,7 @@ use crate::{
     BoxGrpcFuture,
     Error,
     Key,
+    RequiredSigner,
     ToProtobuf,
     Transaction,
     ValidateChecksums,
@@ -171,7 +172,11 @@ impl NodeCreateTransaction {
     }
 }
 
-impl TransactionData for NodeCreateTransactionData {}
+impl TransactionData for NodeCreateTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.admin_key.clone().map(RequiredSigner::Key).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for NodeCreateTransactionData {
     fn execute(
@@ -22,6 +22,7 @@ use crate::{
     BoxGrpcFuture,
     Error,
     Key,
+    RequiredSigner,
     ToProtobuf,
     Transaction,
     ValidateChecksums,
@@ -197,7 +198,11 @@ impl NodeUpdateTransaction {
     }
 }
 
-impl TransactionData for NodeUpdateTransactionData {}
+impl TransactionData for NodeUpdateTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.admin_key.clone().map(RequiredSigner::Key).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for NodeUpdateTransactionData {
     fn execute(
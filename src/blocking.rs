@@ -0,0 +1,632 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A blocking (synchronous) API, for code that doesn't run on an async runtime.
+//!
+//! The blocking [`Client`] manages its own runtime, and every request made with it blocks the calling thread until it completes.
+//! Requests either go through the client, or through the `_blocking` methods of the [`Execute`], [`GetCost`] and [`GetReceipt`] traits
+//! (their own `execute` methods are `async`):
+//!
+//! ```no_run
+//! use hedera::blocking::{
+//!     Client,
+//!     Execute,
+//!     GetReceipt,
+//! };
+//! use hedera::{
+//!     AccountBalanceQuery,
+//!     AccountId,
+//!     Hbar,
+//!     PrivateKey,
+//!     TopicId,
+//!     TopicMessageQuery,
+//!     TransferTransaction,
+//! };
+//!
+//! # fn main() -> hedera::Result<()> {
+//! let client = Client::for_testnet();
+//! client.set_operator(AccountId::new(0, 0, 1001), PrivateKey::generate_ed25519());
+//!
+//! let response = TransferTransaction::new()
+//!     .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(-1))
+//!     .hbar_transfer(AccountId::new(0, 0, 3), Hbar::new(1))
+//!     .execute_blocking(&client)?;
+//!
+//! let receipt = response.get_receipt_blocking(&client)?;
+//! println!("transfer: {:?}", receipt.status);
+//!
+//! // or, the same thing through the client.
+//! let balance = client.execute(AccountBalanceQuery::new().account_id(AccountId::new(0, 0, 1001)))?;
+//! println!("balance: {}", balance.hbars);
+//!
+//! for message in client.subscribe(TopicMessageQuery::new().topic_id(TopicId::new(0, 0, 1002))) {
+//!     println!("message: {:?}", message?.contents);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Panics
+//! Everything in this module panics when called from within an async runtime, use the async API there instead.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::time::Duration;
+
+use futures_core::future::LocalBoxFuture;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use tokio::runtime::Runtime;
+use triomphe::Arc;
+
+use crate::mirror_query::MirrorQueryExecute;
+use crate::query::QueryExecute;
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    ContractCreateFlow,
+    EthereumFlow,
+    Hbar,
+    MirrorQuery,
+    Query,
+    TokenRejectFlow,
+    Transaction,
+    TransactionReceipt,
+    TransactionRecord,
+    TransactionResponse,
+};
+
+/// A blocking client for the Hiero network, see the [module level docs](self) for an example.
+///
+/// This derefs to the async [`Client`](crate::Client), for all of its configuration
+/// (the operator, the network, timeouts and so on), but requests should be made with the blocking methods here.
+#[derive(Clone, Debug)]
+pub struct Client {
+    // note: the client comes first, so that it gets dropped before the runtime it runs on.
+    inner: crate::Client,
+    runtime: Arc<Runtime>,
+}
+
+impl Client {
+    /// Runs `f` on a new runtime, which every request made with the client it builds runs on too.
+    fn build<T>(f: impl Future<Output = T>) -> (T, Arc<Runtime>) {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .thread_name("hedera-blocking")
+            .enable_all()
+            .build()
+            .expect("failed to start a runtime for the blocking client");
+
+        (runtime.block_on(f), Arc::new(runtime))
+    }
+
+    /// Construct a client pre-configured for mainnet access.
+    ///
+    /// See [`Client::for_mainnet`](crate::Client::for_mainnet).
+    #[must_use]
+    pub fn for_mainnet() -> Self {
+        let (inner, runtime) = Self::build(async { crate::Client::for_mainnet() });
+
+        Self { inner, runtime }
+    }
+
+    /// Construct a client pre-configured for testnet access.
+    ///
+    /// See [`Client::for_testnet`](crate::Client::for_testnet).
+    #[must_use]
+    pub fn for_testnet() -> Self {
+        let (inner, runtime) = Self::build(async { crate::Client::for_testnet() });
+
+        Self { inner, runtime }
+    }
+
+    /// Construct a client pre-configured for previewnet access.
+    ///
+    /// See [`Client::for_previewnet`](crate::Client::for_previewnet).
+    #[must_use]
+    pub fn for_previewnet() -> Self {
+        let (inner, runtime) = Self::build(async { crate::Client::for_previewnet() });
+
+        Self { inner, runtime }
+    }
+
+    /// Construct a client pre-configured for access to the given network.
+    ///
+    /// See [`Client::for_name`](crate::Client::for_name).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if the network name is not a supported network name.
+    pub fn for_name(name: &str) -> crate::Result<Self> {
+        let (inner, runtime) = Self::build(async { crate::Client::for_name(name) });
+
+        Ok(Self { inner: inner?, runtime })
+    }
+
+    /// Construct a client from a map of node addresses to their account IDs.
+    ///
+    /// See [`Client::for_network`](crate::Client::for_network).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if any of the addresses are malformed.
+    pub fn for_network(network: HashMap<String, AccountId>) -> crate::Result<Self> {
+        let (inner, runtime) = Self::build(async { crate::Client::for_network(network) });
+
+        Ok(Self { inner: inner?, runtime })
+    }
+
+    /// Construct a client from a mirror network, fetching the consensus network from its address book.
+    ///
+    /// See [`Client::for_mirror_network`](crate::Client::for_mirror_network).
+    ///
+    /// # Errors
+    /// - If fetching the address book fails.
+    pub fn for_mirror_network(mirror_network: Vec<String>) -> crate::Result<Self> {
+        let (inner, runtime) = Self::build(crate::Client::for_mirror_network(mirror_network));
+
+        Ok(Self { inner: inner?, runtime })
+    }
+
+    /// Create a client from the given json config.
+    ///
+    /// See [`Client::from_config`](crate::Client::from_config).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if an error occurs parsing the configuration.
+    #[cfg(feature = "serde")]
+    pub fn from_config(json: &str) -> crate::Result<Self> {
+        let (inner, runtime) = Self::build(async { crate::Client::from_config(json) });
+
+        Ok(Self { inner: inner?, runtime })
+    }
+
+    /// Create a client from environment variables.
+    ///
+    /// See [`Client::from_env`](crate::Client::from_env).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if a required variable is missing, or if any variable is malformed.
+    pub fn from_env() -> crate::Result<Self> {
+        Self::from_env_with_prefix("")
+    }
+
+    /// Create a client from environment variables that all start with `prefix`.
+    ///
+    /// See [`Client::from_env_with_prefix`](crate::Client::from_env_with_prefix).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if a required variable is missing, or if any variable is malformed.
+    pub fn from_env_with_prefix(prefix: &str) -> crate::Result<Self> {
+        let (inner, runtime) = Self::build(async { crate::Client::from_env_with_prefix(prefix) });
+
+        Ok(Self { inner: inner?, runtime })
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Execute a transaction, query, flow or mirror node query, blocking until it completes.
+    ///
+    /// # Errors
+    /// - Whatever the request's own `execute` would return.
+    pub fn execute<E: Execute>(&self, request: &mut E) -> crate::Result<E::Response> {
+        self.block_on(request.execute_async(&self.inner, None))
+    }
+
+    /// Execute a transaction, query, flow or mirror node query, blocking until it completes or `timeout` passes.
+    ///
+    /// # Errors
+    /// - Whatever the request's own `execute_with_timeout` would return.
+    pub fn execute_with_timeout<E: Execute>(
+        &self,
+        request: &mut E,
+        timeout: Duration,
+    ) -> crate::Result<E::Response> {
+        self.block_on(request.execute_async(&self.inner, Some(timeout)))
+    }
+
+    /// Fetch the cost of a transaction or query.
+    ///
+    /// # Errors
+    /// - Whatever the request's own `get_cost` would return.
+    pub fn get_cost<C: GetCost>(&self, request: &C) -> crate::Result<Hbar> {
+        self.block_on(request.get_cost_async(&self.inner))
+    }
+
+    /// Get the receipt for a transaction, waiting for consensus.
+    ///
+    /// # Errors
+    /// - See [`TransactionResponse::get_receipt`].
+    pub fn get_receipt(&self, response: &TransactionResponse) -> crate::Result<TransactionReceipt> {
+        self.block_on(response.get_receipt(&self.inner))
+    }
+
+    /// Get the receipt for a transaction, waiting for consensus or until `timeout` passes.
+    ///
+    /// # Errors
+    /// - See [`TransactionResponse::get_receipt_with_timeout`].
+    pub fn get_receipt_with_timeout(
+        &self,
+        response: &TransactionResponse,
+        timeout: Duration,
+    ) -> crate::Result<TransactionReceipt> {
+        self.block_on(response.get_receipt_with_timeout(&self.inner, timeout))
+    }
+
+    /// Get the record for a transaction, waiting for consensus.
+    ///
+    /// # Errors
+    /// - See [`TransactionResponse::get_record`].
+    pub fn get_record(&self, response: &TransactionResponse) -> crate::Result<TransactionRecord> {
+        self.block_on(response.get_record(&self.inner))
+    }
+
+    /// Get the record for a transaction, waiting for consensus or until `timeout` passes.
+    ///
+    /// # Errors
+    /// - See [`TransactionResponse::get_record_with_timeout`].
+    pub fn get_record_with_timeout(
+        &self,
+        response: &TransactionResponse,
+        timeout: Duration,
+    ) -> crate::Result<TransactionRecord> {
+        self.block_on(response.get_record_with_timeout(&self.inner, timeout))
+    }
+
+    /// Subscribe to a mirror node query, such as a [`TopicMessageQuery`](crate::TopicMessageQuery).
+    ///
+    /// Each call to [`next`](Iterator::next) blocks until the next item arrives.
+    pub fn subscribe<'a, D>(&'a self, query: &MirrorQuery<D>) -> Subscription<'a, D::Item>
+    where
+        D: MirrorQueryExecute + 'a,
+    {
+        Subscription { runtime: &self.runtime, stream: Box::pin(query.subscribe(&self.inner)) }
+    }
+
+    /// Subscribe to a mirror node query, such as a [`TopicMessageQuery`](crate::TopicMessageQuery).
+    ///
+    /// Note that `timeout` is the connection timeout.
+    pub fn subscribe_with_timeout<'a, D>(
+        &'a self,
+        query: &MirrorQuery<D>,
+        timeout: Duration,
+    ) -> Subscription<'a, D::Item>
+    where
+        D: MirrorQueryExecute + 'a,
+    {
+        Subscription {
+            runtime: &self.runtime,
+            stream: Box::pin(query.subscribe_with_timeout(&self.inner, timeout)),
+        }
+    }
+
+    /// Send a ping to the given node.
+    ///
+    /// # Errors
+    /// - See [`Client::ping`](crate::Client::ping).
+    pub fn ping(&self, node_account_id: AccountId) -> crate::Result<()> {
+        self.block_on(self.inner.ping(node_account_id))
+    }
+
+    /// Send a ping to all nodes.
+    ///
+    /// # Errors
+    /// - See [`Client::ping_all`](crate::Client::ping_all).
+    pub fn ping_all(&self) -> crate::Result<()> {
+        self.block_on(self.inner.ping_all())
+    }
+
+    /// Closes this client, blocking until every request that's in flight has been cancelled.
+    ///
+    /// See [`Client::close`](crate::Client::close).
+    pub fn close(&self) {
+        self.block_on(self.inner.close());
+    }
+}
+
+impl Deref for Client {
+    type Target = crate::Client;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+/// A blocking iterator over the items of a mirror node subscription, see [`Client::subscribe`].
+pub struct Subscription<'a, T> {
+    runtime: &'a Runtime,
+    stream: Pin<Box<dyn Stream<Item = crate::Result<T>> + 'a>>,
+}
+
+impl<T> Iterator for Subscription<'_, T> {
+    type Item = crate::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A request that can be executed with a blocking [`Client`].
+///
+/// This is implemented for every transaction, query and flow, as well as mirror node queries.
+pub trait Execute: sealed::Sealed {
+    /// The response to the request.
+    type Response;
+
+    /// Execute this request, blocking until it completes.
+    ///
+    /// See [`Client::execute`].
+    ///
+    /// # Errors
+    /// - Whatever the request's own `execute` would return.
+    fn execute_blocking(&mut self, client: &Client) -> crate::Result<Self::Response>
+    where
+        Self: Sized,
+    {
+        client.execute(self)
+    }
+
+    /// Execute this request, blocking until it completes or `timeout` passes.
+    ///
+    /// See [`Client::execute_with_timeout`].
+    ///
+    /// # Errors
+    /// - Whatever the request's own `execute_with_timeout` would return.
+    fn execute_blocking_with_timeout(
+        &mut self,
+        client: &Client,
+        timeout: Duration,
+    ) -> crate::Result<Self::Response>
+    where
+        Self: Sized,
+    {
+        client.execute_with_timeout(self, timeout)
+    }
+
+    #[doc(hidden)]
+    fn execute_async<'a>(
+        &'a mut self,
+        client: &'a crate::Client,
+        timeout: Option<Duration>,
+    ) -> LocalBoxFuture<'a, crate::Result<Self::Response>>;
+}
+
+/// A request whose cost can be fetched with a blocking [`Client`].
+///
+/// This is implemented for every transaction and query.
+pub trait GetCost: sealed::Sealed {
+    /// Fetch the cost of this request, blocking until it's known.
+    ///
+    /// See [`Client::get_cost`].
+    ///
+    /// # Errors
+    /// - Whatever the request's own `get_cost` would return.
+    fn get_cost_blocking(&self, client: &Client) -> crate::Result<Hbar>
+    where
+        Self: Sized,
+    {
+        client.get_cost(self)
+    }
+    #[doc(hidden)]
+    fn get_cost_async<'a>(
+        &'a self,
+        client: &'a crate::Client,
+    ) -> LocalBoxFuture<'a, crate::Result<Hbar>>;
+}
+
+/// A transaction response whose receipt and record can be fetched with a blocking [`Client`].
+///
+/// This is implemented for [`TransactionResponse`].
+pub trait GetReceipt: sealed::Sealed {
+    /// Get the receipt for the transaction, blocking until consensus.
+    ///
+    /// See [`Client::get_receipt`].
+    ///
+    /// # Errors
+    /// - See [`TransactionResponse::get_receipt`].
+    fn get_receipt_blocking(&self, client: &Client) -> crate::Result<TransactionReceipt>;
+
+    /// Get the receipt for the transaction, blocking until consensus or until `timeout` passes.
+    ///
+    /// See [`Client::get_receipt_with_timeout`].
+    ///
+    /// # Errors
+    /// - See [`TransactionResponse::get_receipt_with_timeout`].
+    fn get_receipt_blocking_with_timeout(
+        &self,
+        client: &Client,
+        timeout: Duration,
+    ) -> crate::Result<TransactionReceipt>;
+
+    /// Get the record for the transaction, blocking until consensus.
+    ///
+    /// See [`Client::get_record`].
+    ///
+    /// # Errors
+    /// - See [`TransactionResponse::get_record`].
+    fn get_record_blocking(&self, client: &Client) -> crate::Result<TransactionRecord>;
+
+    /// Get the record for the transaction, blocking until consensus or until `timeout` passes.
+    ///
+    /// See [`Client::get_record_with_timeout`].
+    ///
+    /// # Errors
+    /// - See [`TransactionResponse::get_record_with_timeout`].
+    fn get_record_blocking_with_timeout(
+        &self,
+        client: &Client,
+        timeout: Duration,
+    ) -> crate::Result<TransactionRecord>;
+}
+
+impl sealed::Sealed for TransactionResponse {}
+
+impl GetReceipt for TransactionResponse {
+    fn get_receipt_blocking(&self, client: &Client) -> crate::Result<TransactionReceipt> {
+        client.get_receipt(self)
+    }
+
+    fn get_receipt_blocking_with_timeout(
+        &self,
+        client: &Client,
+        timeout: Duration,
+    ) -> crate::Result<TransactionReceipt> {
+        client.get_receipt_with_timeout(self, timeout)
+    }
+
+    fn get_record_blocking(&self, client: &Client) -> crate::Result<TransactionRecord> {
+        client.get_record(self)
+    }
+
+    fn get_record_blocking_with_timeout(
+        &self,
+        client: &Client,
+        timeout: Duration,
+    ) -> crate::Result<TransactionRecord> {
+        client.get_record_with_timeout(self, timeout)
+    }
+}
+
+impl<D: TransactionExecute> sealed::Sealed for Transaction<D> {}
+
+impl<D: TransactionExecute> Execute for Transaction<D> {
+    type Response = TransactionResponse;
+
+    fn execute_async<'a>(
+        &'a mut self,
+        client: &'a crate::Client,
+        timeout: Option<Duration>,
+    ) -> LocalBoxFuture<'a, crate::Result<Self::Response>> {
+        Box::pin(self.execute_with_optional_timeout(client, timeout))
+    }
+}
+
+impl<D: TransactionExecute> GetCost for Transaction<D> {
+    fn get_cost_async<'a>(
+        &'a self,
+        client: &'a crate::Client,
+    ) -> LocalBoxFuture<'a, crate::Result<Hbar>> {
+        Box::pin(self.get_cost(client))
+    }
+}
+
+impl<D: QueryExecute> sealed::Sealed for Query<D> {}
+
+impl<D: QueryExecute> Execute for Query<D> {
+    type Response = D::Response;
+
+    fn execute_async<'a>(
+        &'a mut self,
+        client: &'a crate::Client,
+        timeout: Option<Duration>,
+    ) -> LocalBoxFuture<'a, crate::Result<Self::Response>> {
+        Box::pin(self.execute_with_optional_timeout(client, timeout))
+    }
+}
+
+impl<D: QueryExecute> GetCost for Query<D> {
+    fn get_cost_async<'a>(
+        &'a self,
+        client: &'a crate::Client,
+    ) -> LocalBoxFuture<'a, crate::Result<Hbar>> {
+        Box::pin(self.get_cost(client))
+    }
+}
+
+impl<D: MirrorQueryExecute> sealed::Sealed for MirrorQuery<D> {}
+
+impl<D: MirrorQueryExecute> Execute for MirrorQuery<D> {
+    type Response = D::Response;
+
+    fn execute_async<'a>(
+        &'a mut self,
+        client: &'a crate::Client,
+        timeout: Option<Duration>,
+    ) -> LocalBoxFuture<'a, crate::Result<Self::Response>> {
+        Box::pin(self.execute_with_optional_timeout(client, timeout))
+    }
+}
+
+// flows don't expose an optional timeout, so these pick whichever `execute` fits.
+macro_rules! impl_execute_for_flow {
+    ($($flow:ty),+ $(,)?) => {$(
+        impl sealed::Sealed for $flow {}
+
+        impl Execute for $flow {
+            type Response = TransactionResponse;
+
+            fn execute_async<'a>(
+                &'a mut self,
+                client: &'a crate::Client,
+                timeout: Option<Duration>,
+            ) -> LocalBoxFuture<'a, crate::Result<Self::Response>> {
+                Box::pin(async move {
+                    match timeout {
+                        Some(timeout) => self.execute_with_timeout(client, timeout).await,
+                        None => self.execute(client).await,
+                    }
+                })
+            }
+        }
+    )+};
+}
+
+impl_execute_for_flow!(ContractCreateFlow, EthereumFlow, TokenRejectFlow);
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::{
+        Client,
+        Execute,
+        GetReceipt,
+    };
+    use crate::testing::{
+        MockNetwork,
+        MockResponse,
+    };
+    use crate::{
+        AccountId,
+        Hbar,
+        PrivateKey,
+        Status,
+        TransferTransaction,
+    };
+
+    #[test]
+    fn execute_and_get_receipt() {
+        // the mock network needs a runtime of its own, since the blocking client can't be used from within one.
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let network = runtime.block_on(MockNetwork::start(1)).unwrap();
+
+        let client = Client::for_network(network.network()).unwrap();
+        client.set_operator(AccountId::new(0, 0, 2), PrivateKey::generate_ed25519());
+
+        network.nodes()[0].respond(MockResponse::busy());
+        network.nodes()[0].respond(MockResponse::receipt(Status::Success));
+
+        let response = client
+            .execute(
+                TransferTransaction::new()
+                    .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+                    .hbar_transfer(AccountId::new(0, 0, 3), Hbar::new(1)),
+            )
+            .unwrap();
+
+        assert_eq!(client.get_receipt(&response).unwrap().status, Status::Success);
+
+        // the same, through the requests themselves.
+        network.nodes()[0].respond(MockResponse::busy());
+
+        let response = TransferTransaction::new()
+            .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 3), Hbar::new(1))
+            .execute_blocking(&client)
+            .unwrap();
+
+        assert_eq!(response.get_receipt_blocking(&client).unwrap().status, Status::Success);
+    }
+}
@@ -0,0 +1,223 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+
+use parking_lot::Mutex;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use super::MirrorNetworkData;
+
+/// How far the network's clock is ahead of the local one.
+#[derive(Default)]
+pub(crate) struct ClockOffset(Mutex<ClockOffsetInner>);
+
+#[derive(Default)]
+struct ClockOffsetInner {
+    // set with `Client::set_clock_offset`, this wins over the estimate.
+    fixed: Option<Duration>,
+    // the most recent estimates, oldest first.
+    samples: VecDeque<Duration>,
+}
+
+impl ClockOffset {
+    const MAX_SAMPLES: usize = 8;
+
+    /// Records of transactions that started longer ago than this aren't used,
+    /// since they could've reached consensus long before they were fetched.
+    const MAX_TRANSACTION_AGE: Duration = Duration::minutes(1);
+
+    /// The longest a transaction can be valid for.
+    const MAX_VALID_DURATION: Duration = Duration::minutes(3);
+
+    pub(crate) fn get(&self) -> Duration {
+        let inner = self.0.lock();
+
+        // a record only gets to us after consensus, so every sample underestimates the offset a bit,
+        // which makes the largest one the best guess.
+        inner.fixed.or_else(|| inner.samples.iter().copied().max()).unwrap_or(Duration::ZERO)
+    }
+
+    pub(crate) fn set(&self, offset: Option<Duration>) {
+        self.0.lock().fixed = offset;
+    }
+
+    /// Adds an estimate from the record of a transaction that started at `valid_start`,
+    /// reached consensus at `consensus_timestamp` and was received at `received_at` (local time).
+    pub(crate) fn observe(
+        &self,
+        valid_start: OffsetDateTime,
+        consensus_timestamp: OffsetDateTime,
+        received_at: OffsetDateTime,
+    ) {
+        let mut inner = self.0.lock();
+
+        let offset = inner.fixed.or_else(|| inner.samples.iter().copied().max());
+        let age = received_at + offset.unwrap_or(Duration::ZERO) - valid_start;
+
+        if !(valid_start..=valid_start + Self::MAX_VALID_DURATION).contains(&consensus_timestamp)
+            || age > Self::MAX_TRANSACTION_AGE
+        {
+            return;
+        }
+
+        if inner.samples.len() == Self::MAX_SAMPLES {
+            inner.samples.pop_front();
+        }
+
+        inner.samples.push_back(consensus_timestamp - received_at);
+    }
+
+    /// Replaces the estimate with one measured by comparing `network_time` to the local time it was taken at.
+    ///
+    /// Unlike records, this also works when the local clock is ahead of the network's,
+    /// so every earlier sample is dropped in favor of it.
+    pub(crate) fn reset(&self, network_time: OffsetDateTime, local_time: OffsetDateTime) {
+        let mut inner = self.0.lock();
+
+        inner.samples.clear();
+        inner.samples.push_back(network_time - local_time);
+    }
+
+    /// Re-estimates the offset with the time from a mirror node, unless it was set with `Client::set_clock_offset`.
+    ///
+    /// The estimate is left alone if the mirror node can't be reached.
+    pub(crate) async fn synchronize(&self, mirror_network: &MirrorNetworkData) {
+        if self.0.lock().fixed.is_some() {
+            return;
+        }
+
+        if let Some((network_time, local_time)) = mirror_network.fetch_time().await {
+            self.reset(network_time, local_time);
+        }
+    }
+
+    /// Returns the current time according to the network.
+    pub(crate) fn now(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc() + self.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use super::ClockOffset;
+
+    #[test]
+    fn estimate() {
+        let clock = ClockOffset::default();
+        let now = OffsetDateTime::now_utc();
+
+        assert_eq!(clock.get(), Duration::ZERO);
+
+        // the network is 30 seconds ahead, the first transaction was generated before that was known.
+        clock.observe(now - Duration::seconds(5), now + Duration::seconds(28), now);
+        clock.observe(now + Duration::seconds(25), now + Duration::seconds(30), now);
+        assert_eq!(clock.get(), Duration::seconds(30));
+
+        // consensus can't be before the transaction starts.
+        clock.observe(now, now - Duration::days(1), now);
+        assert_eq!(clock.get(), Duration::seconds(30));
+
+        // and a transaction from an hour ago could've reached consensus any time since then.
+        clock.observe(now - Duration::hours(1), now - Duration::hours(1), now);
+        assert_eq!(clock.get(), Duration::seconds(30));
+
+        // old samples eventually stop counting.
+        for _ in 0..ClockOffset::MAX_SAMPLES {
+            clock.observe(now - Duration::seconds(5), now - Duration::seconds(2), now);
+        }
+        assert_eq!(clock.get(), Duration::seconds(-2));
+
+        clock.set(Some(Duration::seconds(5)));
+        assert_eq!(clock.get(), Duration::seconds(5));
+
+        clock.set(None);
+        assert_eq!(clock.get(), Duration::seconds(-2));
+
+        // a direct measurement replaces the samples, even when it's behind all of them.
+        clock.reset(now - Duration::seconds(20), now);
+        assert_eq!(clock.get(), Duration::seconds(-20));
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod mock_tests {
+    use assert_matches::assert_matches;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use crate::testing::tests::{
+        count,
+        fast_client,
+        transfer,
+    };
+    use crate::testing::{
+        MockMirrorNode,
+        MockNetwork,
+        MockResponse,
+    };
+    use crate::{
+        Error,
+        Status,
+    };
+
+    #[tokio::test]
+    async fn invalid_transaction_start_is_retried_once() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let client = fast_client(&network);
+
+        network.nodes()[0].respond(MockResponse::pre_check(Status::InvalidTransactionStart));
+
+        transfer().execute(&client).await.unwrap();
+
+        assert_eq!(count(&network, "cryptoTransfer"), 2);
+
+        network.nodes()[0].respond(MockResponse::pre_check(Status::InvalidTransactionStart));
+        network.nodes()[0].respond(MockResponse::pre_check(Status::TransactionExpired));
+
+        let error = transfer().execute(&client).await.unwrap_err();
+
+        assert_matches!(
+            error,
+            Error::TransactionPreCheckStatus { status: Status::TransactionExpired, .. }
+        );
+        assert_eq!(count(&network, "cryptoTransfer"), 4);
+    }
+
+    #[tokio::test]
+    async fn invalid_transaction_start_synchronizes_with_mirror() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let mirror = MockMirrorNode::start().await.unwrap();
+
+        let client = fast_client(&network);
+        client.set_mirror_network_endpoints([mirror.endpoint()]).unwrap();
+
+        // pretend a record said the network is a minute ahead, which our clock is actually in sync with.
+        let now = OffsetDateTime::now_utc();
+        client.load_clock_offset().observe(
+            now + Duration::seconds(59),
+            now + Duration::minutes(1),
+            now,
+        );
+        assert_eq!(client.clock_offset(), Duration::minutes(1));
+
+        network.nodes()[0].respond(MockResponse::pre_check(Status::InvalidTransactionStart));
+
+        transfer().execute(&client).await.unwrap();
+
+        assert_eq!(count(&network, "cryptoTransfer"), 2);
+
+        // the mirror node's `Date` header only has second precision.
+        let offset = client.clock_offset();
+        assert!(offset > Duration::seconds(-2) && offset < Duration::seconds(1), "{offset}");
+    }
+}
@@ -0,0 +1,367 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::env::VarError;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::Client;
+use crate::{
+    AccountId,
+    Error,
+    Hbar,
+    LedgerId,
+    PrivateKey,
+    Proxy,
+    TlsMode,
+};
+
+/// Environment variables that all share a prefix.
+struct Env<'a> {
+    prefix: &'a str,
+    // `std::env::var`, unless the variables come from somewhere else.
+    var: &'a dyn Fn(&str) -> Result<String, VarError>,
+}
+
+impl Env<'_> {
+    fn name(&self, name: &str) -> String {
+        format!("{}{name}", self.prefix)
+    }
+
+    fn malformed(&self, name: &str, error: impl fmt::Display) -> Error {
+        Error::basic_parse(format!(
+            "environment variable `{}` is malformed: {error}",
+            self.name(name)
+        ))
+    }
+
+    /// Returns the value of `name`, `None` if it's unset or empty.
+    fn var(&self, name: &str) -> crate::Result<Option<String>> {
+        match (self.var)(&self.name(name)) {
+            Ok(it) if it.trim().is_empty() => Ok(None),
+            Ok(it) => Ok(Some(it.trim().to_owned())),
+            Err(VarError::NotPresent) => Ok(None),
+            Err(e @ VarError::NotUnicode(_)) => Err(self.malformed(name, e)),
+        }
+    }
+
+    fn parse<T>(&self, name: &str) -> crate::Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.var(name)?.map(|it| it.parse().map_err(|e| self.malformed(name, e))).transpose()
+    }
+
+    fn millis(&self, name: &str) -> crate::Result<Option<Duration>> {
+        Ok(self.parse(name)?.map(Duration::from_millis))
+    }
+
+    fn hbar(&self, name: &str) -> crate::Result<Option<Hbar>> {
+        match self.parse::<Hbar>(name)? {
+            Some(amount) if amount < Hbar::ZERO => {
+                Err(self.malformed(name, "must not be negative"))
+            }
+            amount => Ok(amount),
+        }
+    }
+
+    /// Parses a comma separated list of `<account id>=<address>` pairs.
+    fn network(&self, name: &str) -> crate::Result<Option<HashMap<String, AccountId>>> {
+        let Some(value) = self.var(name)? else {
+            return Ok(None);
+        };
+
+        value
+            .split(',')
+            .map(|node| {
+                let (account_id, address) = node.split_once('=').ok_or_else(|| {
+                    self.malformed(name, format!("expected `<account id>=<address>`, got `{node}`"))
+                })?;
+
+                let account_id = account_id.trim().parse().map_err(|e| self.malformed(name, e))?;
+
+                Ok((address.trim().to_owned(), account_id))
+            })
+            .collect::<crate::Result<_>>()
+            .map(Some)
+    }
+
+    fn tls_mode(&self, name: &str) -> crate::Result<Option<TlsMode>> {
+        self.var(name)?
+            .map(|it| match &*it {
+                "plaintext" => Ok(TlsMode::Plaintext),
+                "tls" => Ok(TlsMode::Tls),
+                "tlsWithFallback" => Ok(TlsMode::TlsWithFallback),
+                _ => Err(self.malformed(
+                    name,
+                    format!("expected `plaintext`, `tls` or `tlsWithFallback`, got `{it}`"),
+                )),
+            })
+            .transpose()
+    }
+
+    fn proxy(&self) -> crate::Result<Option<Proxy>> {
+        let Some(url) = self.var("PROXY")? else {
+            if self.var("NO_PROXY")?.is_some() {
+                return Err(Error::basic_parse(format!(
+                    "missing environment variable: `{}` is set, so `{}` must be too",
+                    self.name("NO_PROXY"),
+                    self.name("PROXY")
+                )));
+            }
+
+            return Ok(None);
+        };
+
+        let mut proxy = Proxy::from_url(&url).map_err(|e| self.malformed("PROXY", e))?;
+
+        for rule in self.var("NO_PROXY")?.iter().flat_map(|it| it.split(',')) {
+            if !rule.trim().is_empty() {
+                proxy.no_proxy(rule.trim());
+            }
+        }
+
+        Ok(Some(proxy))
+    }
+
+    fn operator_key(&self) -> crate::Result<Option<PrivateKey>> {
+        let key = self.var("OPERATOR_KEY")?;
+        let path = self.var("OPERATOR_KEY_PATH")?;
+
+        match (key, path) {
+            (Some(key), None) => {
+                key.parse().map(Some).map_err(|e| self.malformed("OPERATOR_KEY", e))
+            }
+            (None, Some(path)) => {
+                let pem = std::fs::read(&path).map_err(|e| {
+                    self.malformed("OPERATOR_KEY_PATH", format_args!("couldn't read `{path}`: {e}"))
+                })?;
+
+                let key = match self.var("OPERATOR_KEY_PASSWORD")? {
+                    Some(password) => PrivateKey::from_pem_with_password(pem, password),
+                    None => PrivateKey::from_pem(pem),
+                };
+
+                key.map(Some).map_err(|e| self.malformed("OPERATOR_KEY_PATH", e))
+            }
+            (Some(_), Some(_)) => Err(Error::basic_parse(format!(
+                "only one of the environment variables `{}` and `{}` may be set",
+                self.name("OPERATOR_KEY"),
+                self.name("OPERATOR_KEY_PATH")
+            ))),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+pub(super) fn client_from_env(
+    prefix: &str,
+    var: &dyn Fn(&str) -> Result<String, VarError>,
+) -> crate::Result<Client> {
+    let env = Env { prefix, var };
+
+    let client = match (env.var("NETWORK_NAME")?, env.network("NETWORK")?) {
+        (Some(name), None) => {
+            Client::for_name(&name).map_err(|e| env.malformed("NETWORK_NAME", e))?
+        }
+        (None, Some(network)) => {
+            Client::for_network(network).map_err(|e| env.malformed("NETWORK", e))?
+        }
+        (Some(_), Some(_)) => {
+            return Err(Error::basic_parse(format!(
+                "only one of the environment variables `{}` and `{}` may be set",
+                env.name("NETWORK_NAME"),
+                env.name("NETWORK")
+            )))
+        }
+        (None, None) => {
+            return Err(Error::basic_parse(format!(
+                "missing environment variable: one of `{}` or `{}` must be set",
+                env.name("NETWORK_NAME"),
+                env.name("NETWORK")
+            )))
+        }
+    };
+
+    if let Some(addresses) = env.var("MIRROR_NETWORK")? {
+        client.set_mirror_network(addresses.split(',').map(|it| it.trim().to_owned()));
+    }
+
+    match (env.parse::<AccountId>("OPERATOR_ID")?, env.operator_key()?) {
+        (Some(account_id), Some(key)) => client.set_operator(account_id, key),
+        (None, None) => {}
+        (Some(_), None) => {
+            return Err(Error::basic_parse(format!(
+                "missing environment variable: `{}` is set, so `{}` (or `{}`) must be too",
+                env.name("OPERATOR_ID"),
+                env.name("OPERATOR_KEY"),
+                env.name("OPERATOR_KEY_PATH")
+            )))
+        }
+        (None, Some(_)) => {
+            return Err(Error::basic_parse(format!(
+                "missing environment variable: an operator key is set, so `{}` must be too",
+                env.name("OPERATOR_ID")
+            )))
+        }
+    }
+
+    if let Some(ledger_id) = env.parse::<LedgerId>("LEDGER_ID")? {
+        client.set_ledger_id(Some(ledger_id));
+    }
+
+    if let Some(amount) = env.hbar("MAX_TRANSACTION_FEE")? {
+        client.set_default_max_transaction_fee(amount);
+    }
+
+    if let Some(amount) = env.hbar("MAX_QUERY_PAYMENT")? {
+        client.set_default_max_query_payment(amount);
+    }
+
+    if let Some(value) = env.parse("AUTO_VALIDATE_CHECKSUMS")? {
+        client.set_auto_validate_checksums(value);
+    }
+
+    if let Some(value) = env.parse("REGENERATE_TRANSACTION_IDS")? {
+        client.set_default_regenerate_transaction_id(value);
+    }
+
+    if let Some(value) = env.parse("MAX_ATTEMPTS")? {
+        client.set_max_attempts(value);
+    }
+
+    if let Some(value) = env.millis("MIN_BACKOFF_MS")? {
+        client.set_min_backoff(value);
+    }
+
+    if let Some(value) = env.millis("MAX_BACKOFF_MS")? {
+        client.set_max_backoff(value);
+    }
+
+    if let Some(value) = env.millis("REQUEST_TIMEOUT_MS")? {
+        client.set_request_timeout(Some(value));
+    }
+
+    if let Some(value) = env.millis("GRPC_TIMEOUT_MS")? {
+        client.set_grpc_timeout(Some(value));
+    }
+
+    if let Some(value) = env.parse("MAX_NODE_ATTEMPTS")? {
+        client.set_max_node_attempts(value);
+    }
+
+    if let Some(value) = env.millis("MIN_NODE_BACKOFF_MS")? {
+        client.set_min_node_backoff(value);
+    }
+
+    if let Some(value) = env.millis("MAX_NODE_BACKOFF_MS")? {
+        client.set_max_node_backoff(value);
+    }
+
+    if let Some(value) = env.millis("NETWORK_UPDATE_PERIOD_MS")? {
+        client.set_network_update_period((!value.is_zero()).then_some(value));
+    }
+
+    if let Some(tls_mode) = env.tls_mode("TLS_MODE")? {
+        client.set_tls_mode(tls_mode);
+    }
+
+    if let Some(proxy) = env.proxy()? {
+        client.set_proxy(Some(proxy));
+    }
+
+    Ok(client)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::env::VarError;
+    use std::num::NonZeroUsize;
+    use std::time::Duration;
+
+    use super::client_from_env;
+    use crate::{
+        AccountId,
+        Client,
+        PrivateKey,
+    };
+
+    // the variables are looked up in `vars` rather than the environment, which is shared by tests running in parallel.
+    fn from_vars(prefix: &str, vars: &[(&str, &str)]) -> crate::Result<Client> {
+        let vars: HashMap<_, _> = vars
+            .iter()
+            .map(|(name, value)| (format!("{prefix}{name}"), value.to_string()))
+            .collect();
+
+        client_from_env(prefix, &|name| vars.get(name).cloned().ok_or(VarError::NotPresent))
+    }
+
+    #[tokio::test]
+    async fn from_env() {
+        let key = PrivateKey::generate_ed25519();
+
+        let client = from_vars(
+            "HEDERA_",
+            &[
+                ("NETWORK", "0.0.3=127.0.0.1:50211, 0.0.4=127.0.0.1:50212"),
+                ("MIRROR_NETWORK", "127.0.0.1:5600"),
+                ("OPERATOR_ID", "0.0.2"),
+                ("OPERATOR_KEY", &key.to_string()),
+                ("MAX_ATTEMPTS", "3"),
+                ("GRPC_TIMEOUT_MS", "5000"),
+                ("MAX_NODE_ATTEMPTS", "2"),
+                ("MAX_NODE_BACKOFF_MS", "30000"),
+                ("NETWORK_UPDATE_PERIOD_MS", "0"),
+                ("PROXY", "socks5://127.0.0.1:1080"),
+                ("NO_PROXY", "localhost, 10.0.0.0/8"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(client.network().len(), 2);
+        assert_eq!(client.network()["127.0.0.1:50212"], AccountId::new(0, 0, 4));
+        assert_eq!(client.mirror_network(), ["127.0.0.1:5600"]);
+        assert_eq!(client.get_operator_account_id(), Some(AccountId::new(0, 0, 2)));
+        assert_eq!(client.get_operator_public_key(), Some(key.public_key()));
+        assert_eq!(client.max_attempts(), 3);
+        assert_eq!(client.grpc_timeout(), Some(Duration::from_secs(5)));
+        assert_eq!(client.max_node_attempts().map(NonZeroUsize::get), Some(2));
+        assert_eq!(client.max_node_backoff(), Duration::from_secs(30));
+        assert_eq!(client.network_update_period(), None);
+
+        let proxy = client.proxy().unwrap();
+        assert_eq!(proxy.get_address(), "127.0.0.1:1080");
+        assert_eq!(proxy.get_no_proxy(), ["localhost", "10.0.0.0/8"]);
+    }
+
+    #[test]
+    fn missing_network() {
+        let error = from_vars("HEDERA_", &[]).unwrap_err().to_string();
+
+        assert!(error.contains("`HEDERA_NETWORK_NAME`"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn missing_operator_key() {
+        let error = from_vars("HEDERA_", &[("NETWORK_NAME", "testnet"), ("OPERATOR_ID", "0.0.2")])
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("`HEDERA_OPERATOR_KEY`"), "{error}");
+    }
+
+    #[test]
+    fn malformed_tunable() {
+        let error = from_vars(
+            "HEDERA_",
+            &[("NETWORK", "0.0.3=127.0.0.1:50211"), ("MAX_BACKOFF_MS", "soon")],
+        )
+        .unwrap_err()
+        .to_string();
+
+        assert!(error.contains("`HEDERA_MAX_BACKOFF_MS` is malformed"), "{error}");
+    }
+}
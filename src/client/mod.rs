@@ -3,6 +3,7 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt;
+use std::future::Future;
 use std::num::{
     NonZeroU64,
     NonZeroUsize,
@@ -12,39 +13,76 @@ use std::sync::atomic::{
     AtomicU64,
     Ordering,
 };
-use std::time::Duration;
+use std::time::{
+    Duration,
+    Instant,
+};
 
+pub(crate) use clock::ClockOffset;
+pub use network::{
+    LatencyPercentiles,
+    NodeHealthState,
+    NodeSelectionStrategy,
+    NodeStats,
+    Proxy,
+    ProxyKind,
+    TlsMode,
+};
 pub(crate) use network::{
     Network,
     NetworkData,
 };
-pub(crate) use operator::Operator;
+pub use operator::PayerSelectionStrategy;
+pub(crate) use operator::{
+    Operator,
+    OperatorPool,
+    Payer,
+};
 use parking_lot::RwLock;
+pub(crate) use throttle::{
+    query_request_type,
+    transaction_request_type,
+    Throttle,
+};
 use tokio::sync::watch;
 use triomphe::Arc;
 
 use self::network::managed::ManagedNetwork;
+pub use self::network::mirror::MirrorEndpoint;
 use self::network::mirror::MirrorNetwork;
 pub(crate) use self::network::mirror::MirrorNetworkData;
 use crate::ping_query::PingQuery;
+use crate::request_observer::AnyRequestObserver;
 use crate::signer::AnySigner;
 use crate::{
+    AccountBalanceQuery,
     AccountId,
+    ArcSwap,
     ArcSwapOption,
     Error,
+    FileContentsQuery,
+    FileId,
     Hbar,
     LedgerId,
     NodeAddressBook,
     NodeAddressBookQuery,
     PrivateKey,
     PublicKey,
+    RequestObserver,
+    Signer,
+    ThrottleDefinitions,
+    TransactionIdGenerator,
+    TransactionRecord,
 };
 
+mod clock;
 #[cfg(feature = "serde")]
 mod config;
 
+mod env;
 mod network;
 mod operator;
+mod throttle;
 
 #[derive(Copy, Clone)]
 pub(crate) struct ClientBackoff {
@@ -77,7 +115,7 @@ struct ClientBuilder {
     ledger_id: Option<LedgerId>,
     auto_validate_checksums: bool,
     regenerate_transaction_ids: bool,
-    update_network: bool,
+    network_update_period: Option<Duration>,
     backoff: ClientBackoff,
 }
 
@@ -92,13 +130,13 @@ impl ClientBuilder {
             ledger_id: None,
             auto_validate_checksums: false,
             regenerate_transaction_ids: true,
-            update_network: true,
+            network_update_period: Some(Duration::from_secs(24 * 60 * 60)),
             backoff: ClientBackoff::default(),
         }
     }
 
     fn disable_network_updating(self) -> Self {
-        Self { update_network: false, ..self }
+        Self { network_update_period: None, ..self }
     }
 
     fn ledger_id(self, ledger_id: Option<LedgerId>) -> Self {
@@ -114,22 +152,26 @@ impl ClientBuilder {
             ledger_id,
             auto_validate_checksums,
             regenerate_transaction_ids,
-            update_network,
+            network_update_period,
             backoff,
         } = self;
 
-        let network_update_tx = match update_network {
-            true => network::managed::spawn_network_update(
-                network.clone(),
-                Some(Duration::from_secs(24 * 60 * 60)),
-            ),
-            // yeah, we just drop the rx.
-            false => watch::channel(None).0,
-        };
+        // the updater itself is only spawned once the client is first used, see `begin_request`.
+        let network_update_tx = watch::channel(network_update_period).0;
+
+        let transaction_ids = Arc::new(TransactionIdGenerator::new());
+        let clock_offset = Arc::new(ClockOffset::default());
+        let operator = operator.map(|it| Operator {
+            transaction_ids: Arc::clone(&transaction_ids),
+            clock_offset: Arc::clone(&clock_offset),
+            ..it
+        });
 
         Client(Arc::new(ClientInner {
             network,
-            operator: ArcSwapOption::new(operator.map(Arc::new)),
+            operator: ArcSwapOption::new(
+                operator.map(|it| Arc::new(Payer::Operator(Arc::new(it)))),
+            ),
             max_transaction_fee_tinybar: AtomicU64::new(
                 max_transaction_fee.map_or(0, NonZeroU64::get),
             ),
@@ -138,21 +180,37 @@ impl ClientBuilder {
             auto_validate_checksums: AtomicBool::new(auto_validate_checksums),
             regenerate_transaction_ids: AtomicBool::new(regenerate_transaction_ids),
             network_update_tx,
+            network_update_started: AtomicBool::new(false),
+            closed: watch::channel(false).0,
+            in_flight: watch::channel(0).0,
             backoff: RwLock::new(backoff),
+            request_observer: RwLock::new(None),
+            node_selection_strategy: ArcSwap::new(Arc::new(NodeSelectionStrategy::default())),
+            throttle: ArcSwapOption::empty(),
+            clock_offset,
+            transaction_ids,
         }))
     }
 }
 
 struct ClientInner {
     network: ManagedNetwork,
-    operator: ArcSwapOption<Operator>,
+    operator: ArcSwapOption<Payer>,
     max_transaction_fee_tinybar: AtomicU64,
     max_query_payment_tinybar: AtomicU64,
     ledger_id: ArcSwapOption<LedgerId>,
     auto_validate_checksums: AtomicBool,
     regenerate_transaction_ids: AtomicBool,
     network_update_tx: watch::Sender<Option<Duration>>,
+    network_update_started: AtomicBool,
+    closed: watch::Sender<bool>,
+    in_flight: watch::Sender<usize>,
     backoff: RwLock<ClientBackoff>,
+    request_observer: RwLock<Option<AnyRequestObserver>>,
+    node_selection_strategy: ArcSwap<NodeSelectionStrategy>,
+    throttle: ArcSwapOption<Throttle>,
+    clock_offset: Arc<ClockOffset>,
+    transaction_ids: Arc<TransactionIdGenerator>,
 }
 
 /// Managed client for use on the Hiero network.
@@ -169,35 +227,150 @@ impl fmt::Debug for Client {
 impl Client {
     #[cfg(feature = "serde")]
     fn from_config_data(config: config::ClientConfig) -> crate::Result<Self> {
-        let config::ClientConfig { operator, network, mirror_network } = config;
+        let config::ClientConfig {
+            operator,
+            operator_pool,
+            network,
+            mirror_network,
+            ledger_id,
+            max_transaction_fee,
+            max_query_payment,
+            auto_validate_checksums,
+            regenerate_transaction_ids,
+            max_attempts,
+            min_backoff,
+            max_backoff,
+            request_timeout,
+            grpc_timeout,
+            max_node_attempts,
+            min_node_backoff,
+            max_node_backoff,
+            network_update_period,
+            tls_mode,
+            node_selection_strategy,
+            proxy,
+        } = config;
+
+        for (name, amount) in
+            [("maxTransactionFee", max_transaction_fee), ("maxQueryPayment", max_query_payment)]
+        {
+            if amount.is_some_and(|it| it < Hbar::ZERO) {
+                return Err(Error::basic_parse(format!("`{name}` must not be negative")));
+            }
+        }
 
         // fixme: check to ensure net and mirror net are the same when they're a network name (no other SDK actually checks this though)
-        let client = match network {
-            config::Either::Left(network) => Client::for_network(network)?,
+        let mut builder = match network {
+            config::Either::Left(network) => ClientBuilder::new(ManagedNetwork::new(
+                Network::from_addresses(&network)?,
+                MirrorNetwork::default(),
+            ))
+            .disable_network_updating(),
             config::Either::Right(it) => match it {
-                config::NetworkName::Mainnet => Client::for_mainnet(),
-                config::NetworkName::Testnet => Client::for_testnet(),
-                config::NetworkName::Previewnet => Client::for_previewnet(),
+                config::NetworkName::Mainnet => ClientBuilder::new(ManagedNetwork::mainnet())
+                    .ledger_id(Some(LedgerId::mainnet())),
+                config::NetworkName::Testnet => ClientBuilder::new(ManagedNetwork::testnet())
+                    .ledger_id(Some(LedgerId::testnet())),
+                config::NetworkName::Previewnet => ClientBuilder::new(ManagedNetwork::previewnet())
+                    .ledger_id(Some(LedgerId::previewnet())),
             },
         };
 
-        let mirror_network = mirror_network.map(|mirror_network| match mirror_network {
-            config::Either::Left(mirror_network) => {
-                MirrorNetwork::from_addresses(mirror_network.into_iter().map(Cow::Owned).collect())
+        match &operator_pool {
+            Some(_) if operator.is_some() => {
+                return Err(Error::basic_parse("`operator` and `operatorPool` can't both be set"));
             }
-            config::Either::Right(it) => match it {
-                config::NetworkName::Mainnet => MirrorNetwork::mainnet(),
-                config::NetworkName::Testnet => MirrorNetwork::testnet(),
-                config::NetworkName::Previewnet => MirrorNetwork::previewnet(),
-            },
-        });
+            Some((operators, _)) if operators.is_empty() => {
+                return Err(Error::basic_parse("`operatorPool` needs at least one operator"));
+            }
+            _ => {}
+        }
+
+        builder.operator = operator;
+
+        if let Some(ledger_id) = ledger_id {
+            builder.ledger_id = ledger_id;
+        }
+
+        if let Some(period) = network_update_period {
+            builder.network_update_period = period;
+        }
+
+        builder.max_transaction_fee =
+            max_transaction_fee.and_then(|it| NonZeroU64::new(it.to_tinybars() as u64));
+        builder.max_query_payment =
+            max_query_payment.and_then(|it| NonZeroU64::new(it.to_tinybars() as u64));
+
+        if let Some(value) = auto_validate_checksums {
+            builder.auto_validate_checksums = value;
+        }
+
+        if let Some(value) = regenerate_transaction_ids {
+            builder.regenerate_transaction_ids = value;
+        }
+
+        if let Some(value) = max_attempts {
+            builder.backoff.max_attempts = value;
+        }
 
-        if let Some(operator) = operator {
-            client.0.operator.store(Some(Arc::new(operator)));
+        if let Some(value) = min_backoff {
+            builder.backoff.initial_backoff = value;
         }
 
-        if let Some(mirror_network) = mirror_network {
-            client.set_mirror_network(mirror_network.load().addresses());
+        if let Some(value) = max_backoff {
+            builder.backoff.max_backoff = value;
+        }
+
+        builder.backoff.request_timeout = request_timeout;
+        builder.backoff.grpc_timeout = grpc_timeout;
+
+        let client = builder.build();
+
+        if let Some((operators, strategy)) = operator_pool {
+            client.store_operator_pool(
+                operators.into_iter().map(|it| (it.account_id, it.signer)),
+                strategy,
+            );
+        }
+
+        match mirror_network {
+            Some(config::Either::Left(endpoints)) => {
+                client.set_mirror_network_endpoints(endpoints)?
+            }
+            Some(config::Either::Right(it)) => {
+                let mirror_network = match it {
+                    config::NetworkName::Mainnet => MirrorNetwork::mainnet(),
+                    config::NetworkName::Testnet => MirrorNetwork::testnet(),
+                    config::NetworkName::Previewnet => MirrorNetwork::previewnet(),
+                };
+
+                client.set_mirror_network(mirror_network.load().addresses());
+            }
+            None => {}
+        }
+
+        if let Some(attempts) = max_node_attempts {
+            client.set_max_node_attempts(attempts);
+        }
+
+        if let Some(backoff) = min_node_backoff {
+            client.set_min_node_backoff(backoff);
+        }
+
+        if let Some(backoff) = max_node_backoff {
+            client.set_max_node_backoff(backoff);
+        }
+
+        if let Some(tls_mode) = tls_mode {
+            client.set_tls_mode(tls_mode);
+        }
+
+        if let Some(strategy) = node_selection_strategy {
+            client.set_node_selection_strategy(strategy);
+        }
+
+        if proxy.is_some() {
+            client.set_proxy(proxy);
         }
 
         Ok(client)
@@ -216,6 +389,122 @@ impl Client {
         Self::from_config_data(config)
     }
 
+    /// Create a client from environment variables.
+    ///
+    /// See [`from_env_with_prefix`](Self::from_env_with_prefix) for the variables that are read, without any prefix.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if a required variable is missing, or if any variable is malformed.
+    pub fn from_env() -> crate::Result<Self> {
+        Self::from_env_with_prefix("")
+    }
+
+    /// Create a client from environment variables that all start with `prefix` (for instance `TEST_`).
+    ///
+    /// The network is either a network name or a list of nodes, exactly one of these must be set:
+    /// - `NETWORK_NAME`: `mainnet`, `testnet`, `previewnet` or `localhost`.
+    /// - `NETWORK`: comma separated `<account id>=<address>` pairs, such as `0.0.3=127.0.0.1:50211`.
+    ///
+    /// Everything else is optional:
+    /// - `MIRROR_NETWORK`: comma separated mirror node addresses.
+    /// - `OPERATOR_ID`: the operator's account ID, which requires one of:
+    ///   - `OPERATOR_KEY`: the operator's private key, hex encoded (raw or DER).
+    ///   - `OPERATOR_KEY_PATH`: the path to a PEM file containing the operator's private key,
+    ///     decrypted with `OPERATOR_KEY_PASSWORD` if that's set.
+    /// - `LEDGER_ID`: `mainnet`, `testnet`, `previewnet` or the hex encoded ledger ID.
+    /// - `MAX_TRANSACTION_FEE` and `MAX_QUERY_PAYMENT`: amounts of hbar, such as `2 ℏ` or `500 tℏ`.
+    /// - `AUTO_VALIDATE_CHECKSUMS` and `REGENERATE_TRANSACTION_IDS`: `true` or `false`.
+    /// - `MAX_ATTEMPTS`: the maximum number of attempts for a request.
+    /// - `MIN_BACKOFF_MS`, `MAX_BACKOFF_MS`, `REQUEST_TIMEOUT_MS` and `GRPC_TIMEOUT_MS`: durations in milliseconds.
+    /// - `MAX_NODE_ATTEMPTS`: how many bad gRPC statuses a node can return before it's removed, `0` for no limit.
+    /// - `MIN_NODE_BACKOFF_MS` and `MAX_NODE_BACKOFF_MS`: the backoff interval for nodes that failed, in milliseconds.
+    /// - `NETWORK_UPDATE_PERIOD_MS`: how often the address book is fetched, in milliseconds, `0` disables updates.
+    /// - `TLS_MODE`: `plaintext`, `tls` or `tlsWithFallback`.
+    /// - `PROXY`: the URL of a proxy, see [`Proxy::from_url`],
+    ///   with `NO_PROXY` as comma separated rules for hosts that bypass it, see [`Proxy::no_proxy`].
+    ///
+    /// Empty variables are treated as unset.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if a required variable is missing, or if any variable is malformed.
+    ///   The error names the variable.
+    pub fn from_env_with_prefix(prefix: &str) -> crate::Result<Self> {
+        env::client_from_env(prefix, &|name| std::env::var(name))
+    }
+
+    /// Create a client from the same variables as [`from_env_with_prefix`](Self::from_env_with_prefix),
+    /// but looked up with `var` instead of read from the environment.
+    ///
+    /// `var` is given the full name of a variable (such as `TEST_NETWORK_NAME`) and returns `None` if it's unset.
+    /// This allows filling in defaults, or reading the variables from somewhere else, without touching the environment.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if a required variable is missing, or if any variable is malformed.
+    ///   The error names the variable.
+    pub fn from_vars_with_prefix(
+        prefix: &str,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> crate::Result<Self> {
+        env::client_from_env(prefix, &|name| var(name).ok_or(std::env::VarError::NotPresent))
+    }
+
+    /// Returns the configuration of this client, as json accepted by [`from_config`](Self::from_config).
+    ///
+    /// Every setting is written out, including the addresses of every node and mirror node,
+    /// so `Client::from_config(&client.to_config())` creates a client that behaves like this one.
+    ///
+    /// Operators (including an operator pool) are written with their private keys.
+    /// Signers other than a private key (such as one set with [`set_operator_with`](Self::set_operator_with))
+    /// can't be written to a config, so an operator with one is left out, as is a pool with any payer that has one.
+    /// A warning is logged when that happens.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_config(&self) -> String {
+        let backoff = self.backoff();
+
+        let copy = |it: &Operator| Operator {
+            account_id: it.account_id,
+            signer: it.signer.clone(),
+            transaction_ids: Arc::clone(&it.transaction_ids),
+            clock_offset: Arc::clone(&it.clock_offset),
+        };
+
+        let payer = self.load_operator();
+
+        let config = config::ClientConfig {
+            operator: match payer.as_deref() {
+                Some(Payer::Operator(it)) => Some(copy(it)),
+                Some(Payer::Pool(_)) | None => None,
+            },
+            operator_pool: payer
+                .as_deref()
+                .and_then(Payer::pool)
+                .map(|it| (it.operators().iter().map(|it| copy(it)).collect(), it.strategy())),
+            network: config::Either::Left(self.network()),
+            mirror_network: Some(config::Either::Left(self.mirror_network_endpoints())),
+            ledger_id: Some(self.ledger_id_internal().as_deref().cloned()),
+            max_transaction_fee: self.default_max_transaction_fee(),
+            max_query_payment: self.default_max_query_payment(),
+            auto_validate_checksums: Some(self.auto_validate_checksums()),
+            regenerate_transaction_ids: Some(self.default_regenerate_transaction_id()),
+            max_attempts: Some(backoff.max_attempts),
+            min_backoff: Some(backoff.initial_backoff),
+            max_backoff: Some(backoff.max_backoff),
+            request_timeout: backoff.request_timeout,
+            grpc_timeout: backoff.grpc_timeout,
+            max_node_attempts: Some(self.max_node_attempts().map_or(0, NonZeroUsize::get)),
+            min_node_backoff: Some(self.min_node_backoff()),
+            max_node_backoff: Some(self.max_node_backoff()),
+            network_update_period: Some(self.network_update_period()),
+            tls_mode: Some(self.tls_mode()),
+            node_selection_strategy: Some(self.node_selection_strategy()),
+            proxy: self.proxy(),
+        };
+
+        serde_json::to_string_pretty(&config::ClientConfigInner::from(config))
+            .expect("client configs are always serializable")
+    }
+
     /// Returns the addresses for the configured mirror network.
     ///
     /// Unless _explicitly_ set, the return value isn't guaranteed to be anything in particular in order to allow future changes without breaking semver.
@@ -250,11 +539,41 @@ impl Client {
     /// Sets the addresses to use for the mirror network.
     ///
     /// This is mostly useful if you used [`Self::for_network`] and need to set a mirror network.
+    ///
+    /// Requests are spread across all of the addresses, and mirror nodes that fail are skipped
+    /// (with an increasing backoff) until they recover.
     pub fn set_mirror_network<I: IntoIterator<Item = String>>(&self, addresses: I) {
-        self.mirrornet().store(
-            MirrorNetworkData::from_addresses(addresses.into_iter().map(Cow::Owned).collect())
-                .into(),
-        );
+        let addresses: Vec<_> = addresses.into_iter().map(Cow::Owned).collect();
+
+        self.mirrornet().rcu(|old| MirrorNetworkData::with_addresses(old, addresses.clone()));
+    }
+
+    /// Returns the connection settings for each mirror node in the configured mirror network.
+    #[must_use]
+    pub fn mirror_network_endpoints(&self) -> Vec<MirrorEndpoint> {
+        self.mirrornet().load().endpoints().to_vec()
+    }
+
+    /// Sets the mirror network, with connection settings for each mirror node.
+    ///
+    /// This is needed for mirror nodes that aren't reachable with the defaults (TLS verified against the system trust store),
+    /// such as a local mirror node that only speaks plaintext.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the TLS settings of any endpoint are invalid (for instance, a malformed CA bundle).
+    pub fn set_mirror_network_endpoints<I: IntoIterator<Item = MirrorEndpoint>>(
+        &self,
+        endpoints: I,
+    ) -> crate::Result<()> {
+        let endpoints: Vec<_> = endpoints.into_iter().collect();
+
+        for endpoint in &endpoints {
+            endpoint.validate()?;
+        }
+
+        self.mirrornet().rcu(|old| MirrorNetworkData::with_endpoints(old, endpoints.clone()));
+
+        Ok(())
     }
 
     /// Construct a client with the given nodes configured.
@@ -272,6 +591,18 @@ impl Client {
         Ok(ClientBuilder::new(network).disable_network_updating().build())
     }
 
+    /// Construct a client for the nodes in `address_book`, such as one read with [`NodeAddressBook::read_from_file`].
+    ///
+    /// Like [`for_network`](Self::for_network), the client has no mirror network and doesn't update its network,
+    /// set a mirror network (and a [network update period](Self::set_network_update_period)) for that.
+    #[must_use]
+    pub fn for_address_book(address_book: &NodeAddressBook) -> Self {
+        let network =
+            ManagedNetwork::new(Network::from_address_book(address_book), MirrorNetwork::default());
+
+        ClientBuilder::new(network).disable_network_updating().build()
+    }
+
     /// Construct a client from a select mirror network
     pub async fn for_mirror_network(mirror_networks: Vec<String>) -> crate::Result<Self> {
         let network_addresses: HashMap<String, AccountId> = HashMap::new();
@@ -316,7 +647,25 @@ impl Client {
     // allowed for API compatibility.
     #[allow(clippy::needless_pass_by_value)]
     pub fn set_network_from_address_book(&self, address_book: NodeAddressBook) {
-        self.net().update_from_address_book(&address_book);
+        self.0.network.update_from_address_book(&address_book);
+    }
+
+    /// Sets a callback that's called with every address book the network is updated from,
+    /// both by [`set_network_from_address_book`](Self::set_network_from_address_book) and by automatic network updates.
+    ///
+    /// This replaces any previously set callback, see [`NodeAddressBook`] for an example of caching the address book on disk.
+    ///
+    /// The callback is called inline with the update, so it should be reasonably quick.
+    pub fn set_address_book_callback<F>(&self, callback: F)
+    where
+        F: Fn(&NodeAddressBook) + Send + Sync + 'static,
+    {
+        self.0.network.set_address_book_callback(callback);
+    }
+
+    /// Removes the callback set with [`set_address_book_callback`](Self::set_address_book_callback).
+    pub fn clear_address_book_callback(&self) {
+        self.0.network.clear_address_book_callback();
     }
 
     /// Updates the network to use the given addresses.
@@ -373,6 +722,39 @@ impl Client {
         self.net().0.load().set_min_backoff(min_node_backoff)
     }
 
+    /// Returns how connections to consensus nodes are secured.
+    ///
+    /// This is [`TlsMode::Plaintext`] by default.
+    #[must_use]
+    pub fn tls_mode(&self) -> TlsMode {
+        self.net().0.load().tls_mode()
+    }
+
+    /// Sets how connections to consensus nodes are secured.
+    ///
+    /// Existing connections are dropped and re-established with the new mode.
+    pub fn set_tls_mode(&self, tls_mode: TlsMode) {
+        self.net().update_tls_mode(tls_mode);
+    }
+
+    /// Returns the proxy that connections to consensus and mirror nodes go through, if any.
+    #[must_use]
+    pub fn proxy(&self) -> Option<Proxy> {
+        self.net().0.load().proxy().cloned()
+    }
+
+    /// Sets the proxy that connections to consensus and mirror nodes go through, `None` connects directly.
+    ///
+    /// The proxy isn't picked up from the environment by default, use [`Proxy::from_env`] for that.
+    ///
+    /// Existing connections are dropped and re-established through the new proxy.
+    pub fn set_proxy(&self, proxy: Option<Proxy>) {
+        let proxy = proxy.map(Arc::new);
+
+        self.net().update_proxy(proxy.clone());
+        self.mirrornet().rcu(|old| MirrorNetworkData::with_proxy(old, proxy.clone()));
+    }
+
     /// Construct a hedera client pre-configured for access to the given network.
     ///
     /// Currently supported network names are `"mainnet"`, `"testnet"`, and `"previewnet"`.
@@ -439,9 +821,7 @@ impl Client {
     ///
     /// The operator private key is used to sign all transactions executed by this client.
     pub fn set_operator(&self, id: AccountId, key: PrivateKey) {
-        self.0
-            .operator
-            .store(Some(Arc::new(Operator { account_id: id, signer: AnySigner::PrivateKey(key) })));
+        self.store_operator(id, AnySigner::PrivateKey(key));
     }
 
     /// Sets the account that will, by default, be paying for transactions and queries built with
@@ -457,10 +837,114 @@ impl Client {
         public_key: PublicKey,
         f: F,
     ) {
-        self.0.operator.store(Some(Arc::new(Operator {
-            account_id: id,
-            signer: AnySigner::arbitrary(Box::new(public_key), f),
-        })));
+        self.store_operator(id, AnySigner::arbitrary(Box::new(public_key), f));
+    }
+
+    /// Sets the account that will, by default, be paying for transactions and queries built with
+    /// this client, with a [`Signer`] (for instance, one backed by an HSM or a remote signing service).
+    ///
+    /// The operator account ID is used to generate the default transaction ID for all transactions
+    /// executed with this client.
+    ///
+    /// The operator signer is used to sign all transactions (including query payments) executed by this client.
+    pub fn set_operator_with_signer<S: Signer>(&self, id: AccountId, signer: S) {
+        self.store_operator(id, AnySigner::signer(signer));
+    }
+
+    fn store_operator(&self, account_id: AccountId, signer: AnySigner) {
+        let operator = Operator {
+            account_id,
+            signer,
+            transaction_ids: Arc::clone(&self.0.transaction_ids),
+            clock_offset: Arc::clone(&self.0.clock_offset),
+        };
+
+        self.0.operator.store(Some(Arc::new(Payer::Operator(Arc::new(operator)))));
+    }
+
+    /// Sets several accounts that take turns paying for transactions and queries built with this client,
+    /// replacing any operator set with [`set_operator`](Self::set_operator) (or the like).
+    ///
+    /// Every transaction (and query payment) picks one payer according to `strategy`,
+    /// its transaction ID is generated for that payer and it gets signed with that payer's signer.
+    /// The payer that was picked is reported by [`TransactionResponse::payer_account_id`](crate::TransactionResponse::payer_account_id).
+    ///
+    /// Each payer can have a different kind of [`Signer`] (for instance, a [`PrivateKey`] for one and an HSM for another).
+    ///
+    /// Setting an operator with any of the `set_operator` methods removes the pool again.
+    ///
+    /// # Panics
+    /// If `operators` is empty.
+    pub fn set_operator_pool(
+        &self,
+        operators: impl IntoIterator<Item = (AccountId, Box<dyn Signer>)>,
+        strategy: PayerSelectionStrategy,
+    ) {
+        self.store_operator_pool(
+            operators
+                .into_iter()
+                .map(|(account_id, signer)| (account_id, AnySigner::signer(signer))),
+            strategy,
+        );
+    }
+
+    fn store_operator_pool(
+        &self,
+        operators: impl IntoIterator<Item = (AccountId, AnySigner)>,
+        strategy: PayerSelectionStrategy,
+    ) {
+        let operators = operators
+            .into_iter()
+            .map(|(account_id, signer)| Operator {
+                account_id,
+                signer,
+                transaction_ids: Arc::clone(&self.0.transaction_ids),
+                clock_offset: Arc::clone(&self.0.clock_offset),
+            })
+            .collect();
+
+        self.0.operator.store(Some(Arc::new(Payer::Pool(OperatorPool::new(operators, strategy)))));
+    }
+
+    /// Returns the accounts in the operator pool, empty if no pool is set.
+    #[must_use]
+    pub fn get_operator_pool_account_ids(&self) -> Vec<AccountId> {
+        self.0
+            .operator
+            .load()
+            .as_deref()
+            .and_then(Payer::pool)
+            .map(OperatorPool::account_ids)
+            .unwrap_or_default()
+    }
+
+    /// Returns the strategy the operator pool picks payers with, `None` if no pool is set.
+    #[must_use]
+    pub fn get_payer_selection_strategy(&self) -> Option<PayerSelectionStrategy> {
+        self.0.operator.load().as_deref().and_then(Payer::pool).map(OperatorPool::strategy)
+    }
+
+    /// Fetches the balance of every account in the operator pool,
+    /// for use by [`PayerSelectionStrategy::BalanceThreshold`].
+    ///
+    /// Does nothing if no pool is set.
+    ///
+    /// # Errors
+    /// - If fetching any of the balances fails, balances fetched before the failure are still updated.
+    pub async fn refresh_operator_pool_balances(&self) -> crate::Result<()> {
+        let payer = self.0.operator.load_full();
+
+        let Some(pool) = payer.as_deref().and_then(Payer::pool) else {
+            return Ok(());
+        };
+
+        for account_id in pool.account_ids() {
+            let balance = AccountBalanceQuery::new().account_id(account_id).execute(self).await?;
+
+            pool.set_balance(account_id, balance.hbars);
+        }
+
+        Ok(())
     }
 
     /// Gets a reference to the configured network.
@@ -540,8 +1024,8 @@ impl Client {
 
     /// Sets the initial backoff for a request being executed.
     #[doc(alias = "set_initial_backoff")]
-    pub fn set_min_backoff(&self, max_backoff: Duration) {
-        self.0.backoff.write().max_backoff = max_backoff;
+    pub fn set_min_backoff(&self, min_backoff: Duration) {
+        self.0.backoff.write().initial_backoff = min_backoff;
     }
 
     /// Returns the maximum amount of time a request will wait between attempts.
@@ -555,19 +1039,156 @@ impl Client {
         self.0.backoff.write().max_backoff = max_backoff;
     }
 
+    /// Returns the maximum amount of time a single gRPC request (to a single node) may take.
+    #[must_use]
+    pub fn grpc_timeout(&self) -> Option<Duration> {
+        self.backoff().grpc_timeout
+    }
+
+    /// Sets the maximum amount of time a single gRPC request (to a single node) may take.
+    ///
+    /// A request that times out is retried on another node.
+    pub fn set_grpc_timeout(&self, timeout: Option<Duration>) {
+        self.0.backoff.write().grpc_timeout = timeout;
+    }
+
     #[must_use]
     pub(crate) fn backoff(&self) -> ClientBackoff {
         *self.0.backoff.read()
     }
 
+    /// Returns the strategy used to pick which nodes requests are sent to.
+    #[must_use]
+    pub fn node_selection_strategy(&self) -> NodeSelectionStrategy {
+        NodeSelectionStrategy::clone(&self.0.node_selection_strategy.load())
+    }
+
+    /// Sets the strategy used to pick which nodes requests are sent to.
+    ///
+    /// This also decides which nodes transactions are frozen with (when they don't have explicit node account IDs).
+    pub fn set_node_selection_strategy(&self, strategy: NodeSelectionStrategy) {
+        self.0.node_selection_strategy.store(Arc::new(strategy));
+    }
+
+    pub(crate) fn load_node_selection_strategy(&self) -> Arc<NodeSelectionStrategy> {
+        self.0.node_selection_strategy.load_full()
+    }
+
+    /// Returns the throttle definitions requests are throttled by, `None` if client-side throttling is disabled.
+    #[must_use]
+    pub fn throttle_definitions(&self) -> Option<ThrottleDefinitions> {
+        self.0.throttle.load_full().map(|it| it.definitions().clone())
+    }
+
+    /// Enables client-side throttling, following `definitions`, or disables it when `None`.
+    ///
+    /// When enabled, requests wait until the throttles of the node they're sent to have room for them,
+    /// rather than being rejected by the node with `Busy`.
+    /// Every node gets an even share of each throttle, just like on the network.
+    ///
+    /// Client-side throttling is disabled by default.
+    pub fn set_throttle_definitions(&self, definitions: Option<ThrottleDefinitions>) {
+        self.0.throttle.store(definitions.map(|it| Arc::new(Throttle::new(it))));
+    }
+
+    /// Enables client-side throttling, following the network's throttle definitions (file `0.0.123`).
+    ///
+    /// The definitions are only fetched once, call this again to pick up changes.
+    ///
+    /// # Errors
+    /// - Any error that [`FileContentsQuery`] can fail with.
+    /// - [`Error::FromProtobuf`] if the file doesn't contain valid throttle definitions.
+    pub async fn load_throttle_definitions(&self) -> crate::Result<ThrottleDefinitions> {
+        let contents =
+            FileContentsQuery::new().file_id(FileId::new(0, 0, 123)).execute(self).await?.contents;
+
+        let definitions = ThrottleDefinitions::from_bytes(&contents)?;
+
+        self.set_throttle_definitions(Some(definitions.clone()));
+
+        Ok(definitions)
+    }
+
+    pub(crate) fn load_throttle(&self) -> Option<Arc<Throttle>> {
+        self.0.throttle.load_full()
+    }
+
+    /// Sets an observer that's called for every attempt at executing a request with this client.
+    ///
+    /// This replaces any previously set observer, see [`RequestObserver`] for details.
+    pub fn set_request_observer<O: RequestObserver>(&self, observer: O) {
+        *self.0.request_observer.write() = Some(AnyRequestObserver::new(observer));
+    }
+
+    /// Removes the observer set with [`set_request_observer`](Self::set_request_observer).
+    pub fn clear_request_observer(&self) {
+        *self.0.request_observer.write() = None;
+    }
+
+    pub(crate) fn request_observer(&self) -> Option<AnyRequestObserver> {
+        self.0.request_observer.read().clone()
+    }
+
     // keep this internal (repr)
-    pub(crate) fn load_operator(&self) -> arc_swap::Guard<Option<Arc<Operator>>> {
+    pub(crate) fn load_operator(&self) -> arc_swap::Guard<Option<Arc<Payer>>> {
         self.0.operator.load()
     }
 
-    // keep this internal (repr)
-    pub(crate) fn full_load_operator(&self) -> Option<Arc<Operator>> {
-        self.0.operator.load_full()
+    /// Returns the operator that pays for the next transaction,
+    /// which is picked from the operator pool when there is one.
+    pub(crate) fn select_operator(&self) -> Option<Arc<Operator>> {
+        self.0.operator.load().as_deref().map(Payer::select)
+    }
+
+    /// Returns how far the network's clock is ahead of the local clock (negative when it's behind).
+    ///
+    /// Unless set with [`set_clock_offset`](Self::set_clock_offset), this is estimated from the consensus timestamps of
+    /// records fetched with [`TransactionResponse::get_record`](crate::TransactionResponse::get_record),
+    /// and is zero until the first record arrives.
+    ///
+    /// When a transaction is rejected with `INVALID_TRANSACTION_START` (its valid start is in the future, so it never
+    /// reaches consensus and there's no record), the offset is re-estimated from the time a mirror node reports instead,
+    /// before the transaction is retried with a new transaction ID.
+    /// That estimate is up to a second low, since mirror nodes only report the time to the second.
+    ///
+    /// The offset is added to the local time whenever the client generates a transaction ID,
+    /// both while executing a request and when freezing a transaction.
+    #[must_use]
+    pub fn clock_offset(&self) -> time::Duration {
+        self.0.clock_offset.get()
+    }
+
+    /// Sets how far the network's clock is ahead of the local clock, instead of estimating it.
+    ///
+    /// `None` goes back to estimating the offset.
+    pub fn set_clock_offset(&self, offset: Option<time::Duration>) {
+        self.0.clock_offset.set(offset);
+    }
+
+    /// Returns the generator this client uses for the transaction IDs it generates.
+    ///
+    /// IDs for transactions that are signed ahead of time should come from here too,
+    /// so that they can't collide with the IDs the client generates itself.
+    #[must_use]
+    pub fn transaction_id_generator(&self) -> &TransactionIdGenerator {
+        &self.0.transaction_ids
+    }
+
+    pub(crate) fn load_transaction_id_generator(&self) -> Arc<TransactionIdGenerator> {
+        Arc::clone(&self.0.transaction_ids)
+    }
+
+    pub(crate) fn load_clock_offset(&self) -> Arc<ClockOffset> {
+        Arc::clone(&self.0.clock_offset)
+    }
+
+    /// Updates the clock offset estimate with a record that was just received.
+    pub(crate) fn observe_record(&self, record: &TransactionRecord) {
+        self.0.clock_offset.observe(
+            record.transaction_id.valid_start,
+            record.consensus_timestamp,
+            time::OffsetDateTime::now_utc(),
+        );
     }
 
     /// Send a ping to the given node.
@@ -604,6 +1225,48 @@ impl Client {
         Ok(())
     }
 
+    /// Send a ping to all nodes, returning how long each node took to answer, or why it didn't.
+    ///
+    /// Unlike [`ping_all`](Self::ping_all) every node is pinged, even if some of them fail.
+    pub async fn ping_all_report(&self) -> HashMap<AccountId, crate::Result<Duration>> {
+        self.ping_all_report_with_optional_timeout(None).await
+    }
+
+    /// Send a ping to all nodes, canceling each ping after `timeout` has elapsed.
+    ///
+    /// See [`ping_all_report`](Self::ping_all_report) for details.
+    pub async fn ping_all_report_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> HashMap<AccountId, crate::Result<Duration>> {
+        self.ping_all_report_with_optional_timeout(Some(timeout)).await
+    }
+
+    async fn ping_all_report_with_optional_timeout(
+        &self,
+        timeout: Option<Duration>,
+    ) -> HashMap<AccountId, crate::Result<Duration>> {
+        let node_ids = self.net().0.load().node_ids().to_vec();
+
+        let pings = node_ids.into_iter().map(|node_account_id| async move {
+            let start = Instant::now();
+            let result = PingQuery::new(node_account_id).execute(self, timeout).await;
+
+            (node_account_id, result.map(|()| start.elapsed()))
+        });
+
+        futures_util::future::join_all(pings).await.into_iter().collect()
+    }
+
+    /// Returns what the client currently knows about every node in its network.
+    ///
+    /// This includes which nodes the client has stopped using (and until when), which is useful for monitoring.
+    /// Nodes are returned in no particular order.
+    #[must_use]
+    pub fn node_stats(&self) -> Vec<NodeStats> {
+        self.net().0.load().node_stats()
+    }
+
     /// Returns the frequency at which the network will update (if it will update at all).
     #[must_use = "this function has no side-effects"]
     pub fn network_update_period(&self) -> Option<Duration> {
@@ -615,7 +1278,7 @@ impl Client {
     /// Note that network updates will not affect any in-flight requests.
     pub fn set_network_update_period(&self, period: Option<Duration>) {
         self.0.network_update_tx.send_if_modified(|place| {
-            let changed = *place == period;
+            let changed = *place != period;
             if changed {
                 *place = period;
             }
@@ -624,15 +1287,183 @@ impl Client {
         });
     }
 
+    /// Closes this client (and every clone of it).
+    ///
+    /// Network updates stop, and requests that are still in flight are cancelled, failing with [`Error::ClientClosed`].
+    /// Once every one of them has finished, the connections to all consensus and mirror nodes are closed.
+    ///
+    /// Mirror subscriptions (such as a [`TopicMessageQuery`](crate::TopicMessageQuery) stream) aren't waited for,
+    /// they end with [`Error::ClientClosed`] the next time they're polled.
+    ///
+    /// Any later request made with this client also fails with [`Error::ClientClosed`], closing it again does nothing.
+    pub async fn close(&self) {
+        self.0.closed.send_replace(true);
+
+        let mut in_flight = self.0.in_flight.subscribe();
+
+        // the sender lives as long as `self`, so this can't fail.
+        let _ = in_flight.wait_for(|&count| count == 0).await;
+
+        // the channels go away with the network data that owns them.
+        self.net().disconnect();
+        self.mirrornet().rcu(|old| MirrorNetworkData::disconnected(old));
+    }
+
+    /// Returns `true` if this client has been [closed](Self::close).
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        *self.0.closed.borrow()
+    }
+
+    /// Marks the start of a request, which lasts as long as the returned guard.
+    ///
+    /// This is also where network updates get started, since it's the first point we know there's a runtime to spawn them on.
+    ///
+    /// # Errors
+    /// - [`Error::ClientClosed`] if the client has been closed.
+    pub(crate) fn begin_request(&self) -> crate::Result<InFlight> {
+        // counting the request first means `close` can't miss it: either it sees the request, or the request sees `closed`.
+        self.0.in_flight.send_modify(|count| *count += 1);
+
+        let in_flight = InFlight { client: self.clone(), closed: self.0.closed.subscribe() };
+
+        if self.is_closed() {
+            return Err(Error::ClientClosed);
+        }
+
+        self.start_network_update();
+
+        Ok(in_flight)
+    }
+
+    /// Marks the start of a subscription, which should end as soon as the returned receiver sees the client closed.
+    ///
+    /// Unlike requests, subscriptions aren't counted as in flight, since they last until the caller drops them.
+    ///
+    /// # Errors
+    /// - [`Error::ClientClosed`] if the client has been closed.
+    pub(crate) fn begin_subscription(&self) -> crate::Result<watch::Receiver<bool>> {
+        if self.is_closed() {
+            return Err(Error::ClientClosed);
+        }
+
+        self.start_network_update();
+
+        Ok(self.0.closed.subscribe())
+    }
+
+    fn start_network_update(&self) {
+        if self.network_update_period().is_some()
+            && !self.0.network_update_started.swap(true, Ordering::AcqRel)
+        {
+            network::managed::spawn_network_update(
+                self.0.network.clone(),
+                self.0.network_update_tx.subscribe(),
+                self.0.closed.subscribe(),
+            );
+        }
+    }
+
     /// Returns the Account ID for the operator.
+    ///
+    /// When an operator pool is set, this is the first account of the pool
+    /// (which isn't necessarily the one that pays next), see [`get_operator_pool_account_ids`](Self::get_operator_pool_account_ids).
     #[must_use]
     pub fn get_operator_account_id(&self) -> Option<AccountId> {
-        self.load_operator().as_deref().map(|it| it.account_id)
+        self.load_operator().as_deref().map(|it| it.first().account_id)
     }
 
     /// Returns the `PublicKey` for the current operator.
+    ///
+    /// When an operator pool is set, this is the key of the first account of the pool.
     #[must_use]
     pub fn get_operator_public_key(&self) -> Option<PublicKey> {
-        self.load_operator().as_deref().map(|it| it.signer.public_key())
+        self.load_operator().as_deref().map(|it| it.first().signer.public_key())
+    }
+}
+
+/// A request that's in flight on a [`Client`], see [`Client::begin_request`].
+pub(crate) struct InFlight {
+    client: Client,
+    closed: watch::Receiver<bool>,
+}
+
+impl InFlight {
+    /// Runs `future` to completion, unless the client is closed first.
+    ///
+    /// # Errors
+    /// - [`Error::ClientClosed`] if the client is closed before `future` completes.
+    pub(crate) async fn run<T>(
+        &mut self,
+        future: impl Future<Output = crate::Result<T>>,
+    ) -> crate::Result<T> {
+        tokio::select! {
+            it = future => it,
+            _ = self.closed.wait_for(|closed| *closed) => Err(Error::ClientClosed),
+        }
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.client.0.in_flight.send_modify(|count| *count -= 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Client;
+
+    #[test]
+    fn client_without_runtime() {
+        // nothing gets spawned until the client is first used.
+        let client = Client::for_testnet();
+
+        assert_eq!(client.network_update_period(), Some(Duration::from_secs(24 * 60 * 60)));
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod mock_tests {
+    use std::time::Duration;
+
+    use assert_matches::assert_matches;
+
+    use crate::testing::tests::{
+        count,
+        fast_client,
+        transfer,
+    };
+    use crate::testing::{
+        MockNetwork,
+        MockResponse,
+    };
+    use crate::Error;
+
+    #[tokio::test]
+    async fn close_cancels_in_flight_requests() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let client = fast_client(&network);
+
+        network.nodes()[0].respond(MockResponse::busy().with_delay(Duration::from_secs(60)));
+
+        let in_flight = tokio::spawn({
+            let client = client.clone();
+            async move { transfer().execute(&client).await }
+        });
+
+        // give the request a chance to reach the node.
+        while count(&network, "cryptoTransfer") == 0 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        client.close().await;
+
+        assert!(client.is_closed());
+        assert_matches!(in_flight.await.unwrap(), Err(Error::ClientClosed));
+        assert_matches!(transfer().execute(&client).await, Err(Error::ClientClosed));
+        assert_matches!(client.ping_all().await, Err(Error::ClientClosed));
     }
 }
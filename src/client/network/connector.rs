@@ -0,0 +1,397 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::atomic::{
+    AtomicBool,
+    Ordering,
+};
+use std::task::{
+    Context,
+    Poll,
+};
+use std::time::Duration;
+
+use futures_core::future::BoxFuture;
+use hyper::Uri;
+use hyper_openssl::client::legacy::{
+    HttpsConnector,
+    MaybeHttpsStream,
+};
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::rt::TokioIo;
+use openssl::ssl::{
+    SslConnector,
+    SslMethod,
+    SslVerifyMode,
+};
+use openssl::x509::X509StoreContextRef;
+use sha2::{
+    Digest,
+    Sha384,
+};
+use tokio::net::TcpStream;
+use tower::{
+    Service,
+    ServiceExt,
+};
+use triomphe::Arc;
+
+use super::proxy::ProxyConnector;
+use super::{
+    HostAndPort,
+    Proxy,
+    TlsMode,
+};
+use crate::error::BoxStdError;
+
+pub(super) type NodeStream = MaybeHttpsStream<TokioIo<TcpStream>>;
+
+/// Connects to a single consensus node.
+///
+/// The `Uri` tonic hands us is ignored, instead every address the node is known by is tried in order,
+/// over TLS and/or plaintext depending on the [`TlsMode`], through the [`Proxy`] if there is one.
+#[derive(Clone)]
+pub(super) struct NodeConnector {
+    addresses: Vec<HostAndPort>,
+    mode: TlsMode,
+    tls_certificate_hash: Option<Arc<Vec<u8>>>,
+    https: HttpsConnector<ProxyConnector>,
+}
+
+impl NodeConnector {
+    pub(super) fn new<'a>(
+        addresses: impl IntoIterator<Item = &'a HostAndPort>,
+        mode: TlsMode,
+        tls_certificate_hash: Option<&[u8]>,
+        proxy: Option<Arc<Proxy>>,
+    ) -> Self {
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_nodelay(true);
+        http.set_keepalive(Some(Duration::from_secs(10)));
+
+        let mut ssl = SslConnector::builder(SslMethod::tls()).unwrap();
+        ssl.set_alpn_protos(b"\x02h2").unwrap();
+
+        let https = HttpsConnector::with_connector(ProxyConnector::new(http, proxy), ssl).unwrap();
+
+        Self {
+            addresses: addresses.into_iter().cloned().collect(),
+            mode,
+            tls_certificate_hash: tls_certificate_hash.map(|it| Arc::new(it.to_vec())),
+            https,
+        }
+    }
+
+    /// Returns a connector that verifies certificates for a single connection attempt,
+    /// and sets `rejected` if the node presents one we don't trust.
+    fn https(&self, rejected: &Arc<AtomicBool>) -> HttpsConnector<ProxyConnector> {
+        let mut https = self.https.clone();
+        let hash = self.tls_certificate_hash.clone();
+        let rejected = Arc::clone(rejected);
+
+        https.set_callback(move |config, _| {
+            if hash.is_some() {
+                // node certificates are self-signed and aren't issued for any particular host name,
+                // the address book hash is what we actually trust.
+                config.set_verify_hostname(false);
+            }
+
+            let hash = hash.clone();
+            let rejected = Arc::clone(&rejected);
+
+            config.set_verify_callback(SslVerifyMode::PEER, move |preverified, ctx| {
+                // without a hash we have nothing to pin against, so we fall back to the system trust store.
+                let trusted = match &hash {
+                    Some(hash) => verify_certificate_hash(hash, ctx),
+                    None => preverified,
+                };
+
+                if !trusted {
+                    rejected.store(true, Ordering::Relaxed);
+                }
+
+                trusted
+            });
+
+            Ok(())
+        });
+
+        https
+    }
+}
+
+impl Service<Uri> for NodeConnector {
+    type Response = NodeStream;
+    type Error = BoxStdError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: Uri) -> Self::Future {
+        let addresses = self.addresses.clone();
+        let mode = self.mode;
+        let rejected = Arc::new(AtomicBool::new(false));
+        let mut https = self.https(&rejected);
+
+        Box::pin(async move {
+            let mut last_error: Option<BoxStdError> = None;
+
+            if mode != TlsMode::Plaintext {
+                for address in &addresses {
+                    let uri = format!("https://{}", address.with_tls_port());
+                    match connect(&mut https, uri).await {
+                        Ok(stream) => return Ok(stream),
+                        Err(e) => {
+                            log::debug!("TLS connection to node at `{address}` failed: {e}");
+                            last_error = Some(e);
+                        }
+                    }
+                }
+            }
+
+            if mode != TlsMode::Tls {
+                if mode == TlsMode::TlsWithFallback {
+                    // a node that speaks TLS with a certificate we don't trust is exactly what
+                    // a man in the middle looks like, so that's not something to fall back from.
+                    if rejected.load(Ordering::Relaxed) {
+                        log::warn!(
+                            "not falling back to plaintext for node at {addresses:?}: it presented an untrusted TLS certificate"
+                        );
+
+                        return Err(
+                            last_error.unwrap_or_else(|| "untrusted TLS certificate".into())
+                        );
+                    }
+
+                    log::warn!("falling back to a plaintext connection for node at {addresses:?}");
+                }
+
+                for address in &addresses {
+                    match connect(&mut https, format!("http://{address}")).await {
+                        Ok(stream) => return Ok(stream),
+                        Err(e) => last_error = Some(e),
+                    }
+                }
+            }
+
+            Err(last_error.unwrap_or_else(|| "node has no addresses to connect to".into()))
+        })
+    }
+}
+
+async fn connect(
+    https: &mut HttpsConnector<ProxyConnector>,
+    uri: String,
+) -> Result<NodeStream, BoxStdError> {
+    let uri = Uri::from_maybe_shared(uri)?;
+
+    https.ready().await?.call(uri).await
+}
+
+fn verify_certificate_hash(expected: &[u8], ctx: &mut X509StoreContextRef) -> bool {
+    // only the node's own (leaf) certificate is pinned, anything above it is irrelevant.
+    if ctx.error_depth() != 0 {
+        return true;
+    }
+
+    let Some(pem) = ctx.current_cert().and_then(|cert| cert.to_pem().ok()) else {
+        return false;
+    };
+
+    let matches = certificate_hash_matches(expected, &pem);
+
+    if !matches {
+        log::warn!("node presented a TLS certificate that doesn't match the address book");
+    }
+
+    matches
+}
+
+/// Returns `true` if `expected` is the SHA-384 hash of `pem`.
+///
+/// The address book stores the hash as a hex string, but a raw digest is accepted too.
+pub(super) fn certificate_hash_matches(expected: &[u8], pem: &[u8]) -> bool {
+    let actual = Sha384::digest(pem);
+
+    if expected == actual.as_slice() {
+        return true;
+    }
+
+    std::str::from_utf8(expected)
+        .map(|it| it.trim().eq_ignore_ascii_case(&hex::encode(actual)))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::net::TcpListener;
+
+    use hyper::Uri;
+    use hyper_openssl::client::legacy::MaybeHttpsStream;
+    use openssl::asn1::Asn1Time;
+    use openssl::bn::BigNum;
+    use openssl::ec::{
+        EcGroup,
+        EcKey,
+    };
+    use openssl::hash::MessageDigest;
+    use openssl::nid::Nid;
+    use openssl::pkey::{
+        PKey,
+        Private,
+    };
+    use openssl::ssl::{
+        SslAcceptor,
+        SslMethod,
+    };
+    use openssl::x509::{
+        X509NameBuilder,
+        X509,
+    };
+    use sha2::{
+        Digest,
+        Sha384,
+    };
+    use tower::{
+        Service,
+        ServiceExt,
+    };
+
+    use super::{
+        certificate_hash_matches,
+        NodeConnector,
+    };
+    use crate::client::network::{
+        HostAndPort,
+        TlsMode,
+    };
+
+    fn self_signed() -> (X509, PKey<Private>) {
+        let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+        let key = PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap();
+
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", "node").unwrap();
+        let name = name.build();
+
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        builder
+            .set_serial_number(&BigNum::from_u32(1).unwrap().to_asn1_integer().unwrap())
+            .unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder.set_pubkey(&key).unwrap();
+        builder.set_not_before(&Asn1Time::days_from_now(0).unwrap()).unwrap();
+        builder.set_not_after(&Asn1Time::days_from_now(1).unwrap()).unwrap();
+        builder.sign(&key, MessageDigest::sha256()).unwrap();
+
+        (builder.build(), key)
+    }
+
+    /// Spawns a TLS server that completes handshakes and nothing else, returning its port.
+    fn spawn_tls_server(cert: &X509, key: &PKey<Private>) -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let mut acceptor = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls()).unwrap();
+        acceptor.set_certificate(cert).unwrap();
+        acceptor.set_private_key(key).unwrap();
+        let acceptor = acceptor.build();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let _ = acceptor.accept(stream);
+            }
+        });
+
+        port
+    }
+
+    fn hex_hash(cert: &X509) -> Vec<u8> {
+        hex::encode(Sha384::digest(cert.to_pem().unwrap())).into_bytes()
+    }
+
+    async fn connect(
+        connector: &mut NodeConnector,
+    ) -> Result<super::NodeStream, crate::error::BoxStdError> {
+        connector.ready().await?.call(Uri::from_static("https://127.0.0.1")).await
+    }
+
+    #[test]
+    fn hash_matches_hex_and_raw() {
+        let pem = b"-----BEGIN CERTIFICATE-----";
+        let digest = Sha384::digest(pem);
+
+        assert!(certificate_hash_matches(digest.as_slice(), pem));
+        assert!(certificate_hash_matches(hex::encode(digest).as_bytes(), pem));
+        assert!(certificate_hash_matches(hex::encode_upper(digest).as_bytes(), pem));
+        assert!(!certificate_hash_matches(b"00", pem));
+    }
+
+    #[tokio::test]
+    async fn tls_with_matching_hash() {
+        let (cert, key) = self_signed();
+        let port = spawn_tls_server(&cert, &key);
+
+        let addresses =
+            BTreeSet::from([format!("127.0.0.1:{port}").parse::<HostAndPort>().unwrap()]);
+        let mut connector =
+            NodeConnector::new(&addresses, TlsMode::Tls, Some(&hex_hash(&cert)), None);
+
+        assert!(matches!(connect(&mut connector).await, Ok(MaybeHttpsStream::Https(_))));
+    }
+
+    #[tokio::test]
+    async fn tls_with_mismatched_hash() {
+        let (cert, key) = self_signed();
+        let (other, _) = self_signed();
+        let port = spawn_tls_server(&cert, &key);
+
+        let addresses =
+            BTreeSet::from([format!("127.0.0.1:{port}").parse::<HostAndPort>().unwrap()]);
+        let mut connector =
+            NodeConnector::new(&addresses, TlsMode::Tls, Some(&hex_hash(&other)), None);
+
+        assert!(connect(&mut connector).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn tls_with_fallback_uses_plaintext() {
+        let (other, _) = self_signed();
+
+        // a node that doesn't speak TLS at all.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                drop(stream);
+            }
+        });
+
+        let addresses =
+            BTreeSet::from([format!("127.0.0.1:{port}").parse::<HostAndPort>().unwrap()]);
+        let mut connector =
+            NodeConnector::new(&addresses, TlsMode::TlsWithFallback, Some(&hex_hash(&other)), None);
+
+        assert!(matches!(connect(&mut connector).await, Ok(MaybeHttpsStream::Http(_))));
+    }
+
+    #[tokio::test]
+    async fn tls_with_fallback_rejects_mismatched_hash() {
+        let (cert, key) = self_signed();
+        let (other, _) = self_signed();
+        let port = spawn_tls_server(&cert, &key);
+
+        let addresses =
+            BTreeSet::from([format!("127.0.0.1:{port}").parse::<HostAndPort>().unwrap()]);
+        let mut connector =
+            NodeConnector::new(&addresses, TlsMode::TlsWithFallback, Some(&hex_hash(&other)), None);
+
+        assert!(connect(&mut connector).await.is_err());
+    }
+}
@@ -1,12 +1,22 @@
 use std::time::Duration;
 
+use parking_lot::RwLock;
 use rand::Rng;
 use tokio::sync::watch;
 use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
 
 use super::mirror::MirrorNetwork;
 use super::Network;
-use crate::NodeAddressBookQuery;
+use crate::{
+    NodeAddressBook,
+    NodeAddressBookQuery,
+};
+
+type AddressBookCallback = Arc<dyn Fn(&NodeAddressBook) + Send + Sync>;
 
 #[derive(Clone)]
 pub(crate) struct ManagedNetwork(Arc<ManagedNetworkInner>);
@@ -20,7 +30,11 @@ impl ManagedNetwork {
         mirror: MirrorNetwork,
         // first_update_delay: Duration,
     ) -> Self {
-        Self(Arc::new(ManagedNetworkInner { primary, mirror }))
+        Self(Arc::new(ManagedNetworkInner {
+            primary,
+            mirror,
+            address_book_callback: RwLock::new(None),
+        }))
     }
 
     pub(crate) fn mainnet() -> Self {
@@ -49,18 +63,48 @@ pub(crate) struct ManagedNetworkInner {
     pub(crate) primary: Network,
     //
     pub(crate) mirror: MirrorNetwork,
+    address_book_callback: RwLock<Option<AddressBookCallback>>,
 }
 
-pub(crate) fn spawn_network_update(
-    network: ManagedNetwork,
-    initial_update_interval: Option<Duration>,
-) -> watch::Sender<Option<Duration>> {
-    let (tx, rx) = watch::channel(initial_update_interval);
+impl ManagedNetworkInner {
+    /// Updates the primary network from `address_book`, and then lets the callback (if any) know about it.
+    pub(crate) fn update_from_address_book(&self, address_book: &NodeAddressBook) {
+        self.primary.update_from_address_book(address_book);
+
+        // the lock isn't held during the call, so the callback can replace (or clear) itself.
+        let callback = self.address_book_callback.read().clone();
 
-    // note: this 100% dies if there's no runtime.
-    tokio::task::spawn(update_network(network, rx));
+        if let Some(callback) = callback {
+            callback(address_book);
+        }
+    }
+
+    pub(crate) fn set_address_book_callback<F>(&self, callback: F)
+    where
+        F: Fn(&NodeAddressBook) + Send + Sync + 'static,
+    {
+        *self.address_book_callback.write() =
+            Some(Arc::new(callback).unsize(Coercion!(to dyn Fn(&NodeAddressBook) + Send + Sync)));
+    }
 
-    tx
+    pub(crate) fn clear_address_book_callback(&self) {
+        *self.address_book_callback.write() = None;
+    }
+}
+
+/// Spawns the task that keeps `network` up to date, which runs until `closed` is `true` (or dropped alongside the interval's sender).
+pub(crate) fn spawn_network_update(
+    network: ManagedNetwork,
+    update_interval_rx: watch::Receiver<Option<Duration>>,
+    mut closed: watch::Receiver<bool>,
+) {
+    // note: this 100% dies if there's no runtime, which is why the client only calls it once it's executing something.
+    tokio::task::spawn(async move {
+        tokio::select! {
+            () = update_network(network, update_interval_rx) => {}
+            _ = closed.wait_for(|closed| *closed) => log::debug!("client network update shutdown: client closed"),
+        }
+    });
 }
 
 // note: This keeps the `ManagedNetwork` alive (has a strong reference),
@@ -78,11 +122,9 @@ async fn update_network(
 
         // note: ideally we'd have a `select!` on the channel closing, but, we can't
         // since there's no `async fn closed()`, and honestly, I'm not 100% certain these futures are cancel safe.
-        match NodeAddressBookQuery::new()
-            .execute_mirrornet(network.mirror.load().channel(), None)
-            .await
+        match NodeAddressBookQuery::new().execute_mirrornet(network.mirror.load_full(), None).await
         {
-            Ok(it) => network.primary.update_from_address_book(&it),
+            Ok(it) => network.update_from_address_book(&it),
             Err(e) => {
                 log::warn!("{e:?}");
             }
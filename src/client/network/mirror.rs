@@ -1,25 +1,50 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::borrow::Cow;
+use std::fmt;
 use std::ops::Deref;
-use std::time::Duration;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+use std::time::{
+    Duration,
+    Instant,
+};
 
 use hyper::Uri;
 use hyper_openssl::client::legacy::HttpsConnector;
 use hyper_util::client::legacy::connect::HttpConnector;
 use once_cell::sync::OnceCell;
+use openssl::pkey::PKey;
 use openssl::ssl::{
     SslConnector,
+    SslConnectorBuilder,
     SslMethod,
     SslVerifyMode,
 };
+use openssl::x509::store::X509StoreBuilder;
+use openssl::x509::X509;
+use parking_lot::RwLock;
+use time::format_description::well_known::Rfc2822;
+use time::OffsetDateTime;
 use tonic::transport::{
     Channel,
     Endpoint,
 };
+use tower::ServiceExt;
 use triomphe::Arc;
 
-use crate::ArcSwap;
+use super::proxy::ProxyConnector;
+use super::{
+    NodeBackoff,
+    NodeHealth,
+    Proxy,
+};
+use crate::{
+    ArcSwap,
+    Error,
+};
 
 pub(crate) const MAINNET: &str = "mainnet-public.mirrornode.hedera.com:443";
 
@@ -27,6 +52,11 @@ pub(crate) const TESTNET: &str = "testnet.mirrornode.hedera.com:443";
 
 pub(crate) const PREVIEWNET: &str = "previewnet.mirrornode.hedera.com:443";
 
+/// Any cheap REST API request will do, only the response's `Date` header is used.
+const TIME_PATH: &str = "/api/v1/network/exchangerate";
+
+const TIME_TIMEOUT: Duration = Duration::from_secs(5);
+
 #[derive(Default)]
 pub(crate) struct MirrorNetwork(ArcSwap<MirrorNetworkData>);
 
@@ -61,52 +91,525 @@ impl MirrorNetwork {
     }
 }
 
-#[derive(Clone, Default)]
+/// Connection settings for a single mirror node.
+///
+/// By default mirror nodes are connected to over TLS, verified against the system trust store.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MirrorEndpoint {
+    address: Cow<'static, str>,
+    plaintext: bool,
+    ca_certificate: Option<Vec<u8>>,
+    client_identity: Option<(Vec<u8>, Vec<u8>)>,
+    sni_hostname: Option<String>,
+}
+
+impl MirrorEndpoint {
+    /// Create a new endpoint for the mirror node at `address` (`host:port`).
+    #[must_use]
+    pub fn new(address: impl Into<String>) -> Self {
+        Self::from_cow(Cow::Owned(address.into()))
+    }
+
+    fn from_cow(address: Cow<'static, str>) -> Self {
+        Self {
+            address,
+            plaintext: false,
+            ca_certificate: None,
+            client_identity: None,
+            sni_hostname: None,
+        }
+    }
+
+    /// Returns the address (`host:port`) of the mirror node.
+    #[must_use]
+    pub fn get_address(&self) -> &str {
+        &self.address
+    }
+
+    /// Returns `true` if this endpoint is connected to without TLS.
+    #[must_use]
+    pub fn get_plaintext(&self) -> bool {
+        self.plaintext
+    }
+
+    /// Sets whether to connect without TLS, this is mostly useful for local mirror nodes.
+    ///
+    /// When `true` all the other TLS settings are ignored.
+    pub fn plaintext(&mut self, plaintext: bool) -> &mut Self {
+        self.plaintext = plaintext;
+        self
+    }
+
+    /// Returns the PEM encoded CA bundle used to verify the mirror node, if any.
+    #[must_use]
+    pub fn get_ca_certificate_pem(&self) -> Option<&[u8]> {
+        self.ca_certificate.as_deref()
+    }
+
+    /// Sets a PEM encoded CA bundle to verify the mirror node with, instead of the system trust store.
+    pub fn ca_certificate_pem(&mut self, pem: impl Into<Vec<u8>>) -> &mut Self {
+        self.ca_certificate = Some(pem.into());
+        self
+    }
+
+    /// Returns the PEM encoded client certificate chain presented to the mirror node, if any.
+    #[must_use]
+    pub fn get_client_certificate_pem(&self) -> Option<&[u8]> {
+        self.client_identity.as_ref().map(|(certificate, _)| certificate.as_slice())
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn get_client_key_pem(&self) -> Option<&[u8]> {
+        self.client_identity.as_ref().map(|(_, key)| key.as_slice())
+    }
+
+    /// Sets a PEM encoded client certificate chain and private key to authenticate with (mTLS).
+    pub fn client_certificate_pem(
+        &mut self,
+        certificate: impl Into<Vec<u8>>,
+        private_key: impl Into<Vec<u8>>,
+    ) -> &mut Self {
+        self.client_identity = Some((certificate.into(), private_key.into()));
+        self
+    }
+
+    /// Returns the host name sent via SNI and verified against the mirror node's certificate, if overridden.
+    #[must_use]
+    pub fn get_sni_hostname(&self) -> Option<&str> {
+        self.sni_hostname.as_deref()
+    }
+
+    /// Sets the host name to send via SNI and to verify the mirror node's certificate against.
+    ///
+    /// By default the host from the address is used.
+    pub fn sni_hostname(&mut self, hostname: impl Into<String>) -> &mut Self {
+        self.sni_hostname = Some(hostname.into());
+        self
+    }
+
+    /// Builds the TLS configuration for this endpoint.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if the CA bundle, client certificate or private key are invalid.
+    fn ssl_connector(&self) -> crate::Result<SslConnectorBuilder> {
+        let mut ssl_builder =
+            SslConnector::builder(SslMethod::tls()).map_err(Error::basic_parse)?;
+        ssl_builder.set_verify(SslVerifyMode::PEER);
+        ssl_builder.set_alpn_protos(b"\x02h2").map_err(Error::basic_parse)?;
+
+        if let Some(pem) = &self.ca_certificate {
+            let certificates = X509::stack_from_pem(pem).map_err(Error::basic_parse)?;
+
+            if certificates.is_empty() {
+                return Err(Error::basic_parse("CA bundle contains no certificates"));
+            }
+
+            let mut store = X509StoreBuilder::new().map_err(Error::basic_parse)?;
+            for certificate in certificates {
+                store.add_cert(certificate).map_err(Error::basic_parse)?;
+            }
+
+            ssl_builder.set_cert_store(store.build());
+        }
+
+        if let Some((certificate, private_key)) = &self.client_identity {
+            let mut chain =
+                X509::stack_from_pem(certificate).map_err(Error::basic_parse)?.into_iter();
+            let leaf = chain.next().ok_or_else(|| {
+                Error::basic_parse("client certificate chain contains no certificates")
+            })?;
+
+            ssl_builder.set_certificate(&leaf).map_err(Error::basic_parse)?;
+            for certificate in chain {
+                ssl_builder.add_extra_chain_cert(certificate).map_err(Error::basic_parse)?;
+            }
+
+            let private_key =
+                PKey::private_key_from_pem(private_key).map_err(Error::basic_parse)?;
+            ssl_builder.set_private_key(&private_key).map_err(Error::basic_parse)?;
+            ssl_builder.check_private_key().map_err(Error::basic_parse)?;
+        }
+
+        Ok(ssl_builder)
+    }
+
+    /// Checks that the TLS settings of this endpoint are usable.
+    pub(crate) fn validate(&self) -> crate::Result<()> {
+        if self.plaintext {
+            return Ok(());
+        }
+
+        self.ssl_connector().map(drop)
+    }
+
+    fn channel(&self, proxy: Option<&Arc<Proxy>>) -> Channel {
+        let endpoint = if self.plaintext {
+            Endpoint::from_shared(format!("http://{}", self.address))
+        } else {
+            Endpoint::from_shared(format!("https://{}", self.address))
+        };
+
+        let endpoint = endpoint
+            .unwrap()
+            .connect_timeout(Duration::from_secs(10))
+            .keep_alive_timeout(Duration::from_secs(10))
+            .keep_alive_while_idle(true)
+            .tcp_keepalive(Some(Duration::from_secs(10)));
+
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+
+        if self.plaintext {
+            return match proxy {
+                Some(proxy) => endpoint
+                    .connect_with_connector_lazy(ProxyConnector::new(http, Some(proxy.clone()))),
+                None => endpoint.connect_lazy(),
+            };
+        }
+
+        // note: endpoints are validated before they're added to the network.
+        let ssl_builder = self.ssl_connector().unwrap();
+
+        // Create HTTPS connector with OpenSSL
+        let http = ProxyConnector::new(http, proxy.cloned());
+        let mut https = HttpsConnector::with_connector(http, ssl_builder).unwrap();
+
+        if let Some(hostname) = self.sni_hostname.clone() {
+            https.set_callback(move |config, _| {
+                // the uri's host would otherwise be used for both SNI and hostname verification.
+                config.set_use_server_name_indication(false);
+                config.set_verify_hostname(false);
+                config.set_hostname(&hostname)?;
+                config.param_mut().set_host(&hostname)?;
+                Ok(())
+            });
+        }
+
+        endpoint.connect_with_connector_lazy(https)
+    }
+}
+
+impl fmt::Debug for MirrorEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // note: the private key is intentionally omitted.
+        f.debug_struct("MirrorEndpoint")
+            .field("address", &self.address)
+            .field("plaintext", &self.plaintext)
+            .field("ca_certificate", &self.ca_certificate.is_some())
+            .field("client_certificate", &self.client_identity.is_some())
+            .field("sni_hostname", &self.sni_hostname)
+            .finish()
+    }
+}
+
+#[derive(Default)]
 pub(crate) struct MirrorNetworkData {
-    addresses: Vec<Cow<'static, str>>,
-    channel: OnceCell<Channel>,
+    endpoints: Vec<MirrorEndpoint>,
+    channels: Box<[OnceCell<Channel>]>,
+    // Health stuff has to be in an Arc because it needs to stick around even if the endpoints change.
+    health: Box<[Arc<RwLock<NodeHealth>>]>,
+    backoff: RwLock<NodeBackoff>,
+    // round-robin cursor, so that load is spread across all the healthy mirror nodes.
+    next: AtomicUsize,
+    proxy: Option<Arc<Proxy>>,
 }
 
 impl MirrorNetworkData {
     pub(crate) fn from_addresses(addresses: Vec<Cow<'static, str>>) -> Self {
-        Self { addresses, channel: OnceCell::new() }
+        Self::with_addresses(&Self::default(), addresses)
     }
 
     pub(crate) fn from_static(network: &[&'static str]) -> Self {
-        let addresses = network.iter().map(|&addr| Cow::Borrowed(addr)).collect();
-
-        Self { addresses, channel: OnceCell::new() }
-    }
-
-    pub(crate) fn channel(&self) -> Channel {
-        self.channel
-            .get_or_init(|| {
-                let endpoint = self.addresses.iter().next().unwrap();
-                let uri = format!("https://{endpoint}");
-                let uri_parsed = Uri::from_maybe_shared(uri).unwrap();
-
-                // Configure OpenSSL
-                let mut ssl_builder = SslConnector::builder(SslMethod::tls()).unwrap();
-                ssl_builder.set_verify(SslVerifyMode::PEER);
-                ssl_builder.set_alpn_protos(b"\x02h2").unwrap();
-
-                // Create HTTPS connector with OpenSSL
-                let mut http = HttpConnector::new();
-                http.enforce_http(false);
-                let https = HttpsConnector::with_connector(http, ssl_builder).unwrap();
-
-                Endpoint::from_shared(uri_parsed.to_string())
-                    .unwrap()
-                    .connect_timeout(Duration::from_secs(10))
-                    .keep_alive_timeout(Duration::from_secs(10))
-                    .keep_alive_while_idle(true)
-                    .tcp_keepalive(Some(Duration::from_secs(10)))
-                    .connect_with_connector_lazy(https)
+        Self::from_addresses(network.iter().map(|&addr| Cow::Borrowed(addr)).collect())
+    }
+
+    /// Creates a new mirror network with the given addresses (using the default connection settings),
+    /// reusing the channels and health of any address that's also in `old`.
+    pub(crate) fn with_addresses(old: &Self, addresses: Vec<Cow<'static, str>>) -> Self {
+        Self::with_endpoints(old, addresses.into_iter().map(MirrorEndpoint::from_cow).collect())
+    }
+
+    /// Creates a new mirror network with the given endpoints,
+    /// reusing the channels and health of any endpoint that's also in `old`.
+    ///
+    /// An endpoint whose settings changed keeps its health, but gets a new channel.
+    pub(crate) fn with_endpoints(old: &Self, endpoints: Vec<MirrorEndpoint>) -> Self {
+        let (channels, health) = endpoints
+            .iter()
+            .map(|endpoint| {
+                match old.endpoints.iter().position(|it| it.address == endpoint.address) {
+                    Some(index) if old.endpoints[index] == *endpoint => {
+                        (old.channels[index].clone(), old.health[index].clone())
+                    }
+                    Some(index) => (OnceCell::new(), old.health[index].clone()),
+                    None => (OnceCell::new(), Arc::default()),
+                }
             })
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        Self {
+            endpoints,
+            channels: channels.into_boxed_slice(),
+            health: health.into_boxed_slice(),
+            backoff: RwLock::new(*old.backoff.read()),
+            next: AtomicUsize::new(0),
+            proxy: old.proxy.clone(),
+        }
+    }
+
+    /// Creates a copy of `old` that connects through `proxy`.
+    ///
+    /// Every channel has to be recreated, but the endpoints (and their health) stay the same.
+    pub(crate) fn with_proxy(old: &Self, proxy: Option<Arc<Proxy>>) -> Self {
+        Self { proxy, ..Self::disconnected(old) }
+    }
+
+    /// Creates a copy of `old` without any of its channels, they're reconnected on demand.
+    pub(crate) fn disconnected(old: &Self) -> Self {
+        Self {
+            endpoints: old.endpoints.clone(),
+            channels: old.endpoints.iter().map(|_| OnceCell::new()).collect(),
+            health: old.health.clone(),
+            backoff: RwLock::new(*old.backoff.read()),
+            next: AtomicUsize::new(0),
+            proxy: old.proxy.clone(),
+        }
+    }
+
+    /// Returns the index of the next mirror node to use.
+    ///
+    /// Mirror nodes are picked round-robin, skipping over unhealthy ones,
+    /// if every mirror node is unhealthy the next one in line is used anyway.
+    ///
+    /// # Panics
+    /// - If there are no mirror nodes.
+    pub(crate) fn next_index(&self, now: Instant) -> usize {
+        assert!(!self.endpoints.is_empty(), "no mirror network addresses configured");
+
+        let len = self.endpoints.len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed) % len;
+
+        let index = (0..len)
+            .map(|offset| (start + offset) % len)
+            .find(|&index| self.is_healthy(index, now))
+            .unwrap_or(start);
+
+        // make sure the next call starts *after* whatever node we skipped to.
+        self.next.store(index + 1, Ordering::Relaxed);
+
+        index
+    }
+
+    pub(crate) fn has_healthy(&self, now: Instant) -> bool {
+        (0..self.endpoints.len()).any(|index| self.is_healthy(index, now))
+    }
+
+    pub(crate) fn is_healthy(&self, index: usize, now: Instant) -> bool {
+        self.health[index].read().is_healthy(now)
+    }
+
+    pub(crate) fn mark_healthy(&self, index: usize) {
+        self.health[index].write().mark_healthy(Instant::now());
+    }
+
+    pub(crate) fn mark_unhealthy(&self, index: usize) {
+        log::debug!("marking mirror node at `{}` as unhealthy", self.address(index));
+
+        self.health[index].write().mark_unhealthy(*self.backoff.read(), Instant::now());
+    }
+
+    pub(crate) fn address(&self, index: usize) -> &str {
+        &self.endpoints[index].address
+    }
+
+    pub(crate) fn channel(&self, index: usize) -> Channel {
+        self.channels[index]
+            .get_or_init(|| self.endpoints[index].channel(self.proxy.as_ref()))
             .clone()
     }
 
     pub(crate) fn addresses(&self) -> impl Iterator<Item = String> + '_ {
-        self.addresses.iter().cloned().map(Cow::into_owned)
+        self.endpoints.iter().map(|it| it.address.clone().into_owned())
+    }
+
+    pub(crate) fn endpoints(&self) -> &[MirrorEndpoint] {
+        &self.endpoints
+    }
+
+    /// Asks the next mirror node for the current time, which it sends in the `Date` header of its responses.
+    ///
+    /// Returns the mirror node's time and the local time halfway through the request,
+    /// or `None` if there are no mirror nodes or the request failed.
+    ///
+    /// The header only has second precision, so the mirror node's time is up to a second behind.
+    pub(crate) async fn fetch_time(&self) -> Option<(OffsetDateTime, OffsetDateTime)> {
+        if self.endpoints.is_empty() {
+            return None;
+        }
+
+        let index = self.next_index(Instant::now());
+
+        // the REST API is served on the same address as the gRPC API,
+        // the channel fills in the scheme and authority.
+        let request = hyper::Request::get(TIME_PATH).body(tonic::body::empty_body()).unwrap();
+
+        let sent_at = OffsetDateTime::now_utc();
+        let response =
+            tokio::time::timeout(TIME_TIMEOUT, self.channel(index).oneshot(request)).await;
+        let received_at = OffsetDateTime::now_utc();
+
+        let response = match response {
+            Ok(Ok(response)) => response,
+            Ok(Err(error)) => {
+                log::debug!(
+                    "couldn't fetch the time from mirror node at `{}`: {error}",
+                    self.address(index)
+                );
+                self.mark_unhealthy(index);
+                return None;
+            }
+            Err(_) => {
+                log::debug!(
+                    "timed out fetching the time from mirror node at `{}`",
+                    self.address(index)
+                );
+                self.mark_unhealthy(index);
+                return None;
+            }
+        };
+
+        self.mark_healthy(index);
+
+        let date = response.headers().get(hyper::header::DATE)?.to_str().ok()?;
+        let date = OffsetDateTime::parse(date, &Rfc2822).ok()?;
+
+        Some((date, sent_at + (received_at - sent_at) / 2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::time::Instant;
+
+    use super::{
+        MirrorEndpoint,
+        MirrorNetworkData,
+    };
+
+    fn network(addresses: &[&'static str]) -> MirrorNetworkData {
+        MirrorNetworkData::from_static(addresses)
+    }
+
+    #[test]
+    fn next_index_round_robins() {
+        let network = network(&["a:443", "b:443", "c:443"]);
+        let now = Instant::now();
+
+        let picked: Vec<_> = (0..6).map(|_| network.next_index(now)).collect();
+
+        assert_eq!(picked, [0, 1, 2, 0, 1, 2]);
+    }
+
+    #[test]
+    fn next_index_skips_unhealthy() {
+        let network = network(&["a:443", "b:443", "c:443"]);
+        network.mark_unhealthy(1);
+
+        let now = Instant::now();
+        let picked: Vec<_> = (0..4).map(|_| network.next_index(now)).collect();
+
+        assert_eq!(picked, [0, 2, 0, 2]);
+        assert!(network.has_healthy(now));
+    }
+
+    #[test]
+    fn next_index_all_unhealthy() {
+        let network = network(&["a:443", "b:443"]);
+        network.mark_unhealthy(0);
+        network.mark_unhealthy(1);
+
+        let now = Instant::now();
+
+        assert!(!network.has_healthy(now));
+        assert_eq!(network.next_index(now), 0);
+        assert_eq!(network.next_index(now), 1);
+    }
+
+    #[test]
+    fn mark_healthy_restores() {
+        let network = network(&["a:443", "b:443"]);
+        network.mark_unhealthy(0);
+        network.mark_healthy(0);
+
+        assert!(network.is_healthy(0, Instant::now()));
+    }
+
+    #[test]
+    fn with_addresses_keeps_health() {
+        let old = network(&["a:443", "b:443"]);
+        old.mark_unhealthy(1);
+
+        let new = MirrorNetworkData::with_addresses(
+            &old,
+            vec![Cow::Borrowed("b:443"), Cow::Borrowed("c:443")],
+        );
+
+        let now = Instant::now();
+
+        assert!(!new.is_healthy(0, now));
+        assert!(new.is_healthy(1, now));
+        assert_eq!(new.addresses().collect::<Vec<_>>(), ["b:443", "c:443"]);
+    }
+
+    #[test]
+    fn with_endpoints_keeps_health_when_settings_change() {
+        let old = network(&["a:443"]);
+        old.mark_unhealthy(0);
+
+        let mut endpoint = MirrorEndpoint::new("a:443");
+        endpoint.sni_hostname("mirror.internal");
+
+        let new = MirrorNetworkData::with_endpoints(&old, vec![endpoint.clone()]);
+
+        assert!(!new.is_healthy(0, Instant::now()));
+        assert_eq!(new.endpoints(), [endpoint]);
+    }
+
+    #[test]
+    fn validate_default_endpoint() {
+        assert!(MirrorEndpoint::new("a:443").validate().is_ok());
+    }
+
+    #[test]
+    fn validate_plaintext_ignores_tls_settings() {
+        let mut endpoint = MirrorEndpoint::new("127.0.0.1:5600");
+        endpoint.plaintext(true).ca_certificate_pem("not a certificate");
+
+        assert!(endpoint.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_invalid_ca_certificate() {
+        let mut endpoint = MirrorEndpoint::new("a:443");
+        endpoint.ca_certificate_pem("not a certificate");
+
+        assert!(endpoint.validate().is_err());
+    }
+
+    #[test]
+    fn validate_invalid_client_certificate() {
+        let mut endpoint = MirrorEndpoint::new("a:443");
+        endpoint.client_certificate_pem("not a certificate", "not a key");
+
+        assert!(endpoint.validate().is_err());
+    }
+
+    #[test]
+    fn debug_omits_private_key() {
+        let mut endpoint = MirrorEndpoint::new("a:443");
+        endpoint.client_certificate_pem("cert", "super secret key");
+
+        assert!(!format!("{endpoint:?}").contains("super secret key"));
     }
 }
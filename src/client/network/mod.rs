@@ -1,7 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
+mod connector;
 pub(super) mod managed;
 pub(super) mod mirror;
+mod proxy;
+mod selection;
+mod stats;
 
 use std::borrow::Cow;
 use std::collections::{
@@ -9,7 +13,7 @@ use std::collections::{
     HashMap,
 };
 use std::fmt;
-use std::net::Ipv4Addr;
+use std::net::IpAddr;
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 use std::time::{
@@ -18,7 +22,6 @@ use std::time::{
 };
 
 use backoff::backoff::Backoff;
-use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
 use rand::thread_rng;
 use tonic::transport::{
@@ -27,11 +30,24 @@ use tonic::transport::{
 };
 use triomphe::Arc;
 
+use self::connector::NodeConnector;
+pub use self::proxy::{
+    Proxy,
+    ProxyKind,
+};
+pub use self::selection::NodeSelectionStrategy;
+use self::stats::NodeCounters;
+pub use self::stats::{
+    LatencyPercentiles,
+    NodeHealthState,
+    NodeStats,
+};
 use crate::{
     AccountId,
     ArcSwap,
     Error,
     NodeAddressBook,
+    NodeEndpoint,
 };
 
 pub(crate) const MAINNET: &[(u64, &[&str])] = &[
@@ -86,6 +102,27 @@ pub(crate) const PREVIEWNET: &[(u64, &[&str])] = &[
     (9, &["6.previewnet.hedera.com", "34.125.23.49", "50.18.17.93", "20.150.136.89"]),
 ];
 
+/// How connections to consensus nodes are secured.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TlsMode {
+    /// Connect over plaintext on port 50211.
+    #[default]
+    Plaintext,
+
+    /// Connect over TLS on port 50212.
+    ///
+    /// When the node has a TLS certificate hash in the address book the node's certificate must match it,
+    /// otherwise the certificate is verified against the system trust store.
+    Tls,
+
+    /// Like [`Tls`](Self::Tls), but connect over plaintext if a TLS connection can't be established.
+    ///
+    /// There's no fallback when the node does speak TLS but presents a certificate that doesn't verify,
+    /// since that's what a man in the middle would look like.
+    TlsWithFallback,
+}
+
 #[derive(Default)]
 pub(crate) struct Network(pub(crate) ArcSwap<NetworkData>);
 
@@ -106,6 +143,10 @@ impl Network {
         Ok(NetworkData::from_addresses(addresses)?.into())
     }
 
+    pub(super) fn from_address_book(address_book: &NodeAddressBook) -> Self {
+        NetworkData::with_address_book(&NetworkData::default(), address_book).into()
+    }
+
     fn try_rcu<T: Into<Arc<NetworkData>>, E, F: FnMut(&Arc<NetworkData>) -> Result<T, E>>(
         &self,
         mut f: F,
@@ -147,6 +188,19 @@ impl Network {
         // todo: skip the updating whem `map` is the same and `connections` is the same.
         self.rcu(|old| NetworkData::with_address_book(old, address_book));
     }
+
+    pub(crate) fn update_tls_mode(&self, tls_mode: TlsMode) {
+        self.rcu(|old| old.with_tls_mode(tls_mode));
+    }
+
+    pub(crate) fn update_proxy(&self, proxy: Option<Arc<Proxy>>) {
+        self.rcu(|old| old.with_proxy(proxy.clone()));
+    }
+
+    /// Drops every channel, they're reconnected on demand.
+    pub(crate) fn disconnect(&self) {
+        self.rcu(|old| old.with_connection_settings(old.tls_mode, old.proxy.clone()));
+    }
 }
 
 impl From<NetworkData> for Network {
@@ -162,8 +216,10 @@ pub(crate) struct NetworkData {
     node_ids: Box<[AccountId]>,
     backoff: RwLock<NodeBackoff>,
     // Health stuff has to be in an Arc because it needs to stick around even if the map changes.
-    health: Box<[Arc<parking_lot::RwLock<NodeHealth>>]>,
+    health: Box<[Arc<parking_lot::RwLock<NodeState>>]>,
     connections: Box<[NodeConnection]>,
+    tls_mode: TlsMode,
+    proxy: Option<Arc<Proxy>>,
 }
 
 impl NetworkData {
@@ -192,6 +248,8 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            tls_mode: TlsMode::default(),
+            proxy: None,
         }
     }
 
@@ -208,27 +266,30 @@ impl NetworkData {
                 .service_endpoints
                 .iter()
                 .filter(|it| it.port() == NodeConnection::PLAINTEXT_PORT)
-                .map(|it| (*it.ip()).into())
+                .map(HostAndPort::from)
                 .collect();
 
+            let tls_certificate_hash = (!address.tls_certificate_hash.is_empty())
+                .then(|| address.tls_certificate_hash.clone().into_boxed_slice());
+
             // if the node is the exact same we want to reuse everything (namely the connections and `healthy`).
             // if the node has different routes then we still want to reuse `healthy` but replace the channel with a new channel.
             // if the node just flat out doesn't exist in `old`, we want to add the new node.
             // and, last but not least, if the node doesn't exist in `new` we want to get rid of it.
             let upsert = match old.map.get(&address.node_account_id) {
                 Some(&account) => {
+                    let old_connection = &old.connections[account];
                     let connection =
-                        match old.connections[account].addresses.symmetric_difference(&new).count()
-                        {
-                            0 => old.connections[account].clone(),
-                            _ => NodeConnection { addresses: new, channel: OnceCell::new() },
+                        match old_connection.addresses.symmetric_difference(&new).count() {
+                            0 if old_connection.tls_certificate_hash == tls_certificate_hash => {
+                                old_connection.clone()
+                            }
+                            _ => NodeConnection::new(new, tls_certificate_hash),
                         };
 
                     (old.health[account].clone(), connection)
                 }
-                None => {
-                    (Arc::default(), NodeConnection { addresses: new, channel: OnceCell::new() })
-                }
+                None => (Arc::default(), NodeConnection::new(new, tls_certificate_hash)),
             };
 
             map.insert(address.node_account_id, i);
@@ -243,6 +304,8 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            tls_mode: old.tls_mode,
+            proxy: old.proxy.clone(),
         }
     }
 
@@ -265,14 +328,16 @@ impl NetworkData {
                 Entry::Vacant(entry) => {
                     entry.insert(next_index);
                     node_ids.push(*node);
+                    let old_index = self.map.get(node).copied();
+
                     // fixme: keep the channel around more.
-                    connections.push(NodeConnection {
-                        addresses: BTreeSet::from([address]),
-                        channel: OnceCell::new(),
-                    });
+                    connections.push(NodeConnection::new(
+                        BTreeSet::from([address]),
+                        old_index.and_then(|it| self.connections[it].tls_certificate_hash.clone()),
+                    ));
 
-                    health.push(match self.map.get(node) {
-                        Some(it) => self.health[*it].clone(),
+                    health.push(match old_index {
+                        Some(it) => self.health[it].clone(),
                         None => Arc::default(),
                     });
                 }
@@ -285,9 +350,46 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            tls_mode: self.tls_mode,
+            proxy: self.proxy.clone(),
         })
     }
 
+    fn with_tls_mode(&self, tls_mode: TlsMode) -> Self {
+        self.with_connection_settings(tls_mode, self.proxy.clone())
+    }
+
+    fn with_proxy(&self, proxy: Option<Arc<Proxy>>) -> Self {
+        self.with_connection_settings(self.tls_mode, proxy)
+    }
+
+    fn with_connection_settings(&self, tls_mode: TlsMode, proxy: Option<Arc<Proxy>>) -> Self {
+        // every channel has to be recreated, but the nodes (and their health) stay the same.
+        let connections = self
+            .connections
+            .iter()
+            .map(|it| NodeConnection::new(it.addresses.clone(), it.tls_certificate_hash.clone()))
+            .collect();
+
+        Self {
+            map: self.map.clone(),
+            node_ids: self.node_ids.clone(),
+            health: self.health.clone(),
+            connections,
+            backoff: RwLock::new(*self.backoff.read()),
+            tls_mode,
+            proxy,
+        }
+    }
+
+    pub(crate) fn tls_mode(&self) -> TlsMode {
+        self.tls_mode
+    }
+
+    pub(crate) fn proxy(&self) -> Option<&Proxy> {
+        self.proxy.as_deref()
+    }
+
     pub(crate) fn node_ids(&self) -> &[AccountId] {
         &self.node_ids
     }
@@ -341,21 +443,61 @@ impl NetworkData {
     pub(crate) fn mark_node_unhealthy(&self, node_index: usize) {
         let now = Instant::now();
 
-        self.health[node_index].write().mark_unhealthy(*self.backoff.read(), now);
+        self.health[node_index].write().health.mark_unhealthy(*self.backoff.read(), now);
+    }
+
+    /// Marks the node as healthy, because it answered a request after `latency`.
+    pub(crate) fn mark_node_healthy(&self, node_index: usize, latency: Duration) {
+        let mut state = self.health[node_index].write();
+
+        state.health.mark_healthy(Instant::now());
+        state.counters.record_success(latency);
     }
 
-    pub(crate) fn mark_node_healthy(&self, node_index: usize) {
-        self.health[node_index].write().mark_healthy(Instant::now());
+    /// Records that a request to the node failed to get an answer.
+    pub(crate) fn record_node_failure(&self, node_index: usize) {
+        self.health[node_index].write().counters.record_failure();
     }
 
     pub(crate) fn is_node_healthy(&self, node_index: usize, now: Instant) -> bool {
         // a healthy node has a healthiness before now.
 
-        self.health[node_index].read().is_healthy(now)
+        self.health[node_index].read().health.is_healthy(now)
     }
 
     pub(crate) fn node_recently_pinged(&self, node_index: usize, now: Instant) -> bool {
-        self.health[node_index].read().recently_pinged(now)
+        self.health[node_index].read().health.recently_pinged(now)
+    }
+
+    pub(crate) fn node_stats(&self) -> Vec<NodeStats> {
+        self.node_ids
+            .iter()
+            .zip(self.health.iter())
+            .map(|(&node_account_id, state)| {
+                let state = state.read();
+
+                let (health, next_retry_at, backoff) = match &state.health {
+                    NodeHealth::Unused => (NodeHealthState::Unused, None, None),
+                    NodeHealth::Healthy { .. } => (NodeHealthState::Healthy, None, None),
+                    NodeHealth::Unhealthy { backoff, healthy_at, attempts: _ } => (
+                        NodeHealthState::Unhealthy,
+                        Some(*healthy_at),
+                        Some(backoff.current_interval),
+                    ),
+                };
+
+                NodeStats {
+                    node_account_id,
+                    health,
+                    next_retry_at,
+                    backoff,
+                    successes: state.counters.successes(),
+                    failures: state.counters.failures(),
+                    latency: state.counters.latency(),
+                    average_latency: state.counters.average_latency(),
+                }
+            })
+            .collect()
     }
 
     pub(crate) fn healthy_node_indexes(&self, time: Instant) -> impl Iterator<Item = usize> + '_ {
@@ -365,28 +507,38 @@ impl NetworkData {
     pub(crate) fn healthy_node_ids(&self) -> impl Iterator<Item = AccountId> + '_ {
         self.healthy_node_indexes(Instant::now()).map(|it| self.node_ids[it])
     }
-    pub(crate) fn random_node_ids(&self) -> Vec<AccountId> {
-        let mut node_ids: Vec<_> = self.healthy_node_ids().collect();
+
+    /// Picks the nodes to freeze a transaction with, according to `strategy`.
+    pub(crate) fn select_node_ids(&self, strategy: &NodeSelectionStrategy) -> Vec<AccountId> {
+        let mut node_indexes: Vec<_> = self.healthy_node_indexes(Instant::now()).collect();
         // self.remove_dead_nodes();
 
-        if node_ids.is_empty() {
-            log::warn!("No healthy nodes, randomly picking some unhealthy ones");
+        if node_indexes.is_empty() {
+            log::warn!("No healthy nodes, picking some unhealthy ones");
             // hack, slowpath, don't care perf, fix this better later tho.
-            node_ids = self.node_ids.to_vec();
+            node_indexes = (0..self.node_ids.len()).collect();
         }
 
-        let node_sample_amount = (node_ids.len() + 2) / 3;
+        let node_sample_amount = (node_indexes.len() + 2) / 3;
+
+        self.order_node_indexes(strategy, &mut node_indexes);
 
-        let node_id_indecies =
-            rand::seq::index::sample(&mut thread_rng(), node_ids.len(), node_sample_amount);
+        node_indexes[..node_sample_amount].iter().map(|&index| self.node_ids[index]).collect()
+    }
 
-        node_id_indecies.into_iter().map(|index| node_ids[index]).collect()
+    /// Orders `node_indexes` from most to least preferred, according to `strategy`.
+    pub(crate) fn order_node_indexes(
+        &self,
+        strategy: &NodeSelectionStrategy,
+        node_indexes: &mut [usize],
+    ) {
+        strategy.order(self, node_indexes, &mut thread_rng());
     }
 
     pub(crate) fn channel(&self, index: usize) -> (AccountId, Channel) {
         let id = self.node_ids[index];
 
-        let channel = self.connections[index].channel();
+        let channel = self.connections[index].channel(self.tls_mode, self.proxy.as_ref());
 
         (id, channel)
     }
@@ -401,6 +553,13 @@ impl NetworkData {
     }
 }
 
+/// Everything we know about a node.
+#[derive(Default)]
+struct NodeState {
+    health: NodeHealth,
+    counters: NodeCounters,
+}
+
 #[derive(Default)]
 enum NodeHealth {
     /// The node has never been used, so we don't know anything about it.
@@ -514,7 +673,7 @@ impl NodeHealth {
     }
 }
 
-#[derive(Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
 struct HostAndPort {
     host: Cow<'static, str>,
     port: u16,
@@ -524,13 +683,36 @@ impl HostAndPort {
     const fn from_static(host: &'static str) -> Self {
         Self { host: Cow::Borrowed(host), port: NodeConnection::PLAINTEXT_PORT }
     }
+
+    /// Returns the address to use for TLS connections.
+    ///
+    /// Nodes serve TLS on `50212` alongside plaintext on `50211`, any other port is assumed to already be correct.
+    fn with_tls_port(&self) -> Self {
+        match self.port {
+            NodeConnection::PLAINTEXT_PORT => {
+                Self { host: self.host.clone(), port: NodeConnection::TLS_PORT }
+            }
+            port => Self { host: self.host.clone(), port },
+        }
+    }
+
+    fn is_domain_name(&self) -> bool {
+        self.host.parse::<IpAddr>().is_err()
+    }
 }
 
 impl FromStr for HostAndPort {
     type Err = crate::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (host, port) = s.split_once(':').ok_or_else(|| Error::basic_parse("Invalid uri"))?;
+        let (host, port) = s.rsplit_once(':').ok_or_else(|| Error::basic_parse("Invalid uri"))?;
+
+        // IPv6 addresses have to be bracketed, otherwise we can't tell where the port starts.
+        let host = match host.strip_prefix('[').and_then(|it| it.strip_suffix(']')) {
+            Some(ipv6) => ipv6,
+            None if host.contains(':') => return Err(Error::basic_parse("Invalid uri")),
+            None => host,
+        };
 
         Ok(Self {
             host: Cow::Owned(host.to_owned()),
@@ -541,49 +723,137 @@ impl FromStr for HostAndPort {
 
 impl fmt::Display for HostAndPort {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}:{}", self.host, self.port)
+        match self.host.contains(':') {
+            true => write!(f, "[{}]:{}", self.host, self.port),
+            false => write!(f, "{}:{}", self.host, self.port),
+        }
     }
 }
 
-impl From<Ipv4Addr> for HostAndPort {
-    fn from(value: Ipv4Addr) -> Self {
-        Self { host: Cow::Owned(value.to_string()), port: NodeConnection::PLAINTEXT_PORT }
+impl From<&NodeEndpoint> for HostAndPort {
+    fn from(value: &NodeEndpoint) -> Self {
+        Self { host: Cow::Owned(value.host()), port: value.port() }
     }
 }
 
-#[derive(Clone)]
 struct NodeConnection {
     addresses: BTreeSet<HostAndPort>,
-    channel: OnceCell<Channel>,
+    tls_certificate_hash: Option<Box<[u8]>>,
+    // the channel and when it was created.
+    channel: RwLock<Option<(Channel, Instant)>>,
+}
+
+impl Clone for NodeConnection {
+    fn clone(&self) -> Self {
+        Self {
+            addresses: self.addresses.clone(),
+            tls_certificate_hash: self.tls_certificate_hash.clone(),
+            channel: RwLock::new(self.channel.read().clone()),
+        }
+    }
 }
 
 impl NodeConnection {
     const PLAINTEXT_PORT: u16 = 50211;
+    const TLS_PORT: u16 = 50212;
+
+    /// How long a channel to a node with a domain name is used before being replaced.
+    ///
+    /// A channel only resolves names when it (re)connects, so without this a node that moved
+    /// would keep being dialed at its old address for as long as that address kept answering.
+    const DNS_REFRESH_PERIOD: Duration = Duration::from_secs(5 * 60);
+
+    fn new(addresses: BTreeSet<HostAndPort>, tls_certificate_hash: Option<Box<[u8]>>) -> Self {
+        Self { addresses, tls_certificate_hash, channel: RwLock::new(None) }
+    }
 
     fn new_static(addresses: &[&'static str]) -> NodeConnection {
-        Self {
-            addresses: addresses.iter().copied().map(HostAndPort::from_static).collect(),
-            channel: OnceCell::default(),
-        }
+        Self::new(addresses.iter().copied().map(HostAndPort::from_static).collect(), None)
     }
 
-    pub(crate) fn channel(&self) -> Channel {
-        let channel = self
-            .channel
-            .get_or_init(|| {
-                let addresses = self.addresses.iter().map(|it| {
-                    Endpoint::from_shared(format!("tcp://{it}"))
-                        .unwrap()
-                        .keep_alive_timeout(Duration::from_secs(10))
-                        .keep_alive_while_idle(true)
-                        .tcp_keepalive(Some(Duration::from_secs(10)))
-                        .connect_timeout(Duration::from_secs(10))
-                });
-
-                Channel::balance_list(addresses)
-            })
-            .clone();
+    fn is_stale(&self, created_at: Instant, now: Instant) -> bool {
+        now.saturating_duration_since(created_at) >= Self::DNS_REFRESH_PERIOD
+            && self.addresses.iter().any(HostAndPort::is_domain_name)
+    }
+
+    pub(crate) fn channel(&self, tls_mode: TlsMode, proxy: Option<&Arc<Proxy>>) -> Channel {
+        let now = Instant::now();
+
+        if let Some((channel, created_at)) = &*self.channel.read() {
+            if !self.is_stale(*created_at, now) {
+                return channel.clone();
+            }
+        }
+
+        let mut cached = self.channel.write();
+
+        // someone else might've replaced the channel while we were waiting for the lock.
+        if let Some((channel, created_at)) = &*cached {
+            if !self.is_stale(*created_at, now) {
+                return channel.clone();
+            }
+        }
+
+        let channel = self.new_channel(tls_mode, proxy);
+
+        *cached = Some((channel.clone(), now));
 
         channel
     }
+
+    fn new_channel(&self, tls_mode: TlsMode, proxy: Option<&Arc<Proxy>>) -> Channel {
+        // a proxy has to sit under every connection, which only our own connector can do.
+        if tls_mode == TlsMode::Plaintext && proxy.is_none() {
+            let addresses = self.addresses.iter().map(|it| {
+                Endpoint::from_shared(format!("tcp://{it}"))
+                    .unwrap()
+                    .keep_alive_timeout(Duration::from_secs(10))
+                    .keep_alive_while_idle(true)
+                    .tcp_keepalive(Some(Duration::from_secs(10)))
+                    .connect_timeout(Duration::from_secs(10))
+            });
+
+            return Channel::balance_list(addresses);
+        }
+
+        let connector = NodeConnector::new(
+            &self.addresses,
+            tls_mode,
+            self.tls_certificate_hash.as_deref(),
+            proxy.cloned(),
+        );
+
+        // the connector decides which address (and scheme) actually gets used, this is just a placeholder.
+        let uri = match self.addresses.first() {
+            Some(it) => format!("https://{}", it.with_tls_port()),
+            None => "https://localhost".to_owned(),
+        };
+
+        Endpoint::from_shared(uri)
+            .unwrap()
+            .keep_alive_timeout(Duration::from_secs(10))
+            .keep_alive_while_idle(true)
+            .tcp_keepalive(Some(Duration::from_secs(10)))
+            .connect_timeout(Duration::from_secs(10))
+            .connect_with_connector_lazy(connector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HostAndPort;
+
+    #[test]
+    fn parse_host_and_port() {
+        for address in ["127.0.0.1:50211", "[2001:db8::1]:50211", "0.testnet.hedera.com:50211"] {
+            assert_eq!(address.parse::<HostAndPort>().unwrap().to_string(), address);
+        }
+
+        assert!("2001:db8::1:50211".parse::<HostAndPort>().is_err());
+
+        let ipv6 = "[2001:db8::1]:50211".parse::<HostAndPort>().unwrap();
+        assert!(!ipv6.is_domain_name());
+        assert_eq!(ipv6.with_tls_port().to_string(), "[2001:db8::1]:50212");
+        assert!("0.testnet.hedera.com:50211".parse::<HostAndPort>().unwrap().is_domain_name());
+    }
 }
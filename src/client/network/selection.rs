@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::{
+    NetworkData,
+    NodeHealth,
+};
+use crate::AccountId;
+
+/// How a [`Client`](crate::Client) picks the nodes a request is sent to.
+///
+/// Whatever the strategy, unhealthy nodes are skipped and a request that fails on one node moves on to the next.
+/// The strategy only decides the order nodes are tried in, and which ones are picked when freezing a transaction.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub enum NodeSelectionStrategy {
+    /// Pick nodes uniformly at random.
+    #[default]
+    Random,
+
+    /// Prefer the nodes with the lowest observed latency.
+    ///
+    /// Latency is a moving average of each node's response times,
+    /// nodes that haven't answered a request yet are tried first, so that every node gets measured.
+    LowestLatency,
+
+    /// Pick nodes at random, proportionally to their weight.
+    ///
+    /// Weights can be anything, such as each node's stake.
+    /// Nodes without a weight (or with a weight of zero) are only tried after every weighted node.
+    Weighted(HashMap<AccountId, u64>),
+
+    /// Prefer the node that most recently answered a request, falling back to random nodes.
+    Sticky,
+}
+
+impl NodeSelectionStrategy {
+    /// Orders `indexes` (indexes of nodes in `network`) from most to least preferred.
+    pub(super) fn order<R: Rng>(&self, network: &NetworkData, indexes: &mut [usize], rng: &mut R) {
+        // shuffling first means ties are broken randomly.
+        indexes.shuffle(rng);
+
+        match self {
+            Self::Random => {}
+
+            Self::LowestLatency => {
+                indexes
+                    .sort_by_key(|&index| network.health[index].read().counters.average_latency());
+            }
+
+            Self::Weighted(weights) => {
+                // weighted random sampling without replacement (Efraimidis-Spirakis), higher keys go first.
+                let mut keyed: Vec<_> = indexes
+                    .iter()
+                    .map(|&index| {
+                        let weight = weights.get(&network.node_ids[index]).copied().unwrap_or(0);
+
+                        let key = match weight {
+                            0 => f64::NEG_INFINITY,
+                            weight => rng.gen::<f64>().powf(1.0 / weight as f64),
+                        };
+
+                        (key, index)
+                    })
+                    .collect();
+
+                keyed.sort_by(|(lhs, _), (rhs, _)| rhs.total_cmp(lhs));
+
+                for (place, (_, index)) in indexes.iter_mut().zip(keyed) {
+                    *place = index;
+                }
+            }
+
+            Self::Sticky => {
+                let last_used = indexes
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(position, &index)| match network.health[index].read().health {
+                        NodeHealth::Healthy { used_at } => Some((used_at, position)),
+                        _ => None,
+                    })
+                    .max_by_key(|&(used_at, _)| used_at);
+
+                if let Some((_, position)) = last_used {
+                    indexes[..=position].rotate_right(1);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use rand::thread_rng;
+
+    use super::NodeSelectionStrategy;
+    use crate::client::NetworkData;
+    use crate::AccountId;
+
+    const NETWORK: &[(u64, &[&str])] = &[(3, &["a"]), (4, &["b"]), (5, &["c"])];
+
+    fn network() -> NetworkData {
+        NetworkData::from_static(NETWORK)
+    }
+
+    fn order(strategy: &NodeSelectionStrategy, network: &NetworkData) -> Vec<usize> {
+        let mut indexes = vec![0, 1, 2];
+        strategy.order(network, &mut indexes, &mut thread_rng());
+
+        indexes
+    }
+
+    #[test]
+    fn random_keeps_every_node() {
+        let mut indexes = order(&NodeSelectionStrategy::Random, &network());
+        indexes.sort_unstable();
+
+        assert_eq!(indexes, [0, 1, 2]);
+    }
+
+    #[test]
+    fn lowest_latency() {
+        let network = network();
+
+        network.mark_node_healthy(0, Duration::from_millis(300));
+        network.mark_node_healthy(1, Duration::from_millis(10));
+
+        // node 2 hasn't been measured, so it goes first.
+        assert_eq!(order(&NodeSelectionStrategy::LowestLatency, &network), [2, 1, 0]);
+    }
+
+    #[test]
+    fn weighted_skips_unweighted() {
+        let network = network();
+
+        let strategy = NodeSelectionStrategy::Weighted(HashMap::from([
+            (AccountId::new(0, 0, 3), 1),
+            (AccountId::new(0, 0, 5), 1_000),
+        ]));
+
+        for _ in 0..10 {
+            assert_eq!(order(&strategy, &network)[2], 1);
+        }
+    }
+
+    #[test]
+    fn sticky() {
+        let network = network();
+
+        network.mark_node_healthy(2, Duration::from_millis(10));
+        std::thread::sleep(Duration::from_millis(1));
+        network.mark_node_healthy(1, Duration::from_millis(10));
+
+        for _ in 0..10 {
+            assert_eq!(order(&NodeSelectionStrategy::Sticky, &network)[0], 1);
+        }
+    }
+}
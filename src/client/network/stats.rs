@@ -0,0 +1,251 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use crate::AccountId;
+
+/// How many of the most recent latencies are kept (per node) for [`LatencyPercentiles`].
+const LATENCY_SAMPLES: usize = 100;
+
+/// How much each new latency counts towards the moving average.
+const LATENCY_AVERAGE_WEIGHT: f64 = 0.2;
+
+/// What the client currently thinks of a consensus node, see [`Client::node_stats`](crate::Client::node_stats).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct NodeStats {
+    /// The account ID of the node.
+    pub node_account_id: AccountId,
+
+    /// Whether the client is currently willing to send requests to the node.
+    pub health: NodeHealthState,
+
+    /// When an unhealthy node will next be used, `None` if the node isn't unhealthy.
+    ///
+    /// This may be in the past, in which case the node will be used (again) by the next request that picks it.
+    pub next_retry_at: Option<Instant>,
+
+    /// How long the node was most recently excluded for, `None` if the node isn't unhealthy.
+    ///
+    /// This grows every time the node fails again, and resets once it answers a request.
+    pub backoff: Option<Duration>,
+
+    /// The number of requests the node answered (with any pre-check status).
+    pub successes: u64,
+
+    /// The number of requests that failed to get an answer from the node (such as connection errors and timeouts).
+    pub failures: u64,
+
+    /// Percentiles of the node's most recent response latencies, `None` if the node never answered a request.
+    pub latency: Option<LatencyPercentiles>,
+
+    /// The exponentially weighted moving average of the node's response latencies, `None` if the node never answered a request.
+    ///
+    /// This is what [`NodeSelectionStrategy::LowestLatency`](crate::NodeSelectionStrategy::LowestLatency) orders nodes by.
+    pub average_latency: Option<Duration>,
+}
+
+/// The health of a consensus node, as seen by the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NodeHealthState {
+    /// The node has never been used.
+    Unused,
+
+    /// The node answered the last request sent to it.
+    Healthy,
+
+    /// The last request sent to the node failed, so it isn't used until its backoff elapses.
+    Unhealthy,
+}
+
+/// Percentiles of a node's recent response latencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct LatencyPercentiles {
+    /// The number of latencies these percentiles were computed from.
+    pub samples: usize,
+
+    /// The median latency.
+    pub p50: Duration,
+
+    /// The 90th percentile latency.
+    pub p90: Duration,
+
+    /// The 99th percentile latency.
+    pub p99: Duration,
+}
+
+/// Request counters and recent latencies for a single node.
+#[derive(Default)]
+pub(super) struct NodeCounters {
+    successes: u64,
+    failures: u64,
+    latencies: VecDeque<Duration>,
+    average_latency: Option<Duration>,
+}
+
+impl NodeCounters {
+    pub(super) fn record_success(&mut self, latency: Duration) {
+        self.successes += 1;
+
+        if self.latencies.len() == LATENCY_SAMPLES {
+            self.latencies.pop_front();
+        }
+
+        self.latencies.push_back(latency);
+
+        self.average_latency = Some(match self.average_latency {
+            Some(average) => {
+                average.mul_f64(1.0 - LATENCY_AVERAGE_WEIGHT)
+                    + latency.mul_f64(LATENCY_AVERAGE_WEIGHT)
+            }
+            None => latency,
+        });
+    }
+
+    pub(super) fn record_failure(&mut self) {
+        self.failures += 1;
+    }
+
+    pub(super) fn successes(&self) -> u64 {
+        self.successes
+    }
+
+    pub(super) fn failures(&self) -> u64 {
+        self.failures
+    }
+
+    pub(super) fn average_latency(&self) -> Option<Duration> {
+        self.average_latency
+    }
+
+    pub(super) fn latency(&self) -> Option<LatencyPercentiles> {
+        if self.latencies.is_empty() {
+            return None;
+        }
+
+        let mut latencies: Vec<_> = self.latencies.iter().copied().collect();
+        latencies.sort_unstable();
+
+        // nearest-rank, so every percentile is a latency that actually happened.
+        let percentile = |p: usize| latencies[(latencies.len() * p).div_ceil(100) - 1];
+
+        Some(LatencyPercentiles {
+            samples: latencies.len(),
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        NodeCounters,
+        LATENCY_SAMPLES,
+    };
+
+    #[test]
+    fn no_latency() {
+        let mut counters = NodeCounters::default();
+        counters.record_failure();
+
+        assert_eq!(counters.failures(), 1);
+        assert_eq!(counters.latency(), None);
+    }
+
+    #[test]
+    fn latency_percentiles() {
+        let mut counters = NodeCounters::default();
+
+        for ms in (1..=10).rev() {
+            counters.record_success(Duration::from_millis(ms));
+        }
+
+        let latency = counters.latency().unwrap();
+
+        assert_eq!(counters.successes(), 10);
+        assert_eq!(latency.samples, 10);
+        assert_eq!(latency.p50, Duration::from_millis(5));
+        assert_eq!(latency.p90, Duration::from_millis(9));
+        assert_eq!(latency.p99, Duration::from_millis(10));
+    }
+
+    #[test]
+    fn average_latency() {
+        let mut counters = NodeCounters::default();
+
+        counters.record_success(Duration::from_millis(100));
+        assert_eq!(counters.average_latency(), Some(Duration::from_millis(100)));
+
+        counters.record_success(Duration::from_millis(200));
+
+        let average = counters.average_latency().unwrap();
+        assert!((average.as_secs_f64() - 0.120).abs() < 1e-6);
+    }
+
+    #[test]
+    fn latency_keeps_recent_samples() {
+        let mut counters = NodeCounters::default();
+
+        counters.record_success(Duration::from_secs(60));
+
+        for _ in 0..LATENCY_SAMPLES {
+            counters.record_success(Duration::from_millis(1));
+        }
+
+        let latency = counters.latency().unwrap();
+
+        assert_eq!(counters.successes(), LATENCY_SAMPLES as u64 + 1);
+        assert_eq!(latency.samples, LATENCY_SAMPLES);
+        assert_eq!(latency.p99, Duration::from_millis(1));
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod mock_tests {
+    use assert_matches::assert_matches;
+
+    use crate::testing::tests::{
+        fast_client,
+        transfer,
+    };
+    use crate::testing::{
+        MockNetwork,
+        MockResponse,
+    };
+    use crate::NodeHealthState;
+
+    #[tokio::test]
+    async fn node_stats() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let client = fast_client(&network);
+
+        let report = client.ping_all_report().await;
+
+        assert_eq!(report.len(), 1);
+        assert_matches!(report[&network.nodes()[0].account_id()], Ok(_));
+
+        network.nodes()[0].respond(MockResponse::grpc_error(tonic::Code::Unavailable));
+
+        transfer().execute(&client).await.unwrap();
+
+        let stats = client.node_stats();
+
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].node_account_id, network.nodes()[0].account_id());
+        assert_eq!(stats[0].health, NodeHealthState::Healthy);
+        assert_eq!(stats[0].failures, 1);
+        // at least the first ping and the transfer itself.
+        assert!(stats[0].successes >= 2);
+        assert_eq!(stats[0].latency.unwrap().samples as u64, stats[0].successes);
+    }
+}
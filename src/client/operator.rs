@@ -1,24 +1,272 @@
+use parking_lot::Mutex;
+use triomphe::Arc;
+
+use super::ClockOffset;
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
-    PublicKey,
+    Hbar,
     TransactionId,
+    TransactionIdGenerator,
 };
 
 #[derive(Debug)]
 pub(crate) struct Operator {
     pub(crate) account_id: AccountId,
     pub(crate) signer: AnySigner,
+    // the client's generator and clock offset, shared by all of its operators.
+    pub(crate) transaction_ids: Arc<TransactionIdGenerator>,
+    pub(crate) clock_offset: Arc<ClockOffset>,
 }
 
 impl Operator {
+    /// Generates a transaction ID for this operator, at the network's current time.
     #[must_use]
-    pub(crate) fn sign(&self, body_bytes: &[u8]) -> (PublicKey, Vec<u8>) {
-        self.signer.sign(body_bytes)
+    pub(crate) fn generate_transaction_id(&self) -> TransactionId {
+        self.transaction_ids.generate_at(self.account_id, self.clock_offset.now())
     }
+}
 
-    #[must_use]
-    pub(crate) fn generate_transaction_id(&self) -> TransactionId {
-        TransactionId::generate(self.account_id)
+/// How a [`Client`](crate::Client) with an operator pool picks the payer for each transaction and query payment.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PayerSelectionStrategy {
+    /// Take turns, in the order the payers were given.
+    #[default]
+    RoundRobin,
+
+    /// Pick the payer that has gone the longest without being used.
+    LeastRecentlyUsed,
+
+    /// Take turns between the payers with a balance of at least this much.
+    ///
+    /// Balances are only known after [`Client::refresh_operator_pool_balances`](crate::Client::refresh_operator_pool_balances),
+    /// payers whose balance hasn't been fetched yet are assumed to have enough.
+    /// When no payer has enough, the one with the highest balance is picked.
+    BalanceThreshold(Hbar),
+}
+
+/// Whoever pays for a client's requests, kept in one place so that replacing one with the other is atomic.
+#[derive(Debug)]
+pub(crate) enum Payer {
+    Operator(Arc<Operator>),
+    Pool(OperatorPool),
+}
+
+impl Payer {
+    /// Picks the operator that pays for the next request.
+    pub(crate) fn select(&self) -> Arc<Operator> {
+        match self {
+            Self::Operator(it) => Arc::clone(it),
+            Self::Pool(pool) => pool.select(),
+        }
+    }
+
+    /// Returns the operator, or the first operator of the pool (without moving the pool on to the next payer).
+    pub(crate) fn first(&self) -> &Arc<Operator> {
+        match self {
+            Self::Operator(it) => it,
+            Self::Pool(pool) => &pool.operators[0],
+        }
+    }
+
+    pub(crate) fn pool(&self) -> Option<&OperatorPool> {
+        match self {
+            Self::Operator(_) => None,
+            Self::Pool(it) => Some(it),
+        }
+    }
+}
+
+/// Several operators taking turns paying for requests.
+#[derive(Debug)]
+pub(crate) struct OperatorPool {
+    operators: Box<[Arc<Operator>]>,
+    strategy: PayerSelectionStrategy,
+    state: Mutex<PoolState>,
+}
+
+#[derive(Debug)]
+struct PoolState {
+    // index of the operator to try first for round robin selection.
+    next: usize,
+    // logical clock, every selection ticks it once.
+    clock: u64,
+    last_used: Box<[u64]>,
+    balances: Box<[Option<Hbar>]>,
+}
+
+impl OperatorPool {
+    /// # Panics
+    /// If `operators` is empty.
+    pub(crate) fn new(operators: Vec<Operator>, strategy: PayerSelectionStrategy) -> Self {
+        assert!(!operators.is_empty(), "an operator pool needs at least one operator");
+
+        let len = operators.len();
+
+        Self {
+            operators: operators.into_iter().map(Arc::new).collect(),
+            strategy,
+            state: Mutex::new(PoolState {
+                next: 0,
+                clock: 0,
+                last_used: vec![0; len].into_boxed_slice(),
+                balances: vec![None; len].into_boxed_slice(),
+            }),
+        }
+    }
+
+    pub(crate) fn strategy(&self) -> PayerSelectionStrategy {
+        self.strategy
+    }
+
+    pub(crate) fn operators(&self) -> &[Arc<Operator>] {
+        &self.operators
+    }
+
+    pub(crate) fn account_ids(&self) -> Vec<AccountId> {
+        self.operators.iter().map(|it| it.account_id).collect()
+    }
+
+    pub(crate) fn set_balance(&self, account_id: AccountId, balance: Hbar) {
+        let mut state = self.state.lock();
+
+        for (index, operator) in self.operators.iter().enumerate() {
+            if operator.account_id == account_id {
+                state.balances[index] = Some(balance);
+            }
+        }
+    }
+
+    /// Picks the operator that pays for the next request.
+    pub(crate) fn select(&self) -> Arc<Operator> {
+        let mut state = self.state.lock();
+        let len = self.operators.len();
+
+        let index = match self.strategy {
+            PayerSelectionStrategy::RoundRobin => state.next % len,
+
+            PayerSelectionStrategy::LeastRecentlyUsed => {
+                (0..len).min_by_key(|&index| state.last_used[index]).unwrap()
+            }
+
+            PayerSelectionStrategy::BalanceThreshold(min_balance) => (0..len)
+                .map(|offset| (state.next + offset) % len)
+                .find(|&index| state.balances[index].map_or(true, |it| it >= min_balance))
+                .unwrap_or_else(|| (0..len).max_by_key(|&index| state.balances[index]).unwrap()),
+        };
+
+        state.next = (index + 1) % len;
+        state.clock += 1;
+        state.last_used[index] = state.clock;
+
+        Arc::clone(&self.operators[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use triomphe::Arc;
+
+    use super::{
+        Operator,
+        OperatorPool,
+        PayerSelectionStrategy,
+    };
+    use crate::signer::AnySigner;
+    use crate::{
+        AccountId,
+        Hbar,
+        PrivateKey,
+    };
+
+    fn pool(strategy: PayerSelectionStrategy) -> OperatorPool {
+        let operators = (2..5)
+            .map(|num| Operator {
+                account_id: AccountId::new(0, 0, num),
+                signer: AnySigner::PrivateKey(PrivateKey::generate_ed25519()),
+                transaction_ids: Arc::default(),
+                clock_offset: Arc::default(),
+            })
+            .collect();
+
+        OperatorPool::new(operators, strategy)
+    }
+
+    fn select(pool: &OperatorPool, count: usize) -> Vec<u64> {
+        (0..count).map(|_| pool.select().account_id.num).collect()
+    }
+
+    #[test]
+    fn round_robin() {
+        assert_eq!(select(&pool(PayerSelectionStrategy::RoundRobin), 5), [2, 3, 4, 2, 3]);
+    }
+
+    #[test]
+    fn least_recently_used() {
+        assert_eq!(select(&pool(PayerSelectionStrategy::LeastRecentlyUsed), 4), [2, 3, 4, 2]);
+    }
+
+    #[test]
+    fn balance_threshold() {
+        let pool = pool(PayerSelectionStrategy::BalanceThreshold(Hbar::new(10)));
+
+        pool.set_balance(AccountId::new(0, 0, 3), Hbar::new(1));
+        assert_eq!(select(&pool, 3), [2, 4, 2]);
+
+        pool.set_balance(AccountId::new(0, 0, 2), Hbar::new(2));
+        pool.set_balance(AccountId::new(0, 0, 4), Hbar::new(5));
+        assert_eq!(select(&pool, 2), [4, 4]);
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod mock_tests {
+    use crate::testing::tests::{
+        fast_client,
+        transfer,
+    };
+    use crate::testing::MockNetwork;
+    use crate::{
+        AccountId,
+        PayerSelectionStrategy,
+        PrivateKey,
+        Signer,
+    };
+
+    #[tokio::test]
+    async fn operator_pool_rotates_payers() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let client = fast_client(&network);
+
+        let payers = [AccountId::new(0, 0, 1001), AccountId::new(0, 0, 1002)];
+
+        client.set_operator_pool(
+            payers.map(|it| (it, Box::new(PrivateKey::generate_ed25519()) as Box<dyn Signer>)),
+            PayerSelectionStrategy::RoundRobin,
+        );
+
+        assert_eq!(client.get_operator_account_id(), Some(payers[0]));
+        assert_eq!(client.get_operator_pool_account_ids(), payers);
+
+        let mut used = Vec::new();
+
+        for _ in 0..3 {
+            let response = transfer().execute(&client).await.unwrap();
+
+            assert_eq!(response.payer_account_id(), response.transaction_id.account_id);
+            used.push(response.payer_account_id());
+        }
+
+        assert_eq!(used, [payers[0], payers[1], payers[0]]);
+
+        // setting a single operator replaces the pool.
+        client.set_operator(AccountId::new(0, 0, 2), PrivateKey::generate_ed25519());
+
+        assert!(client.get_operator_pool_account_ids().is_empty());
+        assert_eq!(
+            transfer().execute(&client).await.unwrap().payer_account_id(),
+            AccountId::new(0, 0, 2)
+        );
     }
 }
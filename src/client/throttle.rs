@@ -0,0 +1,314 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use hedera_proto::services;
+use parking_lot::Mutex;
+use prost::Message;
+
+use crate::{
+    AccountId,
+    RequestType,
+    ThrottleDefinitions,
+};
+
+/// A client-side rate limiter that follows the network's throttle definitions.
+///
+/// The network's throttles are split evenly between its nodes, so every node gets its own share of each bucket.
+/// Buckets are "leaky": each request fills a bucket by how long it takes to drain at the throttled rate,
+/// and a bucket can hold up to its burst period.
+pub(crate) struct Throttle {
+    definitions: ThrottleDefinitions,
+
+    /// The buckets (by index) that each request type is throttled by, with the rate (in milli-ops per second) it's throttled to in each.
+    limits: HashMap<RequestType, Vec<(usize, u64)>>,
+
+    /// How full each bucket of each node is.
+    usage: Mutex<HashMap<(AccountId, usize), BucketUsage>>,
+}
+
+#[derive(Clone, Copy)]
+struct BucketUsage {
+    level: Duration,
+    updated_at: Instant,
+}
+
+impl BucketUsage {
+    /// Returns how full the bucket is at `now`, buckets drain at a rate of one second per second.
+    fn level_at(self, now: Instant) -> Duration {
+        self.level.saturating_sub(now.saturating_duration_since(self.updated_at))
+    }
+}
+
+impl Throttle {
+    pub(crate) fn new(definitions: ThrottleDefinitions) -> Self {
+        let mut limits: HashMap<RequestType, Vec<(usize, u64)>> = HashMap::new();
+
+        for (index, bucket) in definitions.buckets.iter().enumerate() {
+            for group in &bucket.groups {
+                // a rate of zero means the operation isn't allowed at all, which is for the network to say.
+                if group.milli_ops_per_sec == 0 {
+                    continue;
+                }
+
+                for operation in &group.operations {
+                    limits
+                        .entry(operation.clone())
+                        .or_default()
+                        .push((index, group.milli_ops_per_sec));
+                }
+            }
+        }
+
+        Self { definitions, limits, usage: Mutex::default() }
+    }
+
+    pub(crate) fn definitions(&self) -> &ThrottleDefinitions {
+        &self.definitions
+    }
+
+    /// Makes room for a `request_type` request to `node_account_id` at `now`, in a network of `node_count` nodes.
+    ///
+    /// If any of the request's buckets are too full, nothing is used up and how long to wait before trying again is returned instead.
+    pub(crate) fn try_acquire(
+        &self,
+        node_account_id: AccountId,
+        request_type: &RequestType,
+        node_count: usize,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        let Some(limits) = self.limits.get(request_type) else {
+            return Ok(());
+        };
+
+        let mut usage = self.usage.lock();
+
+        let level_at = |usage: &HashMap<_, BucketUsage>, bucket: usize| {
+            usage.get(&(node_account_id, bucket)).map_or(Duration::ZERO, |it| it.level_at(now))
+        };
+
+        let mut wait = Duration::ZERO;
+
+        for &(bucket, milli_ops_per_sec) in limits {
+            let cost = request_cost(milli_ops_per_sec, node_count);
+
+            // a request that costs more than the whole bucket still fits in an empty one.
+            let capacity = self.definitions.buckets[bucket].burst_period.max(cost);
+
+            wait = wait.max((level_at(&usage, bucket) + cost).saturating_sub(capacity));
+        }
+
+        if wait > Duration::ZERO {
+            return Err(wait);
+        }
+
+        for &(bucket, milli_ops_per_sec) in limits {
+            let level = level_at(&usage, bucket) + request_cost(milli_ops_per_sec, node_count);
+
+            usage.insert((node_account_id, bucket), BucketUsage { level, updated_at: now });
+        }
+
+        Ok(())
+    }
+
+    /// Waits until there's room for a `request_type` request to `node_account_id`, and then uses it up.
+    pub(crate) async fn acquire(
+        &self,
+        node_account_id: AccountId,
+        request_type: &RequestType,
+        node_count: usize,
+    ) {
+        while let Err(wait) =
+            self.try_acquire(node_account_id, request_type, node_count, Instant::now())
+        {
+            log::debug!(
+                "Throttling {request_type:?} on node id {node_account_id} for {}ms",
+                wait.as_millis()
+            );
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Returns how much of a bucket a single request uses, when the whole network allows `milli_ops_per_sec`.
+fn request_cost(milli_ops_per_sec: u64, node_count: usize) -> Duration {
+    let node_count = node_count.max(1) as u128;
+
+    // each node only gets `1 / node_count` of the network's rate.
+    let nanos = 1_000_000_000_000 * node_count / u128::from(milli_ops_per_sec);
+
+    Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+}
+
+/// Returns the request type of an encoded transaction, `None` if it isn't one that's throttled by type.
+pub(crate) fn transaction_request_type(transaction: &services::Transaction) -> Option<RequestType> {
+    use services::transaction_body::Data;
+
+    let signed =
+        services::SignedTransaction::decode(&*transaction.signed_transaction_bytes).ok()?;
+    let body = services::TransactionBody::decode(&*signed.body_bytes).ok()?;
+
+    let request_type = match body.data? {
+        Data::ConsensusCreateTopic(_) => RequestType::ConsensusCreateTopic,
+        Data::ConsensusDeleteTopic(_) => RequestType::ConsensusDeleteTopic,
+        Data::ConsensusSubmitMessage(_) => RequestType::ConsensusSubmitMessage,
+        Data::ConsensusUpdateTopic(_) => RequestType::ConsensusUpdateTopic,
+        Data::ContractCall(_) => RequestType::ContractCall,
+        Data::ContractCreateInstance(_) => RequestType::ContractCreate,
+        Data::ContractDeleteInstance(_) => RequestType::ContractDelete,
+        Data::ContractUpdateInstance(_) => RequestType::ContractUpdate,
+        Data::CryptoApproveAllowance(_) => RequestType::CryptoApproveAllowance,
+        Data::CryptoCreateAccount(_) => RequestType::CryptoCreate,
+        Data::CryptoDelete(_) => RequestType::CryptoDelete,
+        Data::CryptoDeleteAllowance(_) => RequestType::CryptoDeleteAllowance,
+        Data::CryptoTransfer(_) => RequestType::CryptoTransfer,
+        Data::CryptoUpdateAccount(_) => RequestType::CryptoUpdate,
+        Data::EthereumTransaction(_) => RequestType::EthereumTransaction,
+        Data::FileAppend(_) => RequestType::FileAppend,
+        Data::FileCreate(_) => RequestType::FileCreate,
+        Data::FileDelete(_) => RequestType::FileDelete,
+        Data::FileUpdate(_) => RequestType::FileUpdate,
+        Data::Freeze(_) => RequestType::Freeze,
+        Data::NodeCreate(_) => RequestType::NodeCreate,
+        Data::NodeDelete(_) => RequestType::NodeDelete,
+        Data::NodeUpdate(_) => RequestType::NodeUpdate,
+        Data::ScheduleCreate(_) => RequestType::ScheduleCreate,
+        Data::ScheduleDelete(_) => RequestType::ScheduleDelete,
+        Data::ScheduleSign(_) => RequestType::ScheduleSign,
+        Data::SystemDelete(_) => RequestType::SystemDelete,
+        Data::SystemUndelete(_) => RequestType::SystemUndelete,
+        Data::TokenAirdrop(_) => RequestType::TokenAirdrop,
+        Data::TokenAssociate(_) => RequestType::TokenAssociateToAccount,
+        Data::TokenBurn(_) => RequestType::TokenBurn,
+        Data::TokenCancelAirdrop(_) => RequestType::TokenCancelAirdrop,
+        Data::TokenClaimAirdrop(_) => RequestType::TokenClaimAirdrop,
+        Data::TokenCreation(_) => RequestType::TokenCreate,
+        Data::TokenDeletion(_) => RequestType::TokenDelete,
+        Data::TokenDissociate(_) => RequestType::TokenDissociateFromAccount,
+        Data::TokenFeeScheduleUpdate(_) => RequestType::TokenFeeScheduleUpdate,
+        Data::TokenFreeze(_) => RequestType::TokenFreezeAccount,
+        Data::TokenGrantKyc(_) => RequestType::TokenGrantKycToAccount,
+        Data::TokenMint(_) => RequestType::TokenMint,
+        Data::TokenPause(_) => RequestType::TokenPause,
+        Data::TokenReject(_) => RequestType::TokenReject,
+        Data::TokenRevokeKyc(_) => RequestType::TokenRevokeKycFromAccount,
+        Data::TokenUnfreeze(_) => RequestType::TokenUnfreezeAccount,
+        Data::TokenUnpause(_) => RequestType::TokenUnpause,
+        Data::TokenUpdate(_) => RequestType::TokenUpdate,
+        Data::TokenUpdateNfts(_) => RequestType::TokenUpdateNfts,
+        Data::TokenWipe(_) => RequestType::TokenAccountWipe,
+        Data::UtilPrng(_) => RequestType::UtilPrng,
+        _ => return None,
+    };
+
+    Some(request_type)
+}
+
+/// Returns the request type of a query, `None` if it isn't one that's throttled by type.
+pub(crate) fn query_request_type(query: &services::Query) -> Option<RequestType> {
+    use services::query::Query;
+
+    let request_type = match query.query.as_ref()? {
+        Query::ConsensusGetTopicInfo(_) => RequestType::ConsensusGetTopicInfo,
+        Query::ContractCallLocal(_) => RequestType::ContractCallLocal,
+        Query::ContractGetBytecode(_) => RequestType::ContractGetBytecode,
+        Query::ContractGetInfo(_) => RequestType::ContractGetInfo,
+        Query::CryptoGetAccountRecords(_) => RequestType::CryptoGetAccountRecords,
+        Query::CryptoGetInfo(_) => RequestType::CryptoGetInfo,
+        Query::CryptogetAccountBalance(_) => RequestType::CryptoGetAccountBalance,
+        Query::FileGetContents(_) => RequestType::FileGetContents,
+        Query::FileGetInfo(_) => RequestType::FileGetInfo,
+        Query::NetworkGetVersionInfo(_) => RequestType::GetVersionInfo,
+        Query::ScheduleGetInfo(_) => RequestType::ScheduleGetInfo,
+        Query::TokenGetInfo(_) => RequestType::TokenGetInfo,
+        Query::TokenGetNftInfo(_) => RequestType::TokenGetNftInfo,
+        Query::TransactionGetReceipt(_) => RequestType::TransactionGetReceipt,
+        Query::TransactionGetRecord(_) => RequestType::TransactionGetRecord,
+        _ => return None,
+    };
+
+    Some(request_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{
+        Duration,
+        Instant,
+    };
+
+    use super::Throttle;
+    use crate::{
+        AccountId,
+        RequestType,
+        ThrottleBucket,
+        ThrottleDefinitions,
+        ThrottleGroup,
+    };
+
+    const NODE: AccountId = AccountId::new(0, 0, 3);
+
+    // 10 transfers a second across the network, with a 1 second burst.
+    fn throttle() -> Throttle {
+        Throttle::new(ThrottleDefinitions {
+            buckets: vec![ThrottleBucket {
+                name: "ThroughputLimits".to_owned(),
+                burst_period: Duration::from_secs(1),
+                groups: vec![ThrottleGroup {
+                    operations: vec![RequestType::CryptoTransfer],
+                    milli_ops_per_sec: 10_000,
+                }],
+            }],
+        })
+    }
+
+    #[test]
+    fn burst_then_wait() {
+        let throttle = throttle();
+        let now = Instant::now();
+
+        // with 2 nodes, each node allows 5 transfers a second.
+        for _ in 0..5 {
+            assert_eq!(throttle.try_acquire(NODE, &RequestType::CryptoTransfer, 2, now), Ok(()));
+        }
+
+        assert_eq!(
+            throttle.try_acquire(NODE, &RequestType::CryptoTransfer, 2, now),
+            Err(Duration::from_millis(200))
+        );
+
+        let later = now + Duration::from_millis(200);
+
+        assert_eq!(throttle.try_acquire(NODE, &RequestType::CryptoTransfer, 2, later), Ok(()));
+        assert!(throttle.try_acquire(NODE, &RequestType::CryptoTransfer, 2, later).is_err());
+    }
+
+    #[test]
+    fn nodes_are_independent() {
+        let throttle = throttle();
+        let now = Instant::now();
+
+        assert_eq!(throttle.try_acquire(NODE, &RequestType::CryptoTransfer, 10, now), Ok(()));
+        assert!(throttle.try_acquire(NODE, &RequestType::CryptoTransfer, 10, now).is_err());
+
+        assert_eq!(
+            throttle.try_acquire(AccountId::new(0, 0, 4), &RequestType::CryptoTransfer, 10, now),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn unthrottled_request_type() {
+        let throttle = throttle();
+        let now = Instant::now();
+
+        for _ in 0..100 {
+            assert_eq!(throttle.try_acquire(NODE, &RequestType::TokenMint, 1, now), Ok(()));
+        }
+    }
+}
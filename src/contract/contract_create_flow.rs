@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use time::Duration;
+use triomphe::Arc;
 
 use crate::signer::AnySigner;
 use crate::staked_id::StakedId;
@@ -17,6 +18,7 @@ use crate::{
     Key,
     PrivateKey,
     PublicKey,
+    Signer,
     TransactionResponse,
 };
 
@@ -281,6 +283,15 @@ impl ContractCreateFlow {
         self
     }
 
+    /// Sets the signer for use in the ``ContractCreateTransaction``, with a [`Signer`].
+    ///
+    /// Important: Only *one* signer is allowed.
+    pub fn sign_with_signer<S: Signer>(&mut self, signer: S) -> &mut Self {
+        self.contract_data.signer = Some(AnySigner::signer(signer));
+
+        self
+    }
+
     /// Generates the required transactions and executes them all.
     pub async fn execute(&self, client: &Client) -> crate::Result<TransactionResponse> {
         self.execute_with_optional_timeout(client, None).await
@@ -301,18 +312,19 @@ impl ContractCreateFlow {
         timeout_per_transaction: Option<std::time::Duration>,
     ) -> crate::Result<TransactionResponse> {
         // todo: proper error
-        let operator_public_key = client
-            .load_operator()
-            .as_deref()
-            .map(|it| it.signer.public_key())
+        let operator = client
+            .select_operator()
             .expect("Must call `Client.set_operator` to use contract create flow");
 
+        // the file's key is the operator's key, so every file transaction is paid for by that same operator,
+        // rather than having the client pick a (possibly different) payer from its pool for each one.
         let bytecode = split_bytecode(&self.bytecode);
         let file_id = make_file_create_transaction(
             bytecode.0,
-            operator_public_key,
+            operator.signer.public_key(),
             self.node_account_ids.clone(),
         )
+        .freeze_with_operator(Some(client), Some(Arc::clone(&operator)))?
         .execute_with_optional_timeout(client, timeout_per_transaction)
         .await?
         .get_receipt_query()
@@ -329,6 +341,7 @@ impl ContractCreateFlow {
                 self.file_append_max_chunks,
                 self.node_account_ids.clone(),
             )
+            .freeze_with_operator(Some(client), Some(Arc::clone(&operator)))?
             .execute_all_with_optional_timeout(client, timeout_per_transaction)
             .await?;
         }
@@ -348,6 +361,7 @@ impl ContractCreateFlow {
 
         // todo: Should this return `response` even if this fails?
         make_file_delete_transaction(file_id, self.node_account_ids.clone())
+            .freeze_with_operator(Some(client), Some(operator))?
             .execute_with_optional_timeout(client, timeout_per_transaction)
             .await?
             .get_receipt_query()
@@ -23,6 +23,7 @@ use crate::{
     FileId,
     Hbar,
     Key,
+    RequiredSigner,
     ToProtobuf,
     Transaction,
     ValidateChecksums,
@@ -246,6 +247,13 @@ impl TransactionData for ContractCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::new(20)
     }
+
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        let admin_key = self.admin_key.clone().map(RequiredSigner::Key);
+        let auto_renew = self.auto_renew_account_id.map(RequiredSigner::Account);
+
+        admin_key.into_iter().chain(auto_renew).collect()
+    }
 }
 
 impl TransactionExecute for ContractCreateTransactionData {
@@ -22,6 +22,7 @@ use crate::{
     BoxGrpcFuture,
     ContractId,
     Error,
+    RequiredSigner,
     Transaction,
     ValidateChecksums,
 };
@@ -78,7 +79,11 @@ impl ContractDeleteTransaction {
     }
 }
 
-impl TransactionData for ContractDeleteTransactionData {}
+impl TransactionData for ContractDeleteTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.contract_id.map(RequiredSigner::Contract).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for ContractDeleteTransactionData {
     fn execute(
@@ -25,6 +25,7 @@ use crate::{
     ContractId,
     Error,
     Key,
+    RequiredSigner,
     ToProtobuf,
     Transaction,
     ValidateChecksums,
@@ -197,7 +198,15 @@ impl ContractUpdateTransaction {
     }
 }
 
-impl TransactionData for ContractUpdateTransactionData {}
+impl TransactionData for ContractUpdateTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        let contract = self.contract_id.map(RequiredSigner::Contract);
+        let admin_key = self.admin_key.clone().map(RequiredSigner::Key);
+        let auto_renew = self.auto_renew_account_id.map(RequiredSigner::Account);
+
+        contract.into_iter().chain(admin_key).chain(auto_renew).collect()
+    }
+}
 
 impl TransactionExecute for ContractUpdateTransactionData {
     fn execute(
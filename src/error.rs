@@ -3,6 +3,8 @@
 use std::error::Error as StdError;
 use std::result::Result as StdResult;
 
+use time::OffsetDateTime;
+
 use crate::entity_id::Checksum;
 use crate::{
     AccountId,
@@ -188,6 +190,37 @@ pub enum Error {
     /// Failed to verify a signature.
     #[error("failed to verify a signature: {0}")]
     SignatureVerify(#[source] BoxStdError),
+
+    /// A [`Signer`](crate::Signer) failed to sign a message.
+    #[error("failed to sign: {0}")]
+    Signer(#[source] BoxStdError),
+
+    /// A [`Signer`](crate::Signer) that needs to wait (for instance, on a remote signing service)
+    /// was used from a synchronous function, such as [`Transaction::to_bytes`](crate::Transaction::to_bytes).
+    ///
+    /// Use an asynchronous function (such as `execute`) instead.
+    #[error("signer could not sign synchronously; use an asynchronous API instead")]
+    SignerNotReady,
+
+    /// The [`Client`](crate::Client) was [closed](crate::Client::close), so it can't make any more requests.
+    #[error("client was closed")]
+    ClientClosed,
+
+    /// Two copies of a transaction couldn't be [merged](crate::Transaction::merge),
+    /// either because they aren't copies of the same transaction or because they have conflicting signatures.
+    #[error("failed to merge transactions: {0}")]
+    TransactionMerge(#[source] BoxStdError),
+
+    /// A transaction in a [`TransactionBundle`](crate::TransactionBundle) can no longer be submitted,
+    /// since its valid window has passed.
+    #[error("transaction `{description}` in the bundle expired at {valid_until}")]
+    TransactionBundleExpired {
+        /// The description of the transaction that expired.
+        description: String,
+
+        /// The end of the transaction's valid window.
+        valid_until: OffsetDateTime,
+    },
 }
 
 impl Error {
@@ -210,6 +243,10 @@ impl Error {
     pub(crate) fn signature_verify(error: impl Into<BoxStdError>) -> Self {
         Self::SignatureVerify(error.into())
     }
+
+    pub(crate) fn transaction_merge(error: impl Into<BoxStdError>) -> Self {
+        Self::TransactionMerge(error.into())
+    }
 }
 
 /// Failed to parse a mnemonic.
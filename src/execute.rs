@@ -3,7 +3,13 @@ mod error;
 
 use std::any::type_name;
 use std::borrow::Cow;
+use std::fmt;
 use std::ops::ControlFlow;
+use std::sync::atomic::{
+    AtomicBool,
+    AtomicUsize,
+    Ordering,
+};
 use std::time::{
     Duration,
     Instant,
@@ -15,16 +21,21 @@ use backoff::{
 };
 use futures_core::future::BoxFuture;
 use futures_util::StreamExt;
+use parking_lot::Mutex;
 use prost::Message;
-use rand::seq::SliceRandom;
-use rand::thread_rng;
 use tonic::metadata::AsciiMetadataValue;
 use tonic::transport::Channel;
 use triomphe::Arc;
 
-use crate::client::NetworkData;
+use crate::client::{
+    ClockOffset,
+    MirrorNetworkData,
+    NetworkData,
+    Throttle,
+};
 use crate::execute::error::is_tonic_status_transient;
 use crate::ping_query::PingQuery;
+use crate::request_observer::AnyRequestObserver;
 use crate::{
     client,
     retry,
@@ -32,8 +43,12 @@ use crate::{
     BoxGrpcFuture,
     Client,
     Error,
+    NodeSelectionStrategy,
+    RequestAttempt,
+    RequestType,
     Status,
     TransactionId,
+    TransactionIdGenerator,
     ValidateChecksums,
 };
 
@@ -70,6 +85,19 @@ pub(crate) trait Execute: ValidateChecksums {
         None
     }
 
+    /// Get the observer registered on this specific request, if any.
+    fn observer(&self) -> Option<&AnyRequestObserver> {
+        None
+    }
+
+    /// Get the request type of `request`, for client-side throttling.
+    ///
+    /// Requests without a request type are never throttled.
+    #[allow(unused_variables)]
+    fn request_type(request: &Self::GrpcRequest) -> Option<RequestType> {
+        None
+    }
+
     /// Check whether to retry an pre-check status.
     fn should_retry_pre_check(&self, _status: Status) -> bool {
         false
@@ -85,11 +113,11 @@ pub(crate) trait Execute: ValidateChecksums {
     ///
     /// A created request is cached per node until any request returns
     /// `TransactionExpired`; in which case, the request cache is cleared.
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)>;
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>>;
 
     /// Execute the created GRPC request against the provided GRPC channel.
     fn execute(
@@ -128,6 +156,23 @@ struct ExecuteContext {
     max_attempts: usize,
     // timeout for a single grpc request.
     grpc_timeout: Option<Duration>,
+    // the client's observer, `executable.observer()` is the request's own.
+    observer: Option<AnyRequestObserver>,
+    node_selection_strategy: Arc<NodeSelectionStrategy>,
+    throttle: Option<Arc<Throttle>>,
+    clock_offset: Arc<ClockOffset>,
+    // asked for the time when the network says our clock is off.
+    mirror_network: Arc<MirrorNetworkData>,
+    transaction_ids: Arc<TransactionIdGenerator>,
+    // set once the transaction ID has been regenerated because the node rejected its valid start.
+    regenerated_transaction_id: AtomicBool,
+}
+
+impl ExecuteContext {
+    fn generate_transaction_id(&self) -> Option<TransactionId> {
+        self.operator_account_id
+            .map(|it| self.transaction_ids.generate_at(it, self.clock_offset.now()))
+    }
 }
 
 pub(crate) async fn execute<E>(
@@ -138,6 +183,8 @@ pub(crate) async fn execute<E>(
 where
     E: Execute + Sync,
 {
+    let mut in_flight = client.begin_request()?;
+
     if client.auto_validate_checksums() {
         let ledger_id = client.ledger_id_internal();
         let ledger_id = ledger_id
@@ -159,7 +206,7 @@ where
         executable
             .operator_account_id()
             .copied()
-            .or_else(|| client.load_operator().as_ref().map(|it| it.account_id))
+            .or_else(|| client.load_operator().as_deref().map(|it| it.first().account_id))
     };
 
     let backoff = client.backoff();
@@ -173,19 +220,32 @@ where
         backoff_builder.with_max_elapsed_time(Some(timeout));
     }
 
-    execute_inner(
-        &ExecuteContext {
-            max_attempts: backoff.max_attempts,
-            backoff_config: backoff_builder.build(),
-            operator_account_id,
-            network: client.net().0.load_full(),
-            grpc_timeout: backoff.grpc_timeout,
-        },
-        executable,
-    )
-    .await
+    let ctx = ExecuteContext {
+        max_attempts: backoff.max_attempts,
+        backoff_config: backoff_builder.build(),
+        operator_account_id,
+        network: client.net().0.load_full(),
+        grpc_timeout: backoff.grpc_timeout,
+        observer: client.request_observer(),
+        node_selection_strategy: client.load_node_selection_strategy(),
+        throttle: client.load_throttle(),
+        clock_offset: client.load_clock_offset(),
+        mirror_network: client.mirrornet().load_full(),
+        transaction_ids: client.load_transaction_id_generator(),
+        regenerated_transaction_id: AtomicBool::new(false),
+    };
+
+    in_flight.run(execute_inner(&ctx, executable)).await
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "execute",
+        skip_all,
+        fields(request = type_name::<E>(), transaction_id = tracing::field::Empty)
+    )
+)]
 async fn execute_inner<E>(ctx: &ExecuteContext, executable: &E) -> crate::Result<E::Response>
 where
     E: Execute + Sync,
@@ -198,6 +258,15 @@ where
                 backoff_config: ctx.backoff_config.clone(),
                 max_attempts: ctx.max_attempts,
                 grpc_timeout: ctx.grpc_timeout,
+                // pings aren't attempts at executing the request, so they aren't observed.
+                observer: None,
+                node_selection_strategy: Arc::clone(&ctx.node_selection_strategy),
+                // nor do they count against the user's throttle budget.
+                throttle: None,
+                clock_offset: Arc::clone(&ctx.clock_offset),
+                mirror_network: Arc::clone(&ctx.mirror_network),
+                transaction_ids: Arc::clone(&ctx.transaction_ids),
+                regenerated_transaction_id: AtomicBool::new(false),
             };
             let ping_query = PingQuery::new(ctx.network.node_ids()[index]);
 
@@ -213,10 +282,18 @@ where
     // if we need to generate a transaction ID for this request (and one was not provided),
     // generate one now
     let explicit_transaction_id = executable.transaction_id();
-    let mut transaction_id = executable
+    let transaction_id = executable
         .requires_transaction_id()
         .then_some(explicit_transaction_id)
-        .and_then(|it| it.or_else(|| ctx.operator_account_id.map(TransactionId::generate)));
+        .and_then(|it| it.or_else(|| ctx.generate_transaction_id()));
+
+    if let Some(transaction_id) = &transaction_id {
+        record_span("transaction_id", transaction_id);
+    }
+
+    // a regenerated transaction ID has to outlive the backoff between attempts.
+    let transaction_id = Mutex::new(transaction_id);
+    let transaction_id = &transaction_id;
 
     // if we were explicitly given a list of nodes to use, we iterate through each
     // of the given nodes (in a random order)
@@ -227,12 +304,36 @@ where
 
     let explicit_node_indexes = explicit_node_indexes.as_deref();
 
+    let observing = ctx.observer.is_some() || executable.observer().is_some();
+
+    // an attempt is only reported once we know how long we wait before the next one.
+    let pending_attempt: Mutex<Option<RequestAttempt>> = Mutex::new(None);
+    let report_attempt = |backoff: Duration| {
+        let Some(mut attempt) = pending_attempt.lock().take() else { return };
+
+        attempt.backoff = backoff;
+
+        for observer in ctx.observer.iter().chain(executable.observer()) {
+            observer.on_attempt(&attempt);
+        }
+    };
+    let report_attempt = &report_attempt;
+    let pending_attempt = &pending_attempt;
+
+    // node attempts made so far, across every backoff.
+    let attempts_made = AtomicUsize::new(0);
+    let attempts_made = &attempts_made;
+
     let layer = move || async move {
         loop {
             let mut last_error: Option<Error> = None;
 
-            let random_node_indexes = random_node_indexes(&ctx.network, explicit_node_indexes)
-                .ok_or(retry::Error::EmptyTransient)?;
+            let random_node_indexes = random_node_indexes(
+                &ctx.network,
+                &ctx.node_selection_strategy,
+                explicit_node_indexes,
+            )
+            .ok_or(retry::Error::EmptyTransient)?;
 
             let random_node_indexes = {
                 let random_node_indexes = &random_node_indexes;
@@ -251,7 +352,33 @@ where
             let mut random_node_indexes = std::pin::pin!(random_node_indexes);
 
             while let Some(node_index) = random_node_indexes.next().await {
-                let tmp = execute_single(ctx, executable, node_index, &mut transaction_id).await;
+                // the previous attempt (if any) was followed by this one, immediately.
+                report_attempt(Duration::ZERO);
+
+                let attempt_number = attempts_made.fetch_add(1, Ordering::Relaxed) + 1;
+
+                let mut attempt = None;
+                let mut current_transaction_id = *transaction_id.lock();
+                let tmp = execute_single(
+                    ctx,
+                    executable,
+                    node_index,
+                    attempt_number,
+                    &mut current_transaction_id,
+                    observing.then_some(&mut attempt),
+                )
+                .await;
+
+                let previous_transaction_id =
+                    std::mem::replace(&mut *transaction_id.lock(), current_transaction_id);
+
+                if current_transaction_id != previous_transaction_id {
+                    if let Some(transaction_id) = &current_transaction_id {
+                        record_span("transaction_id", transaction_id);
+                    }
+                }
+
+                *pending_attempt.lock() = attempt;
 
                 log::log!(
                     match &tmp {
@@ -275,6 +402,35 @@ where
                     },
                 );
 
+                #[cfg(feature = "tracing")]
+                {
+                    let node_account_id = ctx.network.node_ids()[node_index];
+
+                    match &tmp {
+                        Ok(ControlFlow::Break(_)) => {
+                            tracing::debug!(%node_account_id, attempt = attempt_number, "attempt succeeded");
+                        }
+                        Ok(ControlFlow::Continue(error)) => tracing::warn!(
+                            %node_account_id,
+                            attempt = attempt_number,
+                            ?error,
+                            "attempt failed, trying the next node"
+                        ),
+                        Err(error) if error.is_transient() => tracing::warn!(
+                            %node_account_id,
+                            attempt = attempt_number,
+                            ?error,
+                            "attempt failed, backing off"
+                        ),
+                        Err(error) => tracing::error!(
+                            %node_account_id,
+                            attempt = attempt_number,
+                            ?error,
+                            "attempt failed"
+                        ),
+                    }
+                }
+
                 match tmp? {
                     ControlFlow::Continue(err) => last_error = Some(err),
                     ControlFlow::Break(res) => return Ok(res),
@@ -292,15 +448,27 @@ where
     // the outer loop continues until we timeout or reach the maximum number of "attempts"
     // an attempt is counted when we have a successful response from a node that must either
     // be retried immediately (on a new node) or retried after a backoff.
-    crate::retry(backoff, Some(ctx.max_attempts), layer).await
+    let result = crate::retry(backoff, Some(ctx.max_attempts), layer, report_attempt).await;
+
+    report_attempt(Duration::ZERO);
+
+    result
+}
+
+/// Records `value` as `field` on the current span, this does nothing without the `tracing` feature.
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+fn record_span(field: &'static str, value: &dyn fmt::Display) {
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record(field, tracing::field::display(value));
 }
 
+/// Returns how to proceed after a request failed with `status`, and whether the node was marked unhealthy.
 fn map_tonic_error(
     status: tonic::Status,
     network: &client::NetworkData,
     node_index: usize,
     request_free: bool,
-) -> retry::Error {
+) -> (retry::Error, bool) {
     const MIME_HTML: &[u8] = b"text/html";
 
     match status.code() {
@@ -310,7 +478,7 @@ fn map_tonic_error(
             network.mark_node_unhealthy(node_index);
 
             // try the next node in our allowed list, immediately
-            retry::Error::Transient(status.into())
+            (retry::Error::Transient(status.into()), true)
         }
 
         // todo: find a way to make this less fragile
@@ -327,61 +495,145 @@ fn map_tonic_error(
             // hack to the hack:
             // if this is a free request let's try retrying it anyway...
             match request_free {
-                true => retry::Error::Transient(status.into()),
-                false => retry::Error::Permanent(status.into()),
+                true => (retry::Error::Transient(status.into()), true),
+                false => (retry::Error::Permanent(status.into()), true),
             }
         }
 
         _ if is_tonic_status_transient(&status) => {
             network.mark_node_unhealthy(node_index);
 
-            retry::Error::Transient(status.into())
+            (retry::Error::Transient(status.into()), true)
         }
 
         // fail immediately
-        _ => retry::Error::Permanent(status.into()),
+        _ => (retry::Error::Permanent(status.into()), false),
     }
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "attempt",
+        skip_all,
+        fields(
+            request = type_name::<E>(),
+            attempt = attempt_number,
+            node_account_id = tracing::field::Empty,
+            transaction_id = tracing::field::Empty,
+            status = tracing::field::Empty,
+        )
+    )
+)]
 async fn execute_single<E: Execute + Sync>(
     ctx: &ExecuteContext,
     executable: &E,
     node_index: usize,
+    attempt_number: usize,
     transaction_id: &mut Option<TransactionId>,
+    // when observing, filled in with the details of the attempt (if a request is actually sent).
+    mut attempt: Option<&mut Option<RequestAttempt>>,
 ) -> retry::Result<ControlFlow<E::Response, Error>> {
     let (node_account_id, channel) = ctx.network.channel(node_index);
 
+    record_span("node_account_id", &node_account_id);
+
+    if let Some(transaction_id) = transaction_id {
+        record_span("transaction_id", transaction_id);
+    }
+
     log::debug!(
-        "Preparing {} on node at index {node_index} / node id {node_account_id}",
+        "Preparing {} (attempt {attempt_number}) on node at index {node_index} / node id {node_account_id}",
         type_name::<E>()
     );
 
     let (request, context) = executable
         .make_request(transaction_id.as_ref(), node_account_id)
+        .await
         // Does not represent a network error or error returned by a node
         .map_err(retry::Error::Permanent)?;
 
+    if let Some(throttle) = &ctx.throttle {
+        if let Some(request_type) = E::request_type(&request) {
+            throttle.acquire(node_account_id, &request_type, ctx.network.node_ids().len()).await;
+        }
+    }
+
     log::debug!(
-        "Executing {} on node at index {node_index} / node id {node_account_id}",
+        "Executing {} (attempt {attempt_number}) on node at index {node_index} / node id {node_account_id}",
         type_name::<E>()
     );
 
+    if let Some(attempt) = attempt.as_deref_mut() {
+        *attempt = Some(RequestAttempt {
+            node_account_id,
+            transaction_id: *transaction_id,
+            request: request.encode_to_vec(),
+            grpc_status: tonic::Code::Ok,
+            pre_check_status: None,
+            backoff: Duration::ZERO,
+            node_marked_unhealthy: false,
+        });
+    }
+
+    // shorthand for updating the observed attempt (if there is one).
+    let mut observe = |f: &dyn Fn(&mut RequestAttempt)| {
+        if let Some(Some(attempt)) = attempt.as_deref_mut() {
+            f(attempt);
+        }
+    };
+
+    let sent_at = Instant::now();
     let fut = executable.execute(channel, request);
 
     let response = match ctx.grpc_timeout {
         Some(it) => match tokio::time::timeout(it, fut).await {
             Ok(it) => it,
             Err(_) => {
+                ctx.network.record_node_failure(node_index);
+                observe(&|it| it.grpc_status = tonic::Code::DeadlineExceeded);
+                record_span("status", &format_args!("{:?}", tonic::Code::DeadlineExceeded));
+
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    %node_account_id,
+                    attempt = attempt_number,
+                    grpc_status = ?tonic::Code::DeadlineExceeded,
+                    "request timed out"
+                );
+
                 return Ok(ControlFlow::Continue(crate::Error::GrpcStatus(
                     tonic::Status::deadline_exceeded("explicitly given grpc timeout was exceeded"),
-                )))
+                )));
             }
         },
         None => fut.await,
     };
 
     let response = response.map(tonic::Response::into_inner).map_err(|status| {
-        map_tonic_error(status, &ctx.network, node_index, transaction_id.is_none())
+        let code = status.code();
+        record_span("status", &format_args!("{code:?}"));
+
+        ctx.network.record_node_failure(node_index);
+
+        let (error, marked_unhealthy) =
+            map_tonic_error(status, &ctx.network, node_index, transaction_id.is_none());
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            %node_account_id,
+            attempt = attempt_number,
+            grpc_status = ?code,
+            node_marked_unhealthy = marked_unhealthy,
+            "request failed"
+        );
+
+        observe(&|it| {
+            it.grpc_status = code;
+            it.node_marked_unhealthy = marked_unhealthy;
+        });
+
+        error
     });
 
     let response = match response {
@@ -394,7 +646,7 @@ async fn execute_single<E: Execute + Sync>(
     };
 
     // at this point, any failure isn't from the node, it's from the request.
-    ctx.network.mark_node_healthy(node_index);
+    ctx.network.mark_node_healthy(node_index, sent_at.elapsed());
 
     let status = E::response_pre_check_status(&response)
         .and_then(|status| {
@@ -403,6 +655,12 @@ async fn execute_single<E: Execute + Sync>(
         })
         .map_err(retry::Error::Permanent)?;
 
+    observe(&|it| it.pre_check_status = Some(status));
+    record_span("status", &format_args!("{status:?}"));
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(%node_account_id, attempt = attempt_number, ?status, "received pre-check status");
+
     match status {
         Status::Ok if executable.should_retry(&response) => Err(retry::Error::Transient(
             executable.make_error_pre_check(status, transaction_id.as_ref(), response),
@@ -423,14 +681,41 @@ async fn execute_single<E: Execute + Sync>(
             )))
         }
 
-        // would do an `if_let` but, not stable ._.
-        Status::TransactionExpired if ctx.operator_account_id.is_some() => {
-            // the transaction that was generated has since expired
-            // re-generate the transaction ID and try again, immediately
+        Status::TransactionExpired | Status::InvalidTransactionStart
+            if ctx.operator_account_id.is_some()
+                && !ctx.regenerated_transaction_id.swap(true, Ordering::Relaxed) =>
+        {
+            // either the transaction that was generated has since expired,
+            // or the node's clock disagrees with ours about when the transaction starts.
+            // re-generate the transaction ID (with the latest clock offset) and try again, immediately, but only once.
+            let previous = *transaction_id;
+
+            if status == Status::InvalidTransactionStart {
+                // the valid start is in the future as far as the network is concerned,
+                // records can't tell us how far our clock is ahead (there won't be any), so ask a mirror node.
+                ctx.clock_offset.synchronize(&ctx.mirror_network).await;
+
+                if let Some(previous) = previous {
+                    ctx.transaction_ids.rewind(previous.account_id, previous.valid_start);
+                }
+            }
+
+            *transaction_id = ctx.generate_transaction_id();
 
-            let new = TransactionId::generate(ctx.operator_account_id.unwrap());
+            if let Some(transaction_id) = transaction_id {
+                record_span("transaction_id", transaction_id);
+            }
 
-            *transaction_id = Some(new);
+            #[cfg(feature = "tracing")]
+            tracing::info!(
+                %node_account_id,
+                attempt = attempt_number,
+                ?status,
+                previous_transaction_id = previous.map(tracing::field::display),
+                transaction_id = transaction_id.map(tracing::field::display),
+                clock_offset = %ctx.clock_offset.get(),
+                "regenerated the transaction ID"
+            );
 
             Ok(ControlFlow::Continue(executable.make_error_pre_check(
                 status,
@@ -462,11 +747,10 @@ async fn execute_single<E: Execute + Sync>(
 // todo: return an iterator.
 fn random_node_indexes(
     network: &client::NetworkData,
+    strategy: &NodeSelectionStrategy,
     explicit_node_indexes: Option<&[usize]>,
 ) -> Option<Vec<usize>> {
-    // cache the rng impl and "now" because `thread_rng` is TLS (a thread local),
-    // and because using the same reference time avoids situations where a node that wasn't available becomes available.
-    let mut rng = thread_rng();
+    // cache "now" because using the same reference time avoids situations where a node that wasn't available becomes available.
     let now = Instant::now();
 
     if let Some(indexes) = explicit_node_indexes {
@@ -477,7 +761,7 @@ fn random_node_indexes(
 
         assert!(!indexes.is_empty(), "empty explicitly set nodes");
 
-        indexes.shuffle(&mut rng);
+        network.order_node_indexes(strategy, &mut indexes);
 
         return Some(indexes);
     }
@@ -489,11 +773,163 @@ fn random_node_indexes(
             return None;
         }
 
-        // would put this inline, but borrowck wouldn't allow that.
         let amount = (indexes.len() + 2) / 3;
 
-        let (shuffled, _) = indexes.partial_shuffle(&mut rng, amount);
+        network.order_node_indexes(strategy, &mut indexes);
+        indexes.truncate(amount);
+
+        Some(indexes)
+    }
+}
+
+#[cfg(all(test, feature = "tracing", feature = "testing"))]
+mod tests {
+    use std::collections::HashMap;
+    use std::fmt;
+    use std::sync::atomic::{
+        AtomicU64,
+        Ordering,
+    };
+
+    use parking_lot::Mutex;
+    use tracing::field::{
+        Field,
+        Visit,
+    };
+    use tracing::span::{
+        Attributes,
+        Current,
+        Id,
+        Record,
+    };
+    use tracing::{
+        Event,
+        Metadata,
+        Subscriber,
+    };
+
+    use crate::testing::tests::{
+        fast_client,
+        transfer,
+    };
+    use crate::testing::{
+        MockNetwork,
+        MockResponse,
+    };
+    use crate::Status;
+
+    type Fields = HashMap<&'static str, String>;
+
+    struct FieldVisitor<'a>(&'a mut Fields);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.insert(field.name(), format!("{value:?}"));
+        }
+    }
+
+    /// Collects every event, along with every transaction ID recorded on an `execute` span.
+    ///
+    /// Only good for a single threaded runtime, since there's just the one stack of entered spans.
+    #[derive(Default)]
+    struct Collector {
+        next_id: AtomicU64,
+        spans: Mutex<HashMap<u64, &'static Metadata<'static>>>,
+        entered: Mutex<Vec<u64>>,
+        events: Mutex<Vec<Fields>>,
+        execute_transaction_ids: Mutex<Vec<String>>,
+    }
+
+    impl Subscriber for Collector {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let id = Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1);
+
+            self.spans.lock().insert(id.into_u64(), span.metadata());
+            self.record(&id, &Record::new(span.values()));
+
+            id
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            if self.spans.lock()[&span.into_u64()].name() != "execute" {
+                return;
+            }
+
+            let mut fields = Fields::new();
+            values.record(&mut FieldVisitor(&mut fields));
+
+            if let Some(transaction_id) = fields.remove("transaction_id") {
+                self.execute_transaction_ids.lock().push(transaction_id);
+            }
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields::new();
+            event.record(&mut FieldVisitor(&mut fields));
+
+            self.events.lock().push(fields);
+        }
+
+        fn enter(&self, span: &Id) {
+            self.entered.lock().push(span.into_u64());
+        }
+
+        fn exit(&self, span: &Id) {
+            let mut entered = self.entered.lock();
+
+            if let Some(index) = entered.iter().rposition(|it| *it == span.into_u64()) {
+                entered.remove(index);
+            }
+        }
+
+        fn current_span(&self) -> Current {
+            match self.entered.lock().last() {
+                Some(id) => Current::new(Id::from_u64(*id), self.spans.lock()[id]),
+                None => Current::none(),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn regenerated_transaction_id_is_traced() {
+        let dispatch = tracing::Dispatch::new(Collector::default());
+        let _guard = tracing::dispatcher::set_default(&dispatch);
+
+        let network = MockNetwork::start(1).await.unwrap();
+        let client = fast_client(&network);
+        let node_account_id = network.nodes()[0].account_id().to_string();
+
+        network.nodes()[0].respond(MockResponse::pre_check(Status::InvalidTransactionStart));
+
+        let response = transfer().execute(&client).await.unwrap();
+        let transaction_id = response.transaction_id.to_string();
+
+        let collector = dispatch.downcast_ref::<Collector>().unwrap();
+        let events = collector.events.lock();
+        let named = |message: &str| {
+            events.iter().filter(move |it| it.get("message").is_some_and(|it| it == message))
+        };
+
+        let rejected = named("received pre-check status").next().unwrap();
+        assert_eq!(rejected["node_account_id"], node_account_id);
+        assert_eq!(rejected["status"], "InvalidTransactionStart");
+
+        let regenerated = named("regenerated the transaction ID").next().unwrap();
+        assert_eq!(regenerated["status"], "InvalidTransactionStart");
+        assert_eq!(regenerated["transaction_id"], transaction_id);
+        assert_ne!(regenerated["previous_transaction_id"], transaction_id);
+
+        assert_eq!(named("attempt failed, trying the next node").count(), 1);
+        assert_eq!(named("attempt succeeded").count(), 1);
 
-        Some(shuffled.to_vec())
+        // the span starts out with the first transaction ID and ends up with the one that was used.
+        let recorded = collector.execute_transaction_ids.lock();
+        assert_eq!(*recorded, [regenerated["previous_transaction_id"].clone(), transaction_id]);
     }
 }
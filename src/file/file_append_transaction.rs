@@ -27,6 +27,7 @@ use crate::{
     BoxGrpcFuture,
     Error,
     FileId,
+    RequiredSigner,
     Transaction,
     ValidateChecksums,
 };
@@ -91,6 +92,10 @@ impl TransactionData for FileAppendTransactionData {
     fn wait_for_receipt(&self) -> bool {
         true
     }
+
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.file_id.map(RequiredSigner::File).into_iter().collect()
+    }
 }
 
 impl ChunkedTransactionData for FileAppendTransactionData {
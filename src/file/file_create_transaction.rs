@@ -27,6 +27,7 @@ use crate::{
     BoxGrpcFuture,
     Key,
     KeyList,
+    RequiredSigner,
     Transaction,
 };
 
@@ -164,6 +165,10 @@ impl TransactionData for FileCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::new(5)
     }
+
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.keys.clone().map(|it| RequiredSigner::Key(it.into())).into_iter().collect()
+    }
 }
 
 impl TransactionExecute for FileCreateTransactionData {
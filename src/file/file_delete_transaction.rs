@@ -21,6 +21,7 @@ use crate::{
     BoxGrpcFuture,
     Error,
     FileId,
+    RequiredSigner,
     Transaction,
     ValidateChecksums,
 };
@@ -53,7 +54,11 @@ impl FileDeleteTransaction {
     }
 }
 
-impl TransactionData for FileDeleteTransactionData {}
+impl TransactionData for FileDeleteTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.file_id.map(RequiredSigner::File).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for FileDeleteTransactionData {
     fn execute(
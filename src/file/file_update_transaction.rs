@@ -28,6 +28,7 @@ use crate::{
     FileId,
     Key,
     KeyList,
+    RequiredSigner,
     Transaction,
     ValidateChecksums,
 };
@@ -167,7 +168,14 @@ impl FileUpdateTransaction {
     }
 }
 
-impl TransactionData for FileUpdateTransactionData {}
+impl TransactionData for FileUpdateTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        let file = self.file_id.map(RequiredSigner::File);
+        let keys = self.keys.clone().map(|it| RequiredSigner::Key(it.into()));
+
+        file.into_iter().chain(keys).collect()
+    }
+}
 
 impl TransactionExecute for FileUpdateTransactionData {
     fn execute(
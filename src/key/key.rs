@@ -1,8 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
 use hedera_proto::services;
 
 use crate::contract::DelegateContractId;
+use crate::transaction::TransactionExecute;
 use crate::{
     ContractId,
     Error,
@@ -10,6 +13,7 @@ use crate::{
     KeyList,
     PublicKey,
     ToProtobuf,
+    Transaction,
 };
 
 /// Any method that can be used to authorize an operation on Hiero.
@@ -36,6 +40,122 @@ impl Key {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Checks whether the signatures on `transaction` satisfy this key.
+    ///
+    /// Every signature is verified against the body for every node and chunk,
+    /// so a public key only counts as having signed if it signed all of them.
+    ///
+    /// [`ContractId`](Self::ContractId) and [`DelegateContractId`](Self::DelegateContractId) keys
+    /// can only be satisfied by the contract itself while it's executing, so they're never satisfied here.
+    /// Neither are empty [`KeyList`]s or ones with a threshold of zero, since the network treats those as unusable.
+    ///
+    /// # Errors
+    /// - [`Error::SignerNotReady`] if a signer of the transaction can't sign synchronously.
+    /// - [`Error::NoPayerAccountOrTransactionId`] if the transaction has no transaction ID.
+    ///
+    /// # Panics
+    /// - If `transaction` isn't frozen.
+    pub fn is_satisfied_by<D: TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+    ) -> crate::Result<KeySatisfaction> {
+        let sources = transaction.make_sources()?;
+
+        Ok(self.satisfaction(&|key| {
+            sources.signed_transactions().iter().all(|it| {
+                let sig_pair = it.sig_map.as_ref().map_or(&[][..], |it| it.sig_pair.as_slice());
+
+                has_valid_signature(key, &it.body_bytes, sig_pair)
+            })
+        }))
+    }
+
+    /// Checks whether `signatures`, made over `body_bytes`, satisfy this key.
+    ///
+    /// This is for signatures collected outside of a [`Transaction`],
+    /// see [`is_satisfied_by`](Self::is_satisfied_by) for the details.
+    #[must_use]
+    pub fn is_satisfied_by_signatures(
+        &self,
+        body_bytes: &[u8],
+        signatures: &HashMap<PublicKey, Vec<u8>>,
+    ) -> KeySatisfaction {
+        self.satisfaction(&|key| {
+            signatures.get(key).map_or(false, |signature| key.verify(body_bytes, signature).is_ok())
+        })
+    }
+
+    fn satisfaction(&self, is_signed: &dyn Fn(&PublicKey) -> bool) -> KeySatisfaction {
+        let (satisfied, children) = match self {
+            Self::Single(key) => (is_signed(key), Vec::new()),
+            Self::ContractId(_) | Self::DelegateContractId(_) => (false, Vec::new()),
+            Self::KeyList(list) => {
+                let children: Vec<_> =
+                    list.keys.iter().map(|it| it.satisfaction(is_signed)).collect();
+
+                let count = children.iter().filter(|it| it.satisfied).count();
+                let required = list.threshold.map_or(children.len(), |it| it as usize);
+
+                // the network won't accept anything for a key list that requires nothing.
+                (required > 0 && count >= required, children)
+            }
+        };
+
+        KeySatisfaction { key: self.clone(), satisfied, children }
+    }
+}
+
+fn has_valid_signature(
+    key: &PublicKey,
+    body_bytes: &[u8],
+    sig_pair: &[services::SignaturePair],
+) -> bool {
+    use services::signature_pair::Signature;
+
+    let key_bytes = key.to_bytes_raw();
+
+    sig_pair.iter().filter(|it| key_bytes.starts_with(&it.pub_key_prefix)).any(|it| {
+        matches!(
+            &it.signature,
+            Some(Signature::Ed25519(signature) | Signature::EcdsaSecp256k1(signature))
+                if key.verify(body_bytes, signature).is_ok()
+        )
+    })
+}
+
+/// Whether a [`Key`] is satisfied, see [`Key::is_satisfied_by`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeySatisfaction {
+    /// The key this is about.
+    pub key: Key,
+
+    /// Whether there are enough valid signatures for `key`.
+    pub satisfied: bool,
+
+    /// If `key` is a [`KeyList`], whether each of the keys in it is satisfied, in the same order.
+    pub children: Vec<KeySatisfaction>,
+}
+
+impl KeySatisfaction {
+    /// Returns the keys that still need to sign, the ones that aren't satisfied
+    /// and are in a [`KeyList`] that isn't satisfied either.
+    ///
+    /// Any one of these could be enough for a [`KeyList`] with a threshold.
+    /// An empty [`KeyList`], or one with a threshold of zero, can't be satisfied by any signature,
+    /// so it's unsatisfied without anything missing.
+    #[must_use]
+    pub fn missing_keys(&self) -> Vec<&Key> {
+        if self.satisfied {
+            return Vec::new();
+        }
+
+        if !matches!(self.key, Key::KeyList(_)) {
+            return Vec::from([&self.key]);
+        }
+
+        self.children.iter().flat_map(Self::missing_keys).collect()
+    }
 }
 
 impl ToProtobuf for Key {
@@ -111,14 +231,22 @@ impl FromProtobuf<services::Key> for Key {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use assert_matches::assert_matches;
     use hedera_proto::services;
     use hex_literal::hex;
 
     use crate::protobuf::FromProtobuf;
     use crate::{
+        ContractId,
+        Hbar,
         Key,
+        KeyList,
+        PrivateKey,
         PublicKey,
+        TransactionId,
+        TransferTransaction,
     };
 
     #[test]
@@ -211,4 +339,75 @@ mod tests {
 
         assert_matches!(Key::from_protobuf(key), Err(crate::Error::FromProtobuf(_)));
     }
+
+    #[test]
+    fn is_satisfied_by() -> crate::Result<()> {
+        let keys: Vec<_> = (0..3).map(|_| PrivateKey::generate_ed25519()).collect();
+
+        // 2 of (keys[0], contract, all of (keys[1], keys[2]))
+        let key = Key::KeyList(KeyList {
+            keys: Vec::from([
+                keys[0].public_key().into(),
+                ContractId::new(0, 0, 5005).into(),
+                KeyList::from([keys[1].public_key(), keys[2].public_key()]).into(),
+            ]),
+            threshold: Some(2),
+        });
+
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(2.into(), Hbar::new(2))
+            .hbar_transfer(101.into(), Hbar::new(-2))
+            .transaction_id(TransactionId::generate(101.into()))
+            .node_account_ids([6.into(), 7.into()])
+            .freeze()?
+            .sign(keys[0].clone())
+            .sign(keys[1].clone());
+
+        let satisfaction = key.is_satisfied_by(&tx)?;
+
+        assert!(!satisfaction.satisfied);
+        assert_eq!(
+            satisfaction.children.iter().map(|it| it.satisfied).collect::<Vec<_>>(),
+            [true, false, false]
+        );
+        assert_eq!(
+            satisfaction.missing_keys(),
+            [&Key::from(ContractId::new(0, 0, 5005)), &Key::from(keys[2].public_key())]
+        );
+
+        tx.sign(keys[2].clone());
+
+        let satisfaction = key.is_satisfied_by(&tx)?;
+
+        assert!(satisfaction.satisfied);
+        assert!(satisfaction.missing_keys().is_empty());
+
+        // a key list that requires nothing is never satisfied.
+        assert!(!Key::KeyList(KeyList::new()).is_satisfied_by(&tx)?.satisfied);
+        assert!(
+            !Key::KeyList(KeyList {
+                keys: Vec::from([keys[0].public_key().into()]),
+                threshold: Some(0),
+            })
+            .is_satisfied_by(&tx)?
+            .satisfied
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_satisfied_by_signatures() {
+        let private_key = PrivateKey::generate_ecdsa();
+        let key = Key::from(private_key.public_key());
+
+        let body_bytes = b"not really a transaction body";
+
+        let good = HashMap::from([(private_key.public_key(), private_key.sign(body_bytes))]);
+        let bad = HashMap::from([(private_key.public_key(), private_key.sign(b"something else"))]);
+
+        assert!(key.is_satisfied_by_signatures(body_bytes, &good).satisfied);
+        assert!(!key.is_satisfied_by_signatures(body_bytes, &bad).satisfied);
+        assert_eq!(key.is_satisfied_by_signatures(body_bytes, &bad).missing_keys(), [&key]);
+    }
 }
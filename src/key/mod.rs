@@ -6,7 +6,10 @@ mod key_list;
 mod private_key;
 mod public_key;
 
-pub use key::Key;
+pub use key::{
+    Key,
+    KeySatisfaction,
+};
 pub use key_list::KeyList;
 pub use private_key::PrivateKey;
 pub use public_key::PublicKey;
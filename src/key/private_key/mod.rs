@@ -594,13 +594,16 @@ impl PrivateKey {
     /// # Errors
     /// This function will freeze the transaction if it is not frozen.
     /// As such, any error that can be occur during [`Transaction::freeze`] can also occur here.
+    ///
+    /// [`Error::SignerNotReady`](crate::Error::SignerNotReady) if the transaction has a signer
+    /// that can't sign synchronously.
     pub fn sign_transaction<D: crate::transaction::TransactionExecute>(
         &self,
         transaction: &mut Transaction<D>,
     ) -> crate::Result<Vec<u8>> {
         transaction.freeze()?;
 
-        let sig = transaction.add_signature_signer(&AnySigner::PrivateKey(self.clone()));
+        let sig = transaction.add_signature_signer(&AnySigner::PrivateKey(self.clone()))?;
 
         Ok(sig)
     }
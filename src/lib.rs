@@ -101,6 +101,8 @@ mod protobuf;
 
 mod account;
 mod address_book;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 mod client;
 mod contract;
 mod custom_fee_limit;
@@ -129,6 +131,7 @@ mod pending_airdrop_record;
 mod ping_query;
 mod prng_transaction;
 mod query;
+mod request_observer;
 mod retry;
 mod schedule;
 mod semantic_version;
@@ -137,6 +140,9 @@ mod signer;
 mod staked_id;
 mod staking_info;
 mod system;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod throttle_definitions;
 mod token;
 mod topic;
 mod transaction;
@@ -171,8 +177,19 @@ pub use address_book::{
     NodeDeleteTransaction,
     NodeUpdateTransaction,
 };
-pub use client::Client;
 pub(crate) use client::Operator;
+pub use client::{
+    Client,
+    LatencyPercentiles,
+    MirrorEndpoint,
+    NodeHealthState,
+    NodeSelectionStrategy,
+    NodeStats,
+    PayerSelectionStrategy,
+    Proxy,
+    ProxyKind,
+    TlsMode,
+};
 pub use contract::{
     ContractBytecodeQuery,
     ContractCallQuery,
@@ -243,6 +260,7 @@ pub use hedera_proto::services::ResponseCodeEnum as Status;
 pub use key::{
     Key,
     KeyList,
+    KeySatisfaction,
     PrivateKey,
     PublicKey,
 };
@@ -257,7 +275,10 @@ pub use mnemonic::Mnemonic;
 pub use network_version_info::NetworkVersionInfo;
 pub use network_version_info_query::NetworkVersionInfoQuery;
 pub(crate) use network_version_info_query::NetworkVersionInfoQueryData;
-pub use node_address::NodeAddress;
+pub use node_address::{
+    NodeAddress,
+    NodeEndpoint,
+};
 pub use node_address_book::NodeAddressBook;
 pub use node_address_book_query::NodeAddressBookQuery;
 pub(crate) use node_address_book_query::NodeAddressBookQueryData;
@@ -272,6 +293,10 @@ pub use query::{
     AnyQueryResponse,
     Query,
 };
+pub use request_observer::{
+    RequestAttempt,
+    RequestObserver,
+};
 pub(crate) use retry::retry;
 pub use schedule::{
     ScheduleCreateTransaction,
@@ -283,6 +308,7 @@ pub use schedule::{
 };
 pub use semantic_version::SemanticVersion;
 pub use service_endpoint::ServiceEndpoint;
+pub use signer::Signer;
 pub use staking_info::StakingInfo;
 pub use system::{
     FreezeTransaction,
@@ -290,6 +316,11 @@ pub use system::{
     SystemDeleteTransaction,
     SystemUndeleteTransaction,
 };
+pub use throttle_definitions::{
+    ThrottleBucket,
+    ThrottleDefinitions,
+    ThrottleGroup,
+};
 pub use token::{
     AnyCustomFee,
     AssessedCustomFee,
@@ -347,11 +378,20 @@ pub use topic::{
     TopicUpdateTransaction,
 };
 pub use transaction::{
+    merge_transaction_bytes,
     AnyTransaction,
+    RequiredSigner,
+    TokenKeyKind,
+    TopicKeyKind,
     Transaction,
+    TransactionBundle,
+    TransactionBundleEntry,
 };
 pub use transaction_hash::TransactionHash;
-pub use transaction_id::TransactionId;
+pub use transaction_id::{
+    TransactionId,
+    TransactionIdGenerator,
+};
 pub use transaction_receipt::TransactionReceipt;
 pub use transaction_receipt_query::TransactionReceiptQuery;
 pub use transaction_record::TransactionRecord;
@@ -11,11 +11,10 @@ pub use any::{
 };
 pub(crate) use subscribe::{
     subscribe,
+    MirrorQueryExecute,
     MirrorRequest,
 };
 
-use self::subscribe::MirrorQueryExecute;
-
 /// A query that can be executed on the Hiero mirror network.
 #[derive(Clone, Debug, Default)]
 pub struct MirrorQuery<D> {
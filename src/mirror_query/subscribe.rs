@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Instant;
+
 use async_stream::stream;
 use backoff::backoff::Backoff;
 use backoff::ExponentialBackoff;
@@ -9,7 +11,11 @@ use futures_util::StreamExt;
 use tokio::time::sleep;
 use tonic::transport::Channel;
 use tonic::Status;
+#[cfg(feature = "tracing")]
+use tracing::Instrument;
+use triomphe::Arc;
 
+use crate::client::MirrorNetworkData;
 use crate::mirror_query::AnyMirrorQueryData;
 use crate::{
     Client,
@@ -124,10 +130,13 @@ where
             std::time::Duration::from_millis(backoff::default::MAX_ELAPSED_TIME_MILLIS)
         });
 
-        // note: we don't care about keeping the mirrornet around, so, we just take the channel (which is arc-like)
-        let channel = client.mirrornet().load().channel();
+        // note: we keep the mirrornet as it was when the subscription started, updates to it don't affect existing subscriptions.
+        let mirrornet = client.mirrornet().load_full();
 
-        Self::make_item_stream(crate::mirror_query::subscribe(channel, timeout, self.clone()))
+        Self::make_item_stream(until_closed(
+            client,
+            crate::mirror_query::subscribe(mirrornet, timeout, self.clone()),
+        ))
     }
 
     fn execute_with_optional_timeout<'a>(
@@ -140,10 +149,13 @@ where
             std::time::Duration::from_millis(backoff::default::MAX_ELAPSED_TIME_MILLIS)
         });
 
-        // note: we don't care about keeping the mirrornet around, so, we just take the channel (which is arc-like)
-        let channel = client.mirrornet().load().channel();
+        // note: we keep the mirrornet as it was when the subscription started, updates to it don't affect existing subscriptions.
+        let mirrornet = client.mirrornet().load_full();
 
-        Self::try_collect(crate::mirror_query::subscribe(channel, timeout, self.clone()))
+        Self::try_collect(until_closed(
+            client,
+            crate::mirror_query::subscribe(mirrornet, timeout, self.clone()),
+        ))
     }
 }
 
@@ -180,8 +192,47 @@ pub trait MirrorRequest: Send {
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a;
 }
 
+/// Ends `stream` with [`Error::ClientClosed`] if `client` is closed before `stream` ends by itself.
+///
+/// Subscriptions can stay open for as long as the caller holds on to them, so they aren't counted as in flight,
+/// `Client::close` would otherwise wait for the caller to drop them.
+fn until_closed<'a, T: Send + 'a>(
+    client: &crate::Client,
+    stream: impl Stream<Item = crate::Result<T>> + Send + 'a,
+) -> impl Stream<Item = crate::Result<T>> + Send + 'a {
+    let client = client.clone();
+
+    stream! {
+        let mut closed = match client.begin_subscription() {
+            Ok(it) => it,
+            Err(e) => {
+                yield Err(e);
+                return;
+            }
+        };
+
+        let mut stream = std::pin::pin!(stream);
+
+        loop {
+            let next = tokio::select! {
+                item = stream.next() => item.map(Ok),
+                _ = closed.wait_for(|closed| *closed) => Some(Err(Error::ClientClosed)),
+            };
+
+            match next {
+                Some(Ok(item)) => yield item,
+                Some(Err(e)) => {
+                    yield Err(e);
+                    return;
+                }
+                None => return,
+            }
+        }
+    }
+}
+
 pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
-    channel: Channel,
+    mirrornet: Arc<MirrorNetworkData>,
     timeout: std::time::Duration,
     request: R,
 ) -> impl Stream<Item = crate::Result<I>> + Send {
@@ -201,10 +252,33 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
 
         let mut context = R::Context::default();
 
+        let mut index = mirrornet.next_index(Instant::now());
+
+        #[cfg(feature = "tracing")]
+        let mut connection_number = 0_usize;
+
         loop {
+            // one span per connection, `yield`s aren't part of it, so only the work done while connected is instrumented.
+            #[cfg(feature = "tracing")]
+            let span = {
+                connection_number += 1;
+
+                tracing::info_span!(
+                    "mirror_subscription",
+                    connection = connection_number,
+                    mirror_node = %mirrornet.address(index),
+                    status = tracing::field::Empty,
+                )
+            };
+
             let status: Status = 'request: loop {
                 // attempt to establish the stream
-                let response = request.connect(&context, channel.clone()).await;
+                let response = request.connect(&context, mirrornet.channel(index));
+
+                #[cfg(feature = "tracing")]
+                let response = response.instrument(span.clone());
+
+                let response = response.await;
 
                 let stream = match response {
                     // success, we now have a stream and may begin waiting for messages
@@ -217,12 +291,18 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
 
                 let mut stream = std::pin::pin!(stream);
 
+                mirrornet.mark_healthy(index);
                 backoff.reset();
                 backoff_inf.reset();
 
                 #[allow(unused_labels)]
                 'message: loop {
-                    let message = stream.next().await.transpose();
+                    let message = stream.next();
+
+                    #[cfg(feature = "tracing")]
+                    let message = message.instrument(span.clone());
+
+                    let message = message.await.transpose();
 
                     let message = match message {
                         Ok(Some(message)) => message,
@@ -243,15 +323,31 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
                 }
             };
 
+            #[cfg(feature = "tracing")]
+            span.record("status", tracing::field::debug(status.code()));
+
             match status.code() {
-                tonic::Code::Unavailable | tonic::Code::ResourceExhausted => {
-                    // encountered a temporarily down or overloaded service
-                    sleep(backoff_inf.next_backoff().unwrap()).await;
-                }
+                _ if is_mirror_node_failure(&status) => {
+                    // encountered a temporarily down or overloaded service (or the connection was aborted by the server),
+                    // resume on the next healthy mirror node, the context ensures we pick up where we left off.
+                    mirrornet.mark_unhealthy(index);
+
+                    // only wait if there's nowhere else to go.
+                    if !mirrornet.has_healthy(Instant::now()) {
+                        sleep(backoff_inf.next_backoff().unwrap()).await;
+                    }
 
-                tonic::Code::Unknown if status.message() == "error reading a body from connection: connection reset" => {
-                    // connection was aborted by the server
-                    sleep(backoff_inf.next_backoff().unwrap()).await;
+                    let next = mirrornet.next_index(Instant::now());
+
+                    if next != index {
+                        log::debug!(
+                            "failing over from mirror node at `{}` to `{}`",
+                            mirrornet.address(index),
+                            mirrornet.address(next)
+                        );
+                    }
+
+                    index = next;
                 }
 
                 code if request.should_retry(code) => {
@@ -274,3 +370,85 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
         }
     }
 }
+
+/// Returns `true` if `status` indicates a problem with the mirror node itself, rather than the request.
+fn is_mirror_node_failure(status: &Status) -> bool {
+    match status.code() {
+        tonic::Code::Unavailable | tonic::Code::ResourceExhausted => true,
+        tonic::Code::Unknown => {
+            status.message() == "error reading a body from connection: connection reset"
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use assert_matches::assert_matches;
+    use futures_util::StreamExt;
+    use tonic::Status;
+
+    use super::is_mirror_node_failure;
+    use crate::{
+        AccountId,
+        Client,
+        Error,
+        TopicId,
+        TopicMessageQuery,
+    };
+
+    fn client() -> Client {
+        let client = Client::for_network(HashMap::from([(
+            "127.0.0.1:50211".to_owned(),
+            AccountId::new(0, 0, 3),
+        )]))
+        .unwrap();
+
+        // nothing listens here, so subscriptions keep retrying until they're closed.
+        client.set_mirror_network(["127.0.0.1:1".to_owned()]);
+
+        client
+    }
+
+    fn query() -> TopicMessageQuery {
+        TopicMessageQuery::new().topic_id(TopicId::new(0, 0, 1001)).clone()
+    }
+
+    #[tokio::test]
+    async fn close_with_subscriptions_held() {
+        let client = client();
+
+        // one subscription that's never polled, and one that's waiting for the mirror node.
+        let query = query();
+        let mut idle = query.subscribe(&client);
+
+        let polled = tokio::spawn({
+            let client = client.clone();
+            async move { query().subscribe(&client).next().await }
+        });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        tokio::time::timeout(Duration::from_secs(5), client.close())
+            .await
+            .expect("`close` shouldn't wait for subscriptions to be dropped");
+
+        assert_matches!(polled.await.unwrap(), Some(Err(Error::ClientClosed)));
+        assert_matches!(idle.next().await, Some(Err(Error::ClientClosed)));
+    }
+
+    #[test]
+    fn mirror_node_failures() {
+        assert!(is_mirror_node_failure(&Status::unavailable("down")));
+        assert!(is_mirror_node_failure(&Status::resource_exhausted("busy")));
+        assert!(is_mirror_node_failure(&Status::unknown(
+            "error reading a body from connection: connection reset"
+        )));
+
+        assert!(!is_mirror_node_failure(&Status::unknown("something else")));
+        assert!(!is_mirror_node_failure(&Status::not_found("topic")));
+    }
+}
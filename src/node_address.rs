@@ -1,6 +1,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::SocketAddrV4;
+use std::fmt;
+use std::net::{
+    IpAddr,
+    SocketAddr,
+    SocketAddrV4,
+    SocketAddrV6,
+};
+use std::str::FromStr;
 
 use hedera_proto::services;
 
@@ -11,24 +18,120 @@ use crate::{
     FromProtobuf,
 };
 
-fn parse_socket_addr_v4(ip: Vec<u8>, port: i32) -> crate::Result<SocketAddrV4> {
-    let octets: Result<[u8; 4], _> = ip.try_into();
-    let octets = octets.map_err(|v| {
-        Error::from_protobuf(format!("expected 4 byte ip address, got `{}` bytes", v.len()))
-    })?;
-
-    let port = u16::try_from(port).map_err(|_| {
+fn parse_port(port: i32) -> crate::Result<u16> {
+    u16::try_from(port).map_err(|_| {
         Error::from_protobuf(format!(
             "expected 16 bit non-negative port number, but the port was actually `{port}`",
         ))
-    })?;
+    })
+}
+
+fn parse_endpoint(ip: Vec<u8>, domain_name: String, port: i32) -> crate::Result<NodeEndpoint> {
+    let port = parse_port(port)?;
+
+    let ip = if let Ok(octets) = <[u8; 4]>::try_from(ip.as_slice()) {
+        IpAddr::from(octets)
+    } else if let Ok(octets) = <[u8; 16]>::try_from(ip.as_slice()) {
+        IpAddr::from(octets)
+    } else if ip.is_empty() && !domain_name.is_empty() {
+        return Ok(NodeEndpoint::Domain { name: domain_name, port });
+    } else {
+        return Err(Error::from_protobuf(format!(
+            "expected 4 or 16 byte ip address, got `{}` bytes",
+            ip.len()
+        )));
+    };
+
+    Ok(NodeEndpoint::Ip(SocketAddr::new(ip, port)))
+}
+
+/// An address a node serves requests on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NodeEndpoint {
+    /// An IPv4 or IPv6 address.
+    Ip(SocketAddr),
+
+    /// A fully qualified domain name, which gets resolved whenever a connection is made.
+    Domain {
+        /// The domain name of the node.
+        name: String,
+
+        /// The port of the endpoint.
+        port: u16,
+    },
+}
+
+impl NodeEndpoint {
+    /// Returns the port of this endpoint.
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        match self {
+            Self::Ip(it) => it.port(),
+            Self::Domain { port, .. } => *port,
+        }
+    }
+
+    /// Returns the host of this endpoint, without any brackets around IPv6 addresses.
+    #[must_use]
+    pub fn host(&self) -> String {
+        match self {
+            Self::Ip(it) => it.ip().to_string(),
+            Self::Domain { name, .. } => name.clone(),
+        }
+    }
+}
+
+impl From<SocketAddr> for NodeEndpoint {
+    fn from(value: SocketAddr) -> Self {
+        Self::Ip(value)
+    }
+}
+
+impl From<SocketAddrV4> for NodeEndpoint {
+    fn from(value: SocketAddrV4) -> Self {
+        Self::Ip(value.into())
+    }
+}
+
+impl From<SocketAddrV6> for NodeEndpoint {
+    fn from(value: SocketAddrV6) -> Self {
+        Self::Ip(value.into())
+    }
+}
 
-    Ok(SocketAddrV4::new(octets.into(), port))
+impl fmt::Display for NodeEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ip(it) => it.fmt(f),
+            Self::Domain { name, port } => write!(f, "{name}:{port}"),
+        }
+    }
+}
+
+impl FromStr for NodeEndpoint {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(it) = s.parse::<SocketAddr>() {
+            return Ok(Self::Ip(it));
+        }
+
+        let (name, port) = s
+            .rsplit_once(':')
+            .ok_or_else(|| Error::basic_parse(format!("expected `<host>:<port>`, got `{s}`")))?;
+
+        if name.is_empty() || name.contains(':') {
+            return Err(Error::basic_parse(format!("invalid host in endpoint `{s}`")));
+        }
+
+        Ok(Self::Domain { name: name.to_owned(), port: port.parse().map_err(Error::basic_parse)? })
+    }
 }
 
 /// The data about a node, including its service endpoints and the Hiero account to be paid for
 /// services provided by the node (that is, queries answered and transactions submitted.).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NodeAddress {
     /// A non-sequential, unique, static identifier for the node
     pub node_id: u64,
@@ -48,8 +151,8 @@ pub struct NodeAddress {
     /// Its value can be used to verify the node's certificate it presents during TLS negotiations.
     pub tls_certificate_hash: Vec<u8>,
 
-    /// A node's service IP addresses and ports.
-    pub service_endpoints: Vec<SocketAddrV4>,
+    /// A node's service addresses (IP addresses or domain names) and ports.
+    pub service_endpoints: Vec<NodeEndpoint>,
 
     /// A description of the node, up to 100 bytes.
     pub description: String,
@@ -66,11 +169,15 @@ impl FromProtobuf<services::NodeAddress> for NodeAddress {
         // `ip_address`/`portno` are deprecated, but lets handle them anyway.
         #[allow(deprecated)]
         if !pb.ip_address.is_empty() {
-            addresses.push(parse_socket_addr_v4(pb.ip_address, pb.portno)?);
+            addresses.push(parse_endpoint(pb.ip_address, String::new(), pb.portno)?);
         }
 
         for address in pb.service_endpoint {
-            addresses.push(parse_socket_addr_v4(address.ip_address_v4, address.port)?);
+            addresses.push(parse_endpoint(
+                address.ip_address_v4,
+                address.domain_name,
+                address.port,
+            )?);
         }
 
         let node_account_id = AccountId::from_protobuf(pb_getf!(pb, node_account_id)?)?;
@@ -93,10 +200,20 @@ impl ToProtobuf for NodeAddress {
         let service_endpoint = self
             .service_endpoints
             .iter()
-            .map(|it| services::ServiceEndpoint {
-                ip_address_v4: it.ip().octets().to_vec(),
-                port: i32::from(it.port()),
-                domain_name: it.to_string(),
+            .map(|it| match it {
+                NodeEndpoint::Ip(addr) => services::ServiceEndpoint {
+                    ip_address_v4: match addr.ip() {
+                        IpAddr::V4(ip) => ip.octets().to_vec(),
+                        IpAddr::V6(ip) => ip.octets().to_vec(),
+                    },
+                    port: i32::from(addr.port()),
+                    domain_name: String::new(),
+                },
+                NodeEndpoint::Domain { name, port } => services::ServiceEndpoint {
+                    ip_address_v4: Vec::new(),
+                    port: i32::from(*port),
+                    domain_name: name.clone(),
+                },
             })
             .collect();
 
@@ -113,3 +230,73 @@ impl ToProtobuf for NodeAddress {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+
+    use super::NodeEndpoint;
+    use crate::protobuf::ToProtobuf;
+    use crate::{
+        AccountId,
+        FromProtobuf,
+        NodeAddress,
+    };
+
+    #[test]
+    fn parse_endpoints() {
+        assert_eq!(
+            "127.0.0.1:50211".parse::<NodeEndpoint>().unwrap(),
+            NodeEndpoint::Ip(([127, 0, 0, 1], 50211).into())
+        );
+
+        let ipv6 = "[2001:db8::1]:50211".parse::<NodeEndpoint>().unwrap();
+        assert_eq!(ipv6.host(), "2001:db8::1");
+        assert_eq!(ipv6.to_string(), "[2001:db8::1]:50211");
+
+        assert_eq!(
+            "node0.example.com:50211".parse::<NodeEndpoint>().unwrap(),
+            NodeEndpoint::Domain { name: "node0.example.com".to_owned(), port: 50211 }
+        );
+
+        assert!("2001:db8::1:50211".parse::<NodeEndpoint>().is_err());
+        assert!("node0.example.com".parse::<NodeEndpoint>().is_err());
+    }
+
+    #[test]
+    fn from_protobuf_keeps_every_kind_of_endpoint() {
+        let address = NodeAddress {
+            node_id: 0,
+            rsa_public_key: Vec::new(),
+            node_account_id: AccountId::new(0, 0, 3),
+            tls_certificate_hash: Vec::new(),
+            service_endpoints: vec![
+                "127.0.0.1:50211".parse().unwrap(),
+                "[2001:db8::1]:50211".parse().unwrap(),
+                "node0.example.com:50211".parse().unwrap(),
+            ],
+            description: String::new(),
+        };
+
+        let pb = address.to_protobuf();
+
+        assert_eq!(pb.service_endpoint[1].ip_address_v4.len(), 16);
+        assert_eq!(pb.service_endpoint[2].domain_name, "node0.example.com");
+        assert_eq!(NodeAddress::from_protobuf(pb).unwrap(), address);
+    }
+
+    #[test]
+    fn from_protobuf_rejects_bad_ip() {
+        let pb = services::NodeAddress {
+            node_account_id: Some(AccountId::new(0, 0, 3).to_protobuf()),
+            service_endpoint: vec![services::ServiceEndpoint {
+                ip_address_v4: vec![127, 0, 0],
+                port: 50211,
+                domain_name: String::new(),
+            }],
+            ..Default::default()
+        };
+
+        assert!(NodeAddress::from_protobuf(pb).is_err());
+    }
+}
@@ -1,5 +1,9 @@
+use std::io;
+use std::path::Path;
+
 use hedera_proto::services;
 
+use crate::error::BoxStdError;
 use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
@@ -9,7 +13,28 @@ use crate::NodeAddress;
 /// A list of nodes and their metadata.
 ///
 /// Response from [`NodeAddressBookQuery`](crate::NodeAddressBookQuery)
-#[derive(Clone, Debug)]
+///
+/// Address books can be cached on disk (see [`write_to_file`](Self::write_to_file)),
+/// so that a client can start with the last known network rather than a built-in one:
+///
+/// ```no_run
+/// use hedera::{Client, NodeAddressBook};
+///
+/// const CACHE: &str = "address-book.pb";
+///
+/// let client = Client::for_mainnet();
+///
+/// if let Ok(address_book) = NodeAddressBook::read_from_file(CACHE) {
+///     client.set_network_from_address_book(address_book);
+/// }
+///
+/// client.set_address_book_callback(|address_book: &NodeAddressBook| {
+///     if let Err(e) = address_book.write_to_file(CACHE) {
+///         eprintln!("failed to cache the address book: {e}");
+///     }
+/// });
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct NodeAddressBook {
     /// all the nodes this address book contains.
     pub node_addresses: Vec<NodeAddress>,
@@ -30,6 +55,82 @@ impl NodeAddressBook {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Create a new `NodeAddressBook` from JSON, as produced by [`to_json`](Self::to_json).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if the JSON isn't a valid address book.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        serde_json::from_str::<json::NodeAddressBook>(json)
+            .map_err(crate::Error::basic_parse)?
+            .try_into()
+    }
+
+    /// Convert `self` to JSON.
+    ///
+    /// Binary fields (the RSA public key and the TLS certificate hash) are hex encoded.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        // serializing plain structs of strings and numbers can't fail.
+        serde_json::to_string_pretty(&json::NodeAddressBook::from(self)).unwrap()
+    }
+
+    /// Read an address book from the file at `path`, as written by [`write_to_file`](Self::write_to_file).
+    ///
+    /// Files with a `.json` extension are read as JSON (with the `serde` feature), anything else is read as protobuf.
+    ///
+    /// # Errors
+    /// - If reading the file fails.
+    /// - [`io::ErrorKind::InvalidData`] if the file doesn't contain a valid address book.
+    pub fn read_from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)?;
+
+        let invalid_data = |e: BoxStdError| io::Error::new(io::ErrorKind::InvalidData, e);
+
+        #[cfg(feature = "serde")]
+        if is_json(path) {
+            let json = std::str::from_utf8(&bytes).map_err(|e| invalid_data(e.into()))?;
+
+            return Self::from_json(json).map_err(|e| invalid_data(e.into()));
+        }
+
+        Self::from_bytes(&bytes).map_err(|e| invalid_data(e.into()))
+    }
+
+    /// Write this address book to the file at `path`, replacing it if it exists.
+    ///
+    /// Files with a `.json` extension are written as JSON (with the `serde` feature), anything else is written as protobuf.
+    ///
+    /// # Errors
+    /// - If writing the file fails.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+
+        #[cfg(feature = "serde")]
+        let bytes = match is_json(path) {
+            true => self.to_json().into_bytes(),
+            false => self.to_bytes(),
+        };
+
+        #[cfg(not(feature = "serde"))]
+        let bytes = self.to_bytes();
+
+        // the file is written next to its destination and then moved into place,
+        // so that a crash halfway through doesn't leave a truncated address book behind.
+        let mut temporary = path.as_os_str().to_owned();
+        temporary.push(".tmp");
+
+        std::fs::write(&temporary, bytes)?;
+        std::fs::rename(&temporary, path)
+    }
+}
+
+#[cfg(feature = "serde")]
+fn is_json(path: &Path) -> bool {
+    path.extension().is_some_and(|it| it.eq_ignore_ascii_case("json"))
 }
 
 impl FromProtobuf<services::NodeAddressBook> for NodeAddressBook {
@@ -45,3 +146,194 @@ impl ToProtobuf for NodeAddressBook {
         services::NodeAddressBook { node_address: self.node_addresses.to_protobuf() }
     }
 }
+
+#[cfg(feature = "serde")]
+mod json {
+    use crate::{
+        AccountId,
+        Error,
+        NodeEndpoint,
+    };
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct NodeAddressBook {
+        node_addresses: Vec<NodeAddress>,
+    }
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct NodeAddress {
+        node_id: u64,
+        node_account_id: String,
+        rsa_public_key: String,
+        tls_certificate_hash: String,
+        service_endpoints: Vec<String>,
+        #[serde(default)]
+        description: String,
+    }
+
+    impl From<&super::NodeAddressBook> for NodeAddressBook {
+        fn from(value: &super::NodeAddressBook) -> Self {
+            Self {
+                node_addresses: value
+                    .node_addresses
+                    .iter()
+                    .map(|it| NodeAddress {
+                        node_id: it.node_id,
+                        node_account_id: it.node_account_id.to_string(),
+                        rsa_public_key: hex::encode(&it.rsa_public_key),
+                        tls_certificate_hash: hex::encode(&it.tls_certificate_hash),
+                        service_endpoints: it
+                            .service_endpoints
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect(),
+                        description: it.description.clone(),
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    impl TryFrom<NodeAddressBook> for super::NodeAddressBook {
+        type Error = Error;
+
+        fn try_from(value: NodeAddressBook) -> crate::Result<Self> {
+            let node_addresses = value
+                .node_addresses
+                .into_iter()
+                .map(|it| {
+                    Ok(crate::NodeAddress {
+                        node_id: it.node_id,
+                        node_account_id: it.node_account_id.parse::<AccountId>()?,
+                        rsa_public_key: hex::decode(it.rsa_public_key)
+                            .map_err(Error::basic_parse)?,
+                        tls_certificate_hash: hex::decode(it.tls_certificate_hash)
+                            .map_err(Error::basic_parse)?,
+                        service_endpoints: it
+                            .service_endpoints
+                            .iter()
+                            .map(|it| it.parse::<NodeEndpoint>())
+                            .collect::<crate::Result<_>>()?,
+                        description: it.description,
+                    })
+                })
+                .collect::<crate::Result<_>>()?;
+
+            Ok(Self { node_addresses })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddrV4;
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+
+    use triomphe::Arc;
+
+    use super::NodeAddressBook;
+    use crate::{
+        AccountId,
+        Client,
+        NodeAddress,
+    };
+
+    fn address_book() -> NodeAddressBook {
+        NodeAddressBook {
+            node_addresses: vec![NodeAddress {
+                node_id: 0,
+                rsa_public_key: vec![0x30, 0x82, 0x01, 0xa2],
+                node_account_id: AccountId::new(0, 0, 3),
+                tls_certificate_hash: b"4fc2c4d3".to_vec(),
+                service_endpoints: vec![
+                    SocketAddrV4::new([127, 0, 0, 1].into(), 50211).into(),
+                    "node0.example.com:50211".parse().unwrap(),
+                ],
+                description: "node 0".to_owned(),
+            }],
+        }
+    }
+
+    #[test]
+    fn file_round_trip() {
+        // a directory of our own, so concurrent test runs don't trip over each other's files.
+        let directory = std::env::temp_dir().join(format!(
+            "hedera-address-book-test-{}-{:016x}",
+            std::process::id(),
+            rand::random::<u64>()
+        ));
+
+        std::fs::create_dir(&directory).unwrap();
+
+        let read: Vec<_> = ["address-book.pb", "address-book.json"]
+            .into_iter()
+            .map(|name| {
+                let path = directory.join(name);
+
+                address_book().write_to_file(&path).unwrap();
+                NodeAddressBook::read_from_file(&path)
+            })
+            .collect();
+
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        for read in read {
+            assert_eq!(read.unwrap(), address_book());
+        }
+    }
+
+    #[test]
+    fn client_from_address_book() {
+        let client = Client::for_address_book(&address_book());
+
+        assert_eq!(client.network()["127.0.0.1:50211"], AccountId::new(0, 0, 3));
+        assert_eq!(client.network()["node0.example.com:50211"], AccountId::new(0, 0, 3));
+
+        let updates = Arc::new(AtomicUsize::new(0));
+
+        client.set_address_book_callback({
+            let updates = Arc::clone(&updates);
+            move |_: &NodeAddressBook| {
+                updates.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+
+        client.set_network_from_address_book(address_book());
+
+        assert_eq!(updates.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn address_book_callback_clears_itself() {
+        let client = Client::for_address_book(&address_book());
+
+        let updates = Arc::new(AtomicUsize::new(0));
+
+        client.set_address_book_callback({
+            let client = client.clone();
+            let updates = Arc::clone(&updates);
+            move |_: &NodeAddressBook| {
+                updates.fetch_add(1, Ordering::Relaxed);
+                client.clear_address_book_callback();
+            }
+        });
+
+        client.set_network_from_address_book(address_book());
+        client.set_network_from_address_book(address_book());
+
+        assert_eq!(updates.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn malformed_json() {
+        let error = NodeAddressBook::from_json(r#"{"nodeAddresses": [{"nodeId": 0}]}"#);
+
+        assert!(error.is_err());
+    }
+}
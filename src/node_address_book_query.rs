@@ -16,7 +16,9 @@ use hedera_proto::{
 use mirror::network_service_client::NetworkServiceClient;
 use tonic::transport::Channel;
 use tonic::Response;
+use triomphe::Arc;
 
+use crate::client::MirrorNetworkData;
 use crate::mirror_query::{
     AnyMirrorQueryData,
     AnyMirrorQueryMessage,
@@ -162,7 +164,7 @@ impl From<NodeAddressBook> for AnyMirrorQueryResponse {
 impl NodeAddressBookQuery {
     pub(crate) async fn execute_mirrornet(
         &self,
-        channel: Channel,
+        mirrornet: Arc<MirrorNetworkData>,
         timeout: Option<Duration>,
     ) -> crate::Result<NodeAddressBook> {
         let timeout = timeout.unwrap_or_else(|| {
@@ -170,7 +172,7 @@ impl NodeAddressBookQuery {
         });
 
         NodeAddressBookQueryData::try_collect(crate::mirror_query::subscribe(
-            channel,
+            mirrornet,
             timeout,
             self.data.clone(),
         ))
@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use hedera_proto::services::crypto_service_client::CryptoServiceClient;
 
@@ -11,8 +12,10 @@ use crate::execute::{
 use crate::protobuf::ToProtobuf;
 use crate::query::response_header;
 use crate::{
+    client,
     AccountId,
     Client,
+    RequestType,
 };
 
 /// Internal "query" to ping a specific node.
@@ -70,15 +73,19 @@ impl Execute for PingQuery {
         None
     }
 
+    fn request_type(request: &Self::GrpcRequest) -> Option<RequestType> {
+        client::query_request_type(request)
+    }
+
     fn requires_transaction_id(&self) -> bool {
         false
     }
 
-    fn make_request(
-        &self,
-        _transaction_id: Option<&crate::TransactionId>,
+    fn make_request<'a>(
+        &'a self,
+        _transaction_id: Option<&'a crate::TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         const HEADER: services::QueryHeader = services::QueryHeader {
             payment: None,
             response_type: services::ResponseType::AnswerOnly as i32,
@@ -99,7 +106,7 @@ impl Execute for PingQuery {
             )),
         };
 
-        Ok((query, ()))
+        Box::pin(std::future::ready(Ok((query, ()))))
     }
 
     fn execute(
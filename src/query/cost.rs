@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
 
@@ -10,12 +11,15 @@ use crate::execute::{
 };
 use crate::query::execute::response_header;
 use crate::query::QueryExecute;
+use crate::request_observer::AnyRequestObserver;
 use crate::{
+    client,
     AccountId,
     BoxGrpcFuture,
     Client,
     Hbar,
     Query,
+    RequestType,
     Tinybar,
     TransactionId,
 };
@@ -51,6 +55,14 @@ where
         Execute::node_account_ids(self.0)
     }
 
+    fn observer(&self) -> Option<&AnyRequestObserver> {
+        Execute::observer(self.0)
+    }
+
+    fn request_type(request: &Self::GrpcRequest) -> Option<RequestType> {
+        client::query_request_type(request)
+    }
+
     fn transaction_id(&self) -> Option<TransactionId> {
         None
     }
@@ -63,17 +75,17 @@ where
         None
     }
 
-    fn make_request(
-        &self,
-        _transaction_id: Option<&TransactionId>,
+    fn make_request<'a>(
+        &'a self,
+        _transaction_id: Option<&'a TransactionId>,
         _node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         let header = services::QueryHeader {
             response_type: services::ResponseType::CostAnswer as i32,
             payment: None,
         };
 
-        Ok((self.0.data.to_query_protobuf(header), ()))
+        Box::pin(std::future::ready(Ok((self.0.data.to_query_protobuf(header), ()))))
     }
 
     fn execute(
@@ -2,6 +2,7 @@
 
 use std::fmt::Debug;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
 
@@ -11,13 +12,16 @@ use crate::query::{
     AnyQueryData,
     ToQueryProtobuf,
 };
+use crate::request_observer::AnyRequestObserver;
 use crate::{
+    client,
     AccountId,
     BoxGrpcFuture,
     Error,
     FromProtobuf,
     Hbar,
     Query,
+    RequestType,
     Status,
     TransactionId,
 };
@@ -98,6 +102,14 @@ where
         self.payment.operator_account_id()
     }
 
+    fn observer(&self) -> Option<&AnyRequestObserver> {
+        self.payment.observer()
+    }
+
+    fn request_type(request: &Self::GrpcRequest) -> Option<RequestType> {
+        client::query_request_type(request)
+    }
+
     fn should_retry_pre_check(&self, status: Status) -> bool {
         self.data.should_retry_pre_check(status)
     }
@@ -106,20 +118,22 @@ where
         self.data.should_retry(response)
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
-        let payment = if self.data.is_payment_required() {
-            Some(self.payment.make_request(transaction_id, node_account_id)?.0)
-        } else {
-            None
-        };
-
-        let header = services::QueryHeader { response_type: 0, payment };
-
-        Ok((self.data.to_query_protobuf(header), ()))
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
+        Box::pin(async move {
+            let payment = if self.data.is_payment_required() {
+                Some(self.payment.make_request(transaction_id, node_account_id).await?.0)
+            } else {
+                None
+            };
+
+            let header = services::QueryHeader { response_type: 0, payment };
+
+            Ok((self.data.to_query_protobuf(header), ()))
+        })
     }
 
     fn execute(
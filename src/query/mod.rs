@@ -11,6 +11,7 @@ use crate::{
     Client,
     Error,
     Hbar,
+    RequestObserver,
     TransactionId,
     TransactionReceiptQuery,
 };
@@ -177,6 +178,14 @@ where
         self
     }
 
+    /// Sets an observer that's called for every attempt at executing this query (including fetching its cost).
+    ///
+    /// This is in addition to the observer set on the client (if any), see [`RequestObserver`] for details.
+    pub fn request_observer<O: RequestObserver>(&mut self, observer: O) -> &mut Self {
+        self.payment.request_observer(observer);
+        self
+    }
+
     /// Fetch the cost of this query.
     pub async fn get_cost(&self, client: &Client) -> crate::Result<Hbar> {
         self.get_cost_with_optional_timeout(client, None).await
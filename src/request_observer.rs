@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+use std::time::Duration;
+
+use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
+
+use crate::{
+    AccountId,
+    Status,
+    TransactionId,
+};
+
+/// Observes every attempt made to execute a request (a transaction or a query) against a node.
+///
+/// Observers can be registered on the [`Client`](crate::Client), which sees every request it executes,
+/// or on a single request, with `request_observer` on [`Transaction`](crate::Transaction::request_observer) or [`Query`](crate::Query::request_observer).
+/// When both are registered, both are called.
+///
+/// Observers are called inline by the execution loop, so they should be quick (IE, not block on I/O).
+///
+/// Any `Fn(&RequestAttempt)` is an observer.
+///
+/// # Examples
+/// ```
+/// use hedera::{Client, RequestAttempt};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let client = Client::for_testnet();
+///
+/// client.set_request_observer(|attempt: &RequestAttempt| {
+///     println!(
+///         "node {} answered with {:?} / {:?}",
+///         attempt.node_account_id, attempt.grpc_status, attempt.pre_check_status
+///     );
+/// });
+/// # }
+/// ```
+pub trait RequestObserver: Send + Sync + 'static {
+    /// Called once the outcome of `attempt` is known.
+    fn on_attempt(&self, attempt: &RequestAttempt);
+}
+
+impl<F> RequestObserver for F
+where
+    F: Fn(&RequestAttempt) + Send + Sync + 'static,
+{
+    fn on_attempt(&self, attempt: &RequestAttempt) {
+        self(attempt);
+    }
+}
+
+/// A single attempt at executing a request against a node.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RequestAttempt {
+    /// The account ID of the node that the request was sent to.
+    pub node_account_id: AccountId,
+
+    /// The transaction ID of the request, for queries this is the transaction ID of the payment (if any).
+    pub transaction_id: Option<TransactionId>,
+
+    /// The protobuf encoded request, a `Transaction` for transactions and a `Query` for queries.
+    pub request: Vec<u8>,
+
+    /// The gRPC status the request completed with.
+    ///
+    /// This is [`tonic::Code::Ok`] whenever the node answered, even if the answer was a failing pre-check status.
+    pub grpc_status: tonic::Code,
+
+    /// The pre-check status the node answered with, `None` if the node didn't answer.
+    pub pre_check_status: Option<Status>,
+
+    /// How long the SDK waited before making its next attempt, zero if the next attempt was made immediately (or there wasn't one).
+    pub backoff: Duration,
+
+    /// Whether the node was marked as unhealthy because of this attempt.
+    ///
+    /// Unhealthy nodes aren't used again until their own backoff elapses.
+    pub node_marked_unhealthy: bool,
+}
+
+#[derive(Clone)]
+pub(crate) struct AnyRequestObserver(Arc<dyn RequestObserver>);
+
+impl AnyRequestObserver {
+    pub(crate) fn new<O: RequestObserver>(observer: O) -> Self {
+        Self(Arc::new(observer).unsize(Coercion!(to dyn RequestObserver)))
+    }
+
+    pub(crate) fn on_attempt(&self, attempt: &RequestAttempt) {
+        self.0.on_attempt(attempt);
+    }
+}
+
+impl fmt::Debug for AnyRequestObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AnyRequestObserver").field(&"..").finish()
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use std::time::Duration;
+
+    use parking_lot::Mutex;
+    use triomphe::Arc;
+
+    use super::RequestAttempt;
+    use crate::testing::{
+        MockNetwork,
+        MockResponse,
+    };
+    use crate::{
+        AccountId,
+        Hbar,
+        Status,
+        TransferTransaction,
+    };
+
+    #[tokio::test]
+    async fn busy_then_ok() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let client = network.client();
+        client.set_min_backoff(Duration::from_millis(10));
+        client.set_max_backoff(Duration::from_millis(100));
+
+        let client_attempts = Arc::new(Mutex::new(Vec::new()));
+        let transaction_attempts = Arc::new(Mutex::new(Vec::new()));
+
+        client.set_request_observer({
+            let attempts = Arc::clone(&client_attempts);
+            move |it: &RequestAttempt| attempts.lock().push(it.clone())
+        });
+
+        network.nodes()[0].respond(MockResponse::busy());
+
+        let response = TransferTransaction::new()
+            .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 3), Hbar::new(1))
+            .request_observer({
+                let attempts = Arc::clone(&transaction_attempts);
+                move |it: &RequestAttempt| attempts.lock().push(it.clone())
+            })
+            .execute(&client)
+            .await
+            .unwrap();
+
+        let attempts = client_attempts.lock().clone();
+
+        assert_eq!(attempts.len(), 2);
+        assert_eq!(transaction_attempts.lock().len(), 2);
+
+        // with only one node, the SDK has to back off before trying again.
+        assert_eq!(attempts[0].pre_check_status, Some(Status::Busy));
+        assert!(attempts[0].backoff > Duration::ZERO);
+        assert!(!attempts[0].node_marked_unhealthy);
+
+        assert_eq!(attempts[1].grpc_status, tonic::Code::Ok);
+        assert_eq!(attempts[1].pre_check_status, Some(Status::Ok));
+        assert_eq!(attempts[1].backoff, Duration::ZERO);
+        assert_eq!(attempts[1].transaction_id, Some(response.transaction_id));
+        assert_eq!(attempts[1].node_account_id, network.nodes()[0].account_id());
+        assert!(!attempts[1].request.is_empty());
+    }
+}
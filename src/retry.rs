@@ -1,5 +1,9 @@
+use std::time::Duration;
+
 use futures_core::Future;
 use tokio::time::sleep;
+#[cfg(feature = "tracing")]
+use tracing::Instrument;
 
 #[derive(Debug)]
 pub(crate) enum Error {
@@ -26,15 +30,19 @@ impl Error {
 pub(crate) type Result<T> = std::result::Result<T, Error>;
 
 /// Durably retry some function according to the `backoff` until the backoff expires.
-pub(crate) async fn retry<B, Fn, O, Fut>(
+///
+/// `on_backoff` is called with the delay right before every backoff.
+pub(crate) async fn retry<B, Fn, O, Fut, OnBackoff>(
     mut backoff: B,
     max_attempts: Option<usize>,
     mut f: Fn,
+    mut on_backoff: OnBackoff,
 ) -> crate::Result<O>
 where
     B: backoff::backoff::Backoff + Send,
     Fn: FnMut() -> Fut + Send,
     Fut: Future<Output = Result<O>> + Send,
+    OnBackoff: FnMut(Duration) + Send,
 {
     let mut last_error: Option<crate::Error> = None;
     let mut attempt_number = 0;
@@ -50,12 +58,34 @@ where
         }
 
         if let Some(duration) = backoff.next_backoff() {
+            on_backoff(duration);
+
             let duration_ms = duration.as_millis();
             let err_suffix =
                 last_error.as_ref().map(|l| format!(" due to {l:?}")).unwrap_or_default();
 
             log::warn!("Backing off for {duration_ms}ms after failure of attempt {attempt_number}{err_suffix}");
-            sleep(duration).await;
+
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                attempt = attempt_number,
+                delay_ms = duration_ms,
+                error = last_error.as_ref().map(tracing::field::debug),
+                "backing off"
+            );
+
+            let backoff_sleep = sleep(duration);
+
+            #[cfg(feature = "tracing")]
+            let backoff_sleep = backoff_sleep.instrument(tracing::info_span!(
+                "backoff",
+                attempt = attempt_number,
+                delay_ms = duration_ms,
+                error = last_error.as_ref().map(tracing::field::debug),
+            ));
+
+            backoff_sleep.await;
+
             log::warn!("Backed off for {duration_ms}ms after failure of attempt {attempt_number}{err_suffix}");
         } else {
             let last_error = last_error.expect("timeout while network had no healthy nodes");
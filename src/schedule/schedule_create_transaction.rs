@@ -23,6 +23,7 @@ use crate::{
     BoxGrpcFuture,
     Error,
     Key,
+    RequiredSigner,
     Transaction,
     ValidateChecksums,
 };
@@ -147,7 +148,11 @@ impl ScheduleCreateTransaction {
     }
 }
 
-impl TransactionData for ScheduleCreateTransactionData {}
+impl TransactionData for ScheduleCreateTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.admin_key.clone().map(RequiredSigner::Key).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for ScheduleCreateTransactionData {
     fn execute(
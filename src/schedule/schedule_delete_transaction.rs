@@ -20,6 +20,7 @@ use crate::transaction::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     ScheduleId,
     Transaction,
     ValidateChecksums,
@@ -48,7 +49,11 @@ impl ScheduleDeleteTransaction {
         self
     }
 }
-impl TransactionData for ScheduleDeleteTransactionData {}
+impl TransactionData for ScheduleDeleteTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.schedule_id.map(RequiredSigner::Schedule).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for ScheduleDeleteTransactionData {
     fn execute(
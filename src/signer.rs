@@ -1,7 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::error::Error as StdError;
 use std::fmt;
+use std::future::Future;
 
+use futures_core::future::BoxFuture;
+use futures_util::FutureExt;
 use triomphe::Arc;
 use unsize::{
     CoerceUnsize,
@@ -9,10 +13,97 @@ use unsize::{
 };
 
 use crate::{
+    Error,
     PrivateKey,
     PublicKey,
 };
 
+/// Something that can sign messages (namely transaction bodies) on behalf of a [`PublicKey`].
+///
+/// Unlike the closures accepted by functions such as [`Transaction::sign_with`](crate::Transaction::sign_with),
+/// signing is asynchronous, which allows keys to live in an HSM, a KMS, or behind any other remote signing service.
+///
+/// # Examples
+/// ```
+/// use futures_core::future::BoxFuture;
+/// use hedera::{PrivateKey, PublicKey, Signer};
+///
+/// struct RemoteSigner {
+///     // a real implementation would hold a handle to the remote service instead.
+///     key: PrivateKey,
+/// }
+///
+/// impl Signer for RemoteSigner {
+///     fn public_key(&self) -> PublicKey {
+///         self.key.public_key()
+///     }
+///
+///     fn sign<'a>(
+///         &'a self,
+///         message: &'a [u8],
+///     ) -> BoxFuture<'a, Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>>> {
+///         Box::pin(async move { Ok(self.key.sign(message)) })
+///     }
+/// }
+/// ```
+pub trait Signer: Send + Sync + 'static {
+    /// Returns the public key that signatures from this signer can be verified with.
+    ///
+    /// This is called often, so it should be cheap (IE, not a network request).
+    fn public_key(&self) -> PublicKey;
+
+    /// Sign `message`, returning the raw signature bytes.
+    ///
+    /// # Errors
+    /// Any error returned here is surfaced as [`Error::Signer`].
+    fn sign<'a>(
+        &'a self,
+        message: &'a [u8],
+    ) -> BoxFuture<'a, Result<Vec<u8>, Box<dyn StdError + Send + Sync>>>;
+
+    /// Returns the key itself when this signer is a [`PrivateKey`].
+    ///
+    /// Used by the SDK to sign with such a key synchronously, and to write it to a client config.
+    #[doc(hidden)]
+    fn as_private_key(&self) -> Option<&PrivateKey> {
+        None
+    }
+}
+
+impl<S: Signer + ?Sized> Signer for Box<S> {
+    fn public_key(&self) -> PublicKey {
+        (**self).public_key()
+    }
+
+    fn sign<'a>(
+        &'a self,
+        message: &'a [u8],
+    ) -> BoxFuture<'a, Result<Vec<u8>, Box<dyn StdError + Send + Sync>>> {
+        (**self).sign(message)
+    }
+
+    fn as_private_key(&self) -> Option<&PrivateKey> {
+        (**self).as_private_key()
+    }
+}
+
+impl Signer for PrivateKey {
+    fn public_key(&self) -> PublicKey {
+        PrivateKey::public_key(self)
+    }
+
+    fn sign<'a>(
+        &'a self,
+        message: &'a [u8],
+    ) -> BoxFuture<'a, Result<Vec<u8>, Box<dyn StdError + Send + Sync>>> {
+        Box::pin(std::future::ready(Ok(PrivateKey::sign(self, message))))
+    }
+
+    fn as_private_key(&self) -> Option<&PrivateKey> {
+        Some(self)
+    }
+}
+
 #[derive(Clone)]
 pub(crate) enum AnySigner {
     PrivateKey(PrivateKey),
@@ -28,6 +119,7 @@ pub(crate) enum AnySigner {
     // but we can't do that because trait aliases don't exist.
     #[allow(clippy::type_complexity)]
     Arbitrary(Box<PublicKey>, Arc<dyn Fn(&[u8]) -> Vec<u8> + Send + Sync>),
+    Signer(Arc<dyn Signer>),
 }
 
 impl AnySigner {
@@ -40,6 +132,14 @@ impl AnySigner {
             Arc::new(signer).unsize(Coercion!(to dyn Fn(&[u8]) -> Vec<u8> + Send + Sync)),
         )
     }
+
+    pub(crate) fn signer<S: Signer>(signer: S) -> Self {
+        if let Some(key) = signer.as_private_key() {
+            return Self::PrivateKey(key.clone());
+        }
+
+        Self::Signer(Arc::new(signer).unsize(Coercion!(to dyn Signer)))
+    }
 }
 
 impl fmt::Debug for AnySigner {
@@ -49,6 +149,7 @@ impl fmt::Debug for AnySigner {
             Self::Arbitrary(arg0, _) => {
                 f.debug_tuple("Arbitrary").field(arg0).field(&"Fn").finish()
             }
+            Self::Signer(it) => f.debug_tuple("Signer").field(&it.public_key()).finish(),
         }
     }
 }
@@ -59,17 +160,111 @@ impl AnySigner {
         match self {
             AnySigner::PrivateKey(it) => it.public_key(),
             AnySigner::Arbitrary(it, _) => **it,
+            AnySigner::Signer(it) => it.public_key(),
         }
     }
 
-    pub(crate) fn sign(&self, message: &[u8]) -> (PublicKey, Vec<u8>) {
+    pub(crate) async fn sign(&self, message: &[u8]) -> crate::Result<(PublicKey, Vec<u8>)> {
         match self {
-            AnySigner::PrivateKey(it) => (it.public_key(), it.sign(message)),
+            AnySigner::PrivateKey(it) => Ok((it.public_key(), it.sign(message))),
             AnySigner::Arbitrary(public, signer) => {
                 let bytes = signer(message);
 
-                (**public, bytes)
+                Ok((**public, bytes))
+            }
+            AnySigner::Signer(signer) => {
+                let bytes = signer.sign(message).await.map_err(Error::Signer)?;
+
+                Ok((signer.public_key(), bytes))
             }
         }
     }
 }
+
+/// Drives a signing future to completion without an executor.
+///
+/// This is how synchronous APIs (such as `to_bytes`) sign, it works for every signer that doesn't actually need to wait on anything.
+///
+/// # Errors
+/// - [`Error::SignerNotReady`] if a signer didn't finish immediately.
+pub(crate) fn sign_now<T>(signing: impl Future<Output = crate::Result<T>>) -> crate::Result<T> {
+    signing.now_or_never().unwrap_or(Err(Error::SignerNotReady))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::pending;
+
+    use futures_core::future::BoxFuture;
+    use futures_util::FutureExt;
+
+    use super::{
+        sign_now,
+        AnySigner,
+        Signer,
+    };
+    use crate::{
+        Error,
+        PrivateKey,
+        PublicKey,
+    };
+
+    struct PendingSigner(PublicKey);
+
+    impl Signer for PendingSigner {
+        fn public_key(&self) -> PublicKey {
+            self.0
+        }
+
+        fn sign<'a>(
+            &'a self,
+            _message: &'a [u8],
+        ) -> BoxFuture<'a, Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>>> {
+            Box::pin(pending())
+        }
+    }
+
+    struct FailingSigner(PublicKey);
+
+    impl Signer for FailingSigner {
+        fn public_key(&self) -> PublicKey {
+            self.0
+        }
+
+        fn sign<'a>(
+            &'a self,
+            _message: &'a [u8],
+        ) -> BoxFuture<'a, Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>>> {
+            Box::pin(async { Err("remote signer is unavailable".into()) })
+        }
+    }
+
+    #[test]
+    fn private_key_signer_matches_private_key() {
+        let key = PrivateKey::generate_ed25519();
+        let signer = AnySigner::signer(key.clone());
+
+        let (public_key, signature) = signer.sign(b"hello").now_or_never().unwrap().unwrap();
+
+        assert_eq!(public_key, key.public_key());
+        assert_eq!(signature, key.sign(b"hello"));
+
+        // a boxed private key is still signed with directly.
+        let signer: Box<dyn Signer> = Box::new(key);
+        assert_matches::assert_matches!(AnySigner::signer(signer), AnySigner::PrivateKey(_));
+    }
+
+    #[test]
+    fn sign_now_pending_signer() {
+        let signer = AnySigner::signer(PendingSigner(PrivateKey::generate_ed25519().public_key()));
+
+        assert!(matches!(sign_now(signer.sign(b"hello")), Err(Error::SignerNotReady)));
+    }
+
+    #[test]
+    fn failing_signer() {
+        let signer = AnySigner::signer(FailingSigner(PrivateKey::generate_ed25519().public_key()));
+
+        assert!(matches!(sign_now(signer.sign(b"hello")), Err(Error::Signer(_))));
+    }
+}
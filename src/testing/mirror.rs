@@ -0,0 +1,280 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::io;
+use std::net::SocketAddr;
+use std::task::{
+    Context,
+    Poll,
+};
+use std::time::Duration;
+
+use async_stream::stream;
+use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use hedera_proto::mirror;
+use parking_lot::Mutex;
+use time::OffsetDateTime;
+use tokio::sync::oneshot;
+use tonic::body::BoxBody;
+use tonic::codec::ProstCodec;
+use tonic::codegen::http;
+use tonic::server::{
+    Grpc,
+    NamedService,
+};
+use tonic::transport::Server;
+use tower::{
+    service_fn,
+    Service,
+};
+use triomphe::Arc;
+
+use crate::MirrorEndpoint;
+
+/// A mock mirror node, serving topic message subscriptions.
+///
+/// Like any mirror node, its responses carry the current time in a `Date` header, which clients synchronize their clock with.
+///
+/// Every subscription is answered by the next [`MockTopicStream`] queued with [`respond`](Self::respond),
+/// a subscription without one stays open without ever receiving a message.
+///
+/// The node is shut down when this is dropped.
+pub struct MockMirrorNode {
+    address: SocketAddr,
+    state: Arc<MockMirrorState>,
+    _shutdown: oneshot::Sender<()>,
+}
+
+impl MockMirrorNode {
+    /// Start a mock mirror node on a random local port.
+    ///
+    /// # Errors
+    /// - If the port can't be bound.
+    pub async fn start() -> io::Result<Self> {
+        let state =
+            Arc::new(MockMirrorState { streams: Mutex::default(), start_times: Mutex::default() });
+
+        let router = Server::builder().add_service(MirrorConsensusService(Arc::clone(&state)));
+
+        let (address, shutdown) = super::serve(router).await?;
+
+        Ok(Self { address, state, _shutdown: shutdown })
+    }
+
+    /// Returns the local address the node is listening on.
+    #[must_use]
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Returns an endpoint for this node, for use with [`Client::set_mirror_network_endpoints`](crate::Client::set_mirror_network_endpoints).
+    #[must_use]
+    pub fn endpoint(&self) -> MirrorEndpoint {
+        let mut endpoint = MirrorEndpoint::new(self.address.to_string());
+        endpoint.plaintext(true);
+
+        endpoint
+    }
+
+    /// Queue up `stream` for a future subscription.
+    pub fn respond(&self, stream: MockTopicStream) {
+        self.state.streams.lock().push_back(stream);
+    }
+
+    /// Returns the start time requested by every subscription so far, in order.
+    ///
+    /// After a disconnect, the SDK resumes just after the last message it received, which shows up here.
+    #[must_use]
+    pub fn subscription_start_times(&self) -> Vec<Option<OffsetDateTime>> {
+        self.state.start_times.lock().clone()
+    }
+}
+
+/// What a single topic subscription to a [`MockMirrorNode`] receives.
+///
+/// Once everything has been sent, the stream ends.
+#[derive(Clone, Debug, Default)]
+pub struct MockTopicStream {
+    events: Vec<MockTopicEvent>,
+}
+
+#[derive(Clone, Debug)]
+enum MockTopicEvent {
+    Message(mirror::ConsensusTopicResponse),
+    Delay(Duration),
+    Disconnect(tonic::Code),
+}
+
+impl MockTopicStream {
+    /// Create a new, empty, stream.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send a message.
+    pub fn message(
+        &mut self,
+        sequence_number: u64,
+        consensus_timestamp: OffsetDateTime,
+        contents: impl Into<Vec<u8>>,
+    ) -> &mut Self {
+        self.events.push(MockTopicEvent::Message(mirror::ConsensusTopicResponse {
+            consensus_timestamp: Some(consensus_timestamp.into()),
+            message: contents.into(),
+            sequence_number,
+            ..Default::default()
+        }));
+
+        self
+    }
+
+    /// Wait for `delay` before sending anything else.
+    pub fn delay(&mut self, delay: Duration) -> &mut Self {
+        self.events.push(MockTopicEvent::Delay(delay));
+
+        self
+    }
+
+    /// End the stream with a gRPC error, such as [`tonic::Code::Unavailable`].
+    ///
+    /// Anything after this is never sent.
+    pub fn disconnect(&mut self, code: tonic::Code) -> &mut Self {
+        self.events.push(MockTopicEvent::Disconnect(code));
+
+        self
+    }
+}
+
+type TopicResponseStream =
+    BoxStream<'static, Result<mirror::ConsensusTopicResponse, tonic::Status>>;
+
+struct MockMirrorState {
+    streams: Mutex<VecDeque<MockTopicStream>>,
+    start_times: Mutex<Vec<Option<OffsetDateTime>>>,
+}
+
+impl MockMirrorState {
+    fn subscribe(&self, query: mirror::ConsensusTopicQuery) -> TopicResponseStream {
+        self.start_times.lock().push(query.consensus_start_time.map(OffsetDateTime::from));
+
+        let Some(MockTopicStream { events }) = self.streams.lock().pop_front() else {
+            return Box::pin(futures_util::stream::pending::<
+                Result<mirror::ConsensusTopicResponse, tonic::Status>,
+            >());
+        };
+
+        Box::pin(stream! {
+            for event in events {
+                match event {
+                    MockTopicEvent::Message(message) => yield Ok(message),
+                    MockTopicEvent::Delay(delay) => tokio::time::sleep(delay).await,
+                    MockTopicEvent::Disconnect(code) => {
+                        yield Err(tonic::Status::new(code, "scripted mock disconnect"));
+                        return;
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[derive(Clone)]
+struct MirrorConsensusService(Arc<MockMirrorState>);
+
+impl NamedService for MirrorConsensusService {
+    const NAME: &'static str = "com.hedera.mirror.api.proto.ConsensusService";
+}
+
+impl Service<http::Request<BoxBody>> for MirrorConsensusService {
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+        let state = Arc::clone(&self.0);
+
+        Box::pin(async move {
+            // `subscribeTopic` is the only rpc on this service.
+            let service =
+                service_fn(move |request: tonic::Request<mirror::ConsensusTopicQuery>| {
+                    let stream = state.subscribe(request.into_inner());
+
+                    async move { Ok::<_, tonic::Status>(tonic::Response::new(stream)) }
+                });
+
+            let response = Grpc::new(ProstCodec::<
+                mirror::ConsensusTopicResponse,
+                mirror::ConsensusTopicQuery,
+            >::default())
+            .server_streaming(service, request)
+            .await;
+
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use time::OffsetDateTime;
+
+    use super::{
+        MockMirrorNode,
+        MockTopicStream,
+    };
+    use crate::testing::MockNetwork;
+    use crate::{
+        TopicId,
+        TopicMessageQuery,
+    };
+
+    #[tokio::test]
+    async fn resumes_after_disconnect() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let mirror = MockMirrorNode::start().await.unwrap();
+
+        let client = network.client();
+        client.set_mirror_network_endpoints([mirror.endpoint()]).unwrap();
+
+        let start = OffsetDateTime::now_utc();
+        let second = start + Duration::from_secs(1);
+
+        mirror.respond(
+            MockTopicStream::new()
+                .message(1, start, "first")
+                .delay(Duration::from_millis(10))
+                .message(2, second, "second")
+                .disconnect(tonic::Code::Unavailable)
+                .clone(),
+        );
+
+        mirror.respond(
+            MockTopicStream::new().message(3, second + Duration::from_secs(1), "third").clone(),
+        );
+
+        let messages = TopicMessageQuery::new()
+            .topic_id(TopicId::new(0, 0, 1001))
+            .start_time(start)
+            .limit(3)
+            .execute(&client)
+            .await
+            .unwrap();
+
+        let contents: Vec<_> = messages.iter().map(|it| it.contents.as_slice()).collect();
+        assert_eq!(contents, [b"first".as_slice(), b"second", b"third"]);
+
+        let start_times = mirror.subscription_start_times();
+
+        assert_eq!(start_times.len(), 2);
+        assert_eq!(start_times[1], Some(second + time::Duration::nanoseconds(1)));
+    }
+}
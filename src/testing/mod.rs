@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! In-process mock consensus and mirror nodes, for testing code built on the SDK without a network.
+//!
+//! The mocks speak real gRPC on a local port, so everything from the [`Client`](crate::Client) down
+//! (retries, backoff, pings, failover) runs exactly like it does against a real network.
+//!
+//! # Examples
+//! ```
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use hedera::testing::{MockNetwork, MockResponse};
+//! use hedera::{AccountId, Hbar, Status, TransferTransaction};
+//!
+//! let network = MockNetwork::start(1).await?;
+//! let client = network.client();
+//!
+//! // the node is busy the first time around, the SDK retries and the transaction goes through.
+//! network.nodes()[0].respond(MockResponse::busy());
+//! network.nodes()[0].respond(MockResponse::receipt(Status::Success));
+//!
+//! let receipt = TransferTransaction::new()
+//!     .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+//!     .hbar_transfer(AccountId::new(0, 0, 3), Hbar::new(1))
+//!     .execute(&client)
+//!     .await?
+//!     .get_receipt(&client)
+//!     .await?;
+//!
+//! assert_eq!(receipt.status, Status::Success);
+//! # Ok(())
+//! # }
+//! ```
+
+mod mirror;
+mod node;
+
+use std::io;
+use std::net::{
+    Ipv4Addr,
+    SocketAddr,
+};
+
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tonic::transport::server::{
+    Router,
+    TcpIncoming,
+};
+
+pub use self::mirror::{
+    MockMirrorNode,
+    MockTopicStream,
+};
+pub use self::node::{
+    MockNetwork,
+    MockNode,
+    MockRequest,
+    MockResponse,
+};
+
+/// Serves `router` on a random local port until the returned sender is dropped.
+async fn serve(router: Router) -> io::Result<(SocketAddr, oneshot::Sender<()>)> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+    let address = listener.local_addr()?;
+    let incoming = TcpIncoming::from_listener(listener, true, None).map_err(io::Error::other)?;
+
+    let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+
+    tokio::spawn(async move {
+        let shutdown = async move {
+            // either way (sent or dropped), it's time to stop.
+            let _ = shutdown_rx.await;
+        };
+
+        if let Err(e) = router.serve_with_incoming_shutdown(incoming, shutdown).await {
+            log::warn!("mock server at `{address}` failed: {e}");
+        }
+    });
+
+    Ok((address, shutdown_tx))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::time::Duration;
+
+    use super::MockNetwork;
+    use crate::{
+        AccountId,
+        Client,
+        Hbar,
+        TransferTransaction,
+    };
+
+    /// A transfer that the mock nodes will happily accept.
+    pub(crate) fn transfer() -> TransferTransaction {
+        let mut transaction = TransferTransaction::new();
+
+        transaction
+            .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 3), Hbar::new(1));
+
+        transaction
+    }
+
+    /// A client for `network` that doesn't spend long backing off.
+    pub(crate) fn fast_client(network: &MockNetwork) -> Client {
+        let client = network.client();
+        client.set_min_backoff(Duration::from_millis(10));
+        client.set_max_backoff(Duration::from_millis(100));
+
+        client
+    }
+
+    /// How many `method` requests the first node of `network` has seen.
+    pub(crate) fn count(network: &MockNetwork, method: &str) -> usize {
+        network.nodes()[0].requests().iter().filter(|it| it.method == method).count()
+    }
+}
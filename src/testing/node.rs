@@ -0,0 +1,704 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+use std::convert::Infallible;
+use std::io;
+use std::net::SocketAddr;
+use std::task::{
+    Context,
+    Poll,
+};
+use std::time::Duration;
+
+use futures_core::future::BoxFuture;
+use hedera_proto::services;
+use parking_lot::Mutex;
+use prost::Message;
+use time::OffsetDateTime;
+use tokio::sync::oneshot;
+use tonic::body::BoxBody;
+use tonic::codec::ProstCodec;
+use tonic::codegen::http;
+use tonic::server::{
+    Grpc,
+    NamedService,
+};
+use tonic::transport::Server;
+use tower::{
+    service_fn,
+    Service,
+};
+use triomphe::Arc;
+
+use crate::protobuf::ToProtobuf;
+use crate::{
+    AccountId,
+    Client,
+    PrivateKey,
+    Status,
+    TransactionReceipt,
+    TransactionRecord,
+};
+
+/// A set of mock consensus nodes, with account IDs starting at `0.0.3`.
+///
+/// The nodes are shut down when this is dropped.
+pub struct MockNetwork {
+    nodes: Vec<MockNode>,
+    _shutdown: Vec<oneshot::Sender<()>>,
+}
+
+impl MockNetwork {
+    /// Start `node_count` mock consensus nodes, each on a random local port.
+    ///
+    /// # Errors
+    /// - If a node's port can't be bound.
+    pub async fn start(node_count: usize) -> io::Result<Self> {
+        let mut nodes = Vec::with_capacity(node_count);
+        let mut shutdown = Vec::with_capacity(node_count);
+
+        for num in 3..(3 + node_count as u64) {
+            let (node, shutdown_tx) = MockNode::start(AccountId::from(num)).await?;
+
+            nodes.push(node);
+            shutdown.push(shutdown_tx);
+        }
+
+        Ok(Self { nodes, _shutdown: shutdown })
+    }
+
+    /// Returns the nodes in this network.
+    #[must_use]
+    pub fn nodes(&self) -> &[MockNode] {
+        &self.nodes
+    }
+
+    /// Returns the network in the form accepted by [`Client::for_network`].
+    #[must_use]
+    pub fn network(&self) -> HashMap<String, AccountId> {
+        self.nodes.iter().map(|it| (it.address.to_string(), it.account_id())).collect()
+    }
+
+    /// Create a client for this network, with an operator (`0.0.2`) using a freshly generated key.
+    // note: every address is a `SocketAddr` we bound, so `for_network` can't actually fail.
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn client(&self) -> Client {
+        let client = Client::for_network(self.network()).expect("mock node addresses are valid");
+
+        client.set_operator(AccountId::new(0, 0, 2), PrivateKey::generate_ed25519());
+
+        client
+    }
+}
+
+/// A mock consensus node.
+///
+/// Every request is answered by the first matching [`MockResponse`] queued with [`respond`](Self::respond),
+/// and when nothing matches, with a successful default:
+/// - transactions pass pre-check.
+/// - receipt queries return a receipt with [`Status::Success`].
+/// - record queries return a record with a [`Status::Success`] receipt.
+/// - other queries return a response that only has a header.
+///
+/// Cost queries and pings (balance queries for the node's own account) are always answered automatically,
+/// so they never use up a scripted response.
+#[derive(Clone)]
+pub struct MockNode {
+    address: SocketAddr,
+    state: Arc<MockNodeState>,
+}
+
+impl MockNode {
+    async fn start(account_id: AccountId) -> io::Result<(Self, oneshot::Sender<()>)> {
+        let state = Arc::new(MockNodeState {
+            account_id,
+            responses: Mutex::default(),
+            requests: Mutex::default(),
+        });
+
+        let router = Server::builder()
+            .add_service(CryptoService(Arc::clone(&state)))
+            .add_service(TokenService(Arc::clone(&state)))
+            .add_service(ConsensusService(Arc::clone(&state)))
+            .add_service(FileService(Arc::clone(&state)))
+            .add_service(SmartContractService(Arc::clone(&state)))
+            .add_service(ScheduleService(Arc::clone(&state)))
+            .add_service(NetworkService(Arc::clone(&state)));
+
+        let (address, shutdown) = super::serve(router).await?;
+
+        Ok((Self { address, state }, shutdown))
+    }
+
+    /// Returns the node's account ID.
+    #[must_use]
+    pub fn account_id(&self) -> AccountId {
+        self.state.account_id
+    }
+
+    /// Returns the local address the node is listening on.
+    #[must_use]
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Queue up `response` for a future request.
+    pub fn respond(&self, response: MockResponse) {
+        self.state.responses.lock().push_back(response);
+    }
+
+    /// Returns every request this node has received so far, in order.
+    #[must_use]
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.requests.lock().clone()
+    }
+}
+
+/// A request received by a [`MockNode`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct MockRequest {
+    /// The name of the gRPC method that was called, IE, `cryptoTransfer`.
+    pub method: String,
+
+    /// The protobuf encoded request, a `Transaction` for transactions and a `Query` for queries.
+    pub bytes: Vec<u8>,
+}
+
+/// A scripted answer to a single request to a [`MockNode`].
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    kind: MockResponseKind,
+    delay: Duration,
+}
+
+#[derive(Clone, Debug)]
+enum MockResponseKind {
+    PreCheck(Status),
+    Receipt(services::TransactionReceipt),
+    Record(services::TransactionRecord),
+    Grpc(tonic::Code),
+}
+
+impl MockResponse {
+    fn new(kind: MockResponseKind) -> Self {
+        Self { kind, delay: Duration::ZERO }
+    }
+
+    /// Answer the next transaction or query with the given pre-check status.
+    #[must_use]
+    pub fn pre_check(status: Status) -> Self {
+        Self::new(MockResponseKind::PreCheck(status))
+    }
+
+    /// Answer the next transaction or query with [`Status::Busy`].
+    #[must_use]
+    pub fn busy() -> Self {
+        Self::pre_check(Status::Busy)
+    }
+
+    /// Answer the next receipt query with a receipt with the given status.
+    #[must_use]
+    pub fn receipt(status: Status) -> Self {
+        Self::new(MockResponseKind::Receipt(services::TransactionReceipt {
+            status: status as i32,
+            ..Default::default()
+        }))
+    }
+
+    /// Answer the next receipt query with `receipt`.
+    #[must_use]
+    pub fn receipt_with(receipt: &TransactionReceipt) -> Self {
+        Self::new(MockResponseKind::Receipt(receipt.to_protobuf()))
+    }
+
+    /// Answer the next record query with a record whose receipt has the given status.
+    #[must_use]
+    pub fn record(status: Status) -> Self {
+        Self::new(MockResponseKind::Record(services::TransactionRecord {
+            receipt: Some(services::TransactionReceipt {
+                status: status as i32,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }))
+    }
+
+    /// Answer the next record query with `record`.
+    #[must_use]
+    pub fn record_with(record: &TransactionRecord) -> Self {
+        Self::new(MockResponseKind::Record(record.to_protobuf()))
+    }
+
+    /// Fail the next request with a gRPC error, such as [`tonic::Code::Unavailable`].
+    #[must_use]
+    pub fn grpc_error(code: tonic::Code) -> Self {
+        Self::new(MockResponseKind::Grpc(code))
+    }
+
+    /// Wait for `delay` before answering.
+    #[must_use]
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum RequestKind {
+    Transaction,
+    Query,
+    ReceiptQuery,
+    RecordQuery,
+}
+
+impl MockResponseKind {
+    fn answers(&self, request: RequestKind) -> bool {
+        match self {
+            Self::PreCheck(_) | Self::Grpc(_) => true,
+            Self::Receipt(_) => request == RequestKind::ReceiptQuery,
+            Self::Record(_) => request == RequestKind::RecordQuery,
+        }
+    }
+}
+
+struct MockNodeState {
+    account_id: AccountId,
+    responses: Mutex<VecDeque<MockResponse>>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+impl MockNodeState {
+    fn record_request(&self, method: &str, bytes: Vec<u8>) {
+        self.requests.lock().push(MockRequest { method: method.to_owned(), bytes });
+    }
+
+    /// Takes the first scripted response for `request`, waiting out its delay.
+    async fn take_response(&self, request: RequestKind) -> Option<MockResponseKind> {
+        let response = {
+            let mut responses = self.responses.lock();
+            let index = responses.iter().position(|it| it.kind.answers(request))?;
+            responses.remove(index)?
+        };
+
+        if !response.delay.is_zero() {
+            tokio::time::sleep(response.delay).await;
+        }
+
+        Some(response.kind)
+    }
+
+    fn is_ping(&self, query: &services::query::Query) -> bool {
+        use services::crypto_get_account_balance_query::BalanceSource;
+
+        matches!(
+            query,
+            services::query::Query::CryptogetAccountBalance(it)
+                if it.balance_source == Some(BalanceSource::AccountId(self.account_id.to_protobuf()))
+        )
+    }
+
+    async fn answer_transaction(
+        &self,
+        method: &str,
+        transaction: services::Transaction,
+    ) -> Result<services::TransactionResponse, tonic::Status> {
+        self.record_request(method, transaction.encode_to_vec());
+
+        let status = match self.take_response(RequestKind::Transaction).await {
+            Some(MockResponseKind::PreCheck(status)) => status,
+            Some(MockResponseKind::Grpc(code)) => return Err(scripted_error(code)),
+            Some(MockResponseKind::Receipt(_) | MockResponseKind::Record(_)) => {
+                unreachable!("receipts and records only answer queries")
+            }
+            None => Status::Ok,
+        };
+
+        Ok(services::TransactionResponse {
+            node_transaction_precheck_code: status as i32,
+            ..Default::default()
+        })
+    }
+
+    async fn answer_query(
+        &self,
+        method: &str,
+        query: services::Query,
+    ) -> Result<services::Response, tonic::Status> {
+        use services::query::Query;
+
+        self.record_request(method, query.encode_to_vec());
+
+        let Some(query) = query.query else {
+            return Err(tonic::Status::invalid_argument("missing `query` in `Query`"));
+        };
+
+        let (request, transaction_id) = match &query {
+            Query::TransactionGetReceipt(it) => {
+                (RequestKind::ReceiptQuery, it.transaction_id.clone())
+            }
+            Query::TransactionGetRecord(it) => {
+                (RequestKind::RecordQuery, it.transaction_id.clone())
+            }
+            _ => (RequestKind::Query, None),
+        };
+
+        let is_cost = query_header(&query)
+            .is_some_and(|it| it.response_type == services::ResponseType::CostAnswer as i32);
+
+        let scripted = match is_cost || self.is_ping(&query) {
+            true => None,
+            false => self.take_response(request).await,
+        };
+
+        let response = match (scripted, request) {
+            (Some(MockResponseKind::Grpc(code)), _) => return Err(scripted_error(code)),
+            (Some(MockResponseKind::PreCheck(status)), _) => header_only(&query, status),
+            (Some(MockResponseKind::Receipt(receipt)), _) => Some(receipt_response(receipt)),
+            (Some(MockResponseKind::Record(record)), _) => {
+                Some(record_response(record, transaction_id))
+            }
+            (None, RequestKind::ReceiptQuery) => Some(receipt_response(success_receipt())),
+            (None, RequestKind::RecordQuery) => Some(record_response(
+                services::TransactionRecord {
+                    receipt: Some(success_receipt()),
+                    ..Default::default()
+                },
+                transaction_id,
+            )),
+            (None, _) => header_only(&query, Status::Ok),
+        };
+
+        match response {
+            Some(response) => Ok(services::Response { response: Some(response) }),
+            None => Err(tonic::Status::unimplemented(format!("`{method}` isn't mocked"))),
+        }
+    }
+}
+
+fn scripted_error(code: tonic::Code) -> tonic::Status {
+    tonic::Status::new(code, "scripted mock error")
+}
+
+fn success_receipt() -> services::TransactionReceipt {
+    services::TransactionReceipt { status: Status::Success as i32, ..Default::default() }
+}
+
+fn response_header(status: Status) -> services::ResponseHeader {
+    services::ResponseHeader { node_transaction_precheck_code: status as i32, ..Default::default() }
+}
+
+fn receipt_response(receipt: services::TransactionReceipt) -> services::response::Response {
+    services::response::Response::TransactionGetReceipt(services::TransactionGetReceiptResponse {
+        header: Some(response_header(Status::Ok)),
+        receipt: Some(receipt),
+        ..Default::default()
+    })
+}
+
+fn record_response(
+    mut record: services::TransactionRecord,
+    transaction_id: Option<services::TransactionId>,
+) -> services::response::Response {
+    // fill in whatever the SDK requires that a scripted record didn't specify.
+    record.transaction_id = record.transaction_id.or(transaction_id);
+    record.consensus_timestamp.get_or_insert_with(|| OffsetDateTime::now_utc().into());
+
+    services::response::Response::TransactionGetRecord(services::TransactionGetRecordResponse {
+        header: Some(response_header(Status::Ok)),
+        transaction_record: Some(record),
+        ..Default::default()
+    })
+}
+
+fn query_header(query: &services::query::Query) -> Option<&services::QueryHeader> {
+    use services::query::Query;
+
+    let header = match query {
+        Query::CryptogetAccountBalance(it) => &it.header,
+        Query::CryptoGetAccountRecords(it) => &it.header,
+        Query::CryptoGetInfo(it) => &it.header,
+        Query::FileGetContents(it) => &it.header,
+        Query::FileGetInfo(it) => &it.header,
+        Query::ContractCallLocal(it) => &it.header,
+        Query::ContractGetBytecode(it) => &it.header,
+        Query::ContractGetInfo(it) => &it.header,
+        Query::ConsensusGetTopicInfo(it) => &it.header,
+        Query::NetworkGetVersionInfo(it) => &it.header,
+        Query::ScheduleGetInfo(it) => &it.header,
+        Query::TokenGetInfo(it) => &it.header,
+        Query::TokenGetNftInfo(it) => &it.header,
+        Query::TransactionGetReceipt(it) => &it.header,
+        Query::TransactionGetRecord(it) => &it.header,
+        _ => return None,
+    };
+
+    header.as_ref()
+}
+
+/// Returns a response to `query` that has nothing but a header with `status`.
+///
+/// Returns `None` for queries the SDK doesn't make.
+fn header_only(
+    query: &services::query::Query,
+    status: Status,
+) -> Option<services::response::Response> {
+    use services::query::Query;
+    use services::response::Response;
+
+    let header = Some(response_header(status));
+
+    let response = match query {
+        Query::CryptogetAccountBalance(_) => {
+            Response::CryptogetAccountBalance(services::CryptoGetAccountBalanceResponse {
+                header,
+                ..Default::default()
+            })
+        }
+        Query::CryptoGetAccountRecords(_) => {
+            Response::CryptoGetAccountRecords(services::CryptoGetAccountRecordsResponse {
+                header,
+                ..Default::default()
+            })
+        }
+        Query::CryptoGetInfo(_) => Response::CryptoGetInfo(services::CryptoGetInfoResponse {
+            header,
+            ..Default::default()
+        }),
+        Query::FileGetContents(_) => Response::FileGetContents(services::FileGetContentsResponse {
+            header,
+            ..Default::default()
+        }),
+        Query::FileGetInfo(_) => {
+            Response::FileGetInfo(services::FileGetInfoResponse { header, ..Default::default() })
+        }
+        Query::ContractCallLocal(_) => {
+            Response::ContractCallLocal(services::ContractCallLocalResponse {
+                header,
+                ..Default::default()
+            })
+        }
+        Query::ContractGetBytecode(_) => {
+            Response::ContractGetBytecodeResponse(services::ContractGetBytecodeResponse {
+                header,
+                ..Default::default()
+            })
+        }
+        Query::ContractGetInfo(_) => Response::ContractGetInfo(services::ContractGetInfoResponse {
+            header,
+            ..Default::default()
+        }),
+        Query::ConsensusGetTopicInfo(_) => {
+            Response::ConsensusGetTopicInfo(services::ConsensusGetTopicInfoResponse {
+                header,
+                ..Default::default()
+            })
+        }
+        Query::NetworkGetVersionInfo(_) => {
+            Response::NetworkGetVersionInfo(services::NetworkGetVersionInfoResponse {
+                header,
+                ..Default::default()
+            })
+        }
+        Query::ScheduleGetInfo(_) => Response::ScheduleGetInfo(services::ScheduleGetInfoResponse {
+            header,
+            ..Default::default()
+        }),
+        Query::TokenGetInfo(_) => {
+            Response::TokenGetInfo(services::TokenGetInfoResponse { header, ..Default::default() })
+        }
+        Query::TokenGetNftInfo(_) => Response::TokenGetNftInfo(services::TokenGetNftInfoResponse {
+            header,
+            ..Default::default()
+        }),
+        Query::TransactionGetReceipt(_) => {
+            Response::TransactionGetReceipt(services::TransactionGetReceiptResponse {
+                header,
+                ..Default::default()
+            })
+        }
+        Query::TransactionGetRecord(_) => {
+            Response::TransactionGetRecord(services::TransactionGetRecordResponse {
+                header,
+                ..Default::default()
+            })
+        }
+        _ => return None,
+    };
+
+    Some(response)
+}
+
+/// Returns `true` if the rpc `method` takes a `Query` rather than a `Transaction`.
+fn is_query_method(method: &str) -> bool {
+    // every query is a `get` of some sort, except for local contract calls.
+    method == "contractCallLocalMethod" || method.to_ascii_lowercase().contains("get")
+}
+
+async fn handle(
+    state: Arc<MockNodeState>,
+    request: http::Request<BoxBody>,
+) -> http::Response<BoxBody> {
+    let method = request.uri().path().rsplit('/').next().unwrap_or_default().to_owned();
+
+    let state = &*state;
+    let method = method.as_str();
+
+    if is_query_method(method) {
+        let service = service_fn(move |request: tonic::Request<services::Query>| async move {
+            state.answer_query(method, request.into_inner()).await.map(tonic::Response::new)
+        });
+
+        Grpc::new(ProstCodec::<services::Response, services::Query>::default())
+            .unary(service, request)
+            .await
+    } else {
+        let service =
+            service_fn(move |request: tonic::Request<services::Transaction>| async move {
+                state
+                    .answer_transaction(method, request.into_inner())
+                    .await
+                    .map(tonic::Response::new)
+            });
+
+        Grpc::new(ProstCodec::<services::TransactionResponse, services::Transaction>::default())
+            .unary(service, request)
+            .await
+    }
+}
+
+// tonic routes by service name, so every service the node answers needs its own type.
+macro_rules! mock_services {
+    ($($service:ident => $name:literal),* $(,)?) => {$(
+        #[derive(Clone)]
+        struct $service(Arc<MockNodeState>);
+
+        impl NamedService for $service {
+            const NAME: &'static str = $name;
+        }
+
+        impl Service<http::Request<BoxBody>> for $service {
+            type Response = http::Response<BoxBody>;
+            type Error = Infallible;
+            type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+            fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+                Poll::Ready(Ok(()))
+            }
+
+            fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+                let state = Arc::clone(&self.0);
+
+                Box::pin(async move { Ok(handle(state, request).await) })
+            }
+        }
+    )*};
+}
+
+mock_services! {
+    CryptoService => "proto.CryptoService",
+    TokenService => "proto.TokenService",
+    ConsensusService => "proto.ConsensusService",
+    FileService => "proto.FileService",
+    SmartContractService => "proto.SmartContractService",
+    ScheduleService => "proto.ScheduleService",
+    NetworkService => "proto.NetworkService",
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use assert_matches::assert_matches;
+
+    use super::{
+        is_query_method,
+        MockNetwork,
+        MockResponse,
+    };
+    use crate::testing::tests::{
+        count,
+        fast_client,
+        transfer,
+    };
+    use crate::{
+        Error,
+        Status,
+    };
+
+    #[test]
+    fn query_methods() {
+        assert!(is_query_method("getTransactionReceipts"));
+        assert!(is_query_method("cryptoGetBalance"));
+        assert!(is_query_method("ContractGetBytecode"));
+        assert!(is_query_method("contractCallLocalMethod"));
+        assert!(!is_query_method("cryptoTransfer"));
+        assert!(!is_query_method("contractCallMethod"));
+        assert!(!is_query_method("submitMessage"));
+    }
+
+    #[tokio::test]
+    async fn busy_is_retried() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let client = fast_client(&network);
+
+        network.nodes()[0].respond(MockResponse::busy());
+        network.nodes()[0].respond(MockResponse::busy().with_delay(Duration::from_millis(10)));
+
+        let response = transfer().execute(&client).await.unwrap();
+
+        assert_eq!(response.node_account_id, network.nodes()[0].account_id());
+        assert_eq!(count(&network, "cryptoTransfer"), 3);
+    }
+
+    #[tokio::test]
+    async fn pre_check_failure() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let client = fast_client(&network);
+
+        network.nodes()[0].respond(MockResponse::pre_check(Status::InsufficientPayerBalance));
+
+        let error = transfer().execute(&client).await.unwrap_err();
+
+        assert_matches!(
+            error,
+            Error::TransactionPreCheckStatus { status: Status::InsufficientPayerBalance, .. }
+        );
+    }
+
+    #[tokio::test]
+    async fn receipt_and_record() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let client = fast_client(&network);
+
+        network.nodes()[0].respond(MockResponse::receipt(Status::InvalidSignature));
+
+        let response = transfer().execute(&client).await.unwrap();
+
+        assert_matches!(
+            response.get_receipt(&client).await,
+            Err(Error::ReceiptStatus { status: Status::InvalidSignature, .. })
+        );
+
+        let record = response.get_record(&client).await.unwrap();
+
+        assert_eq!(record.receipt.status, Status::Success);
+        assert_eq!(record.transaction_id, response.transaction_id);
+    }
+
+    #[tokio::test]
+    async fn grpc_error_is_retried() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let client = fast_client(&network);
+
+        network.nodes()[0].respond(MockResponse::grpc_error(tonic::Code::Unavailable));
+
+        transfer().execute(&client).await.unwrap();
+
+        assert_eq!(count(&network, "cryptoTransfer"), 2);
+    }
+}
@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use hedera_proto::services;
+
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::RequestType;
+
+/// The throttles the network applies to requests, as found in the throttle definitions system file (`0.0.123`).
+///
+/// These can also be written by hand, for use with [`Client::set_throttle_definitions`](crate::Client::set_throttle_definitions).
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ThrottleDefinitions {
+    /// The throttle buckets, every request is throttled by each bucket that contains its request type.
+    pub buckets: Vec<ThrottleBucket>,
+}
+
+impl ThrottleDefinitions {
+    /// Create a new `ThrottleDefinitions` from protobuf-encoded `bytes`.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the protobuf fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        FromProtobuf::from_bytes(bytes)
+    }
+
+    /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        ToProtobuf::to_bytes(self)
+    }
+}
+
+impl FromProtobuf<services::ThrottleDefinitions> for ThrottleDefinitions {
+    fn from_protobuf(pb: services::ThrottleDefinitions) -> crate::Result<Self> {
+        Ok(Self { buckets: Vec::from_protobuf(pb.throttle_buckets)? })
+    }
+}
+
+impl ToProtobuf for ThrottleDefinitions {
+    type Protobuf = services::ThrottleDefinitions;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::ThrottleDefinitions { throttle_buckets: self.buckets.to_protobuf() }
+    }
+}
+
+/// A named group of throttles that share a single burst capacity.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ThrottleBucket {
+    /// The name of the bucket, such as `ThroughputLimits`.
+    pub name: String,
+
+    /// How long a burst of requests at the full throttled rate can last.
+    pub burst_period: Duration,
+
+    /// The throttled request types and their rates.
+    pub groups: Vec<ThrottleGroup>,
+}
+
+impl FromProtobuf<services::ThrottleBucket> for ThrottleBucket {
+    fn from_protobuf(pb: services::ThrottleBucket) -> crate::Result<Self> {
+        Ok(Self {
+            name: pb.name,
+            burst_period: Duration::from_millis(pb.burst_period_ms),
+            groups: Vec::from_protobuf(pb.throttle_groups)?,
+        })
+    }
+}
+
+impl ToProtobuf for ThrottleBucket {
+    type Protobuf = services::ThrottleBucket;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::ThrottleBucket {
+            name: self.name.clone(),
+            burst_period_ms: u64::try_from(self.burst_period.as_millis()).unwrap_or(u64::MAX),
+            throttle_groups: self.groups.to_protobuf(),
+        }
+    }
+}
+
+/// Request types that are throttled at the same rate.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ThrottleGroup {
+    /// The request types in this group.
+    pub operations: Vec<RequestType>,
+
+    /// The rate these request types are throttled to across the whole network, in thousandths of an operation per second.
+    pub milli_ops_per_sec: u64,
+}
+
+impl FromProtobuf<services::ThrottleGroup> for ThrottleGroup {
+    fn from_protobuf(pb: services::ThrottleGroup) -> crate::Result<Self> {
+        Ok(Self {
+            operations: pb
+                .operations()
+                .map(RequestType::from_protobuf)
+                .collect::<crate::Result<Vec<_>>>()?,
+            milli_ops_per_sec: pb.milli_ops_per_sec,
+        })
+    }
+}
+
+impl ToProtobuf for ThrottleGroup {
+    type Protobuf = services::ThrottleGroup;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::ThrottleGroup {
+            operations: self.operations.iter().map(|it| it.to_protobuf() as i32).collect(),
+            milli_ops_per_sec: self.milli_ops_per_sec,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        ThrottleBucket,
+        ThrottleDefinitions,
+        ThrottleGroup,
+    };
+    use crate::RequestType;
+
+    #[test]
+    fn to_from_bytes() {
+        let definitions = ThrottleDefinitions {
+            buckets: vec![ThrottleBucket {
+                name: "ThroughputLimits".to_owned(),
+                burst_period: Duration::from_secs(1),
+                groups: vec![ThrottleGroup {
+                    operations: vec![RequestType::CryptoTransfer, RequestType::CryptoCreate],
+                    milli_ops_per_sec: 10_500_000,
+                }],
+            }],
+        };
+
+        assert_eq!(ThrottleDefinitions::from_bytes(&definitions.to_bytes()).unwrap(), definitions);
+    }
+}
@@ -25,6 +25,7 @@ use crate::transaction::{
     TransactionExecute,
 };
 use crate::transfer_transaction::{
+    senders,
     TokenTransfer,
     Transfer,
 };
@@ -32,6 +33,7 @@ use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     Transaction,
     ValidateChecksums,
 };
@@ -286,7 +288,11 @@ impl TokenAirdropTransaction {
     }
 }
 
-impl TransactionData for TokenAirdropTransactionData {}
+impl TransactionData for TokenAirdropTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        senders(&[], &self.token_transfers).map(RequiredSigner::Account).collect()
+    }
+}
 
 impl TransactionExecute for TokenAirdropTransactionData {
     fn execute(
@@ -18,6 +18,7 @@ use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     ToProtobuf,
     TokenId,
     Transaction,
@@ -74,7 +75,11 @@ impl TokenAssociateTransaction {
     }
 }
 
-impl TransactionData for TokenAssociateTransactionData {}
+impl TransactionData for TokenAssociateTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.account_id.map(RequiredSigner::Account).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for TokenAssociateTransactionData {
     fn execute(
@@ -19,7 +19,9 @@ use crate::transaction::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     TokenId,
+    TokenKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -99,7 +101,14 @@ impl TokenBurnTransaction {
     }
 }
 
-impl TransactionData for TokenBurnTransactionData {}
+impl TransactionData for TokenBurnTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.token_id
+            .map(|it| RequiredSigner::Token(it, TokenKeyKind::Supply))
+            .into_iter()
+            .collect()
+    }
+}
 
 impl TransactionExecute for TokenBurnTransactionData {
     fn execute(
@@ -18,6 +18,7 @@ use crate::{
     BoxGrpcFuture,
     Error,
     FromProtobuf,
+    RequiredSigner,
     ToProtobuf,
     Transaction,
     ValidateChecksums,
@@ -61,7 +62,11 @@ impl TokenCancelAirdropTransaction {
     }
 }
 
-impl TransactionData for TokenCancelAirdropTransactionData {}
+impl TransactionData for TokenCancelAirdropTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.pending_airdrop_ids.iter().map(|it| RequiredSigner::Account(it.sender_id)).collect()
+    }
+}
 
 impl TransactionExecute for TokenCancelAirdropTransactionData {
     fn execute(
@@ -21,6 +21,7 @@ use crate::transaction::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     Transaction,
     ValidateChecksums,
 };
@@ -77,7 +78,11 @@ impl TokenClaimAirdropTransaction {
     }
 }
 
-impl TransactionData for TokenClaimAirdropTransactionData {}
+impl TransactionData for TokenClaimAirdropTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.pending_airdrop_ids.iter().map(|it| RequiredSigner::Account(it.receiver_id)).collect()
+    }
+}
 
 impl TransactionExecute for TokenClaimAirdropTransactionData {
     fn execute(
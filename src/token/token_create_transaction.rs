@@ -29,6 +29,7 @@ use crate::{
     BoxGrpcFuture,
     Error,
     Key,
+    RequiredSigner,
     Transaction,
     ValidateChecksums,
 };
@@ -460,6 +461,14 @@ impl TransactionData for TokenCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::from_unit(40, crate::HbarUnit::Hbar)
     }
+
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        let treasury = self.treasury_account_id.map(RequiredSigner::Account);
+        let admin_key = self.admin_key.clone().map(RequiredSigner::Key);
+        let auto_renew = self.auto_renew_account_id.map(RequiredSigner::Account);
+
+        treasury.into_iter().chain(admin_key).chain(auto_renew).collect()
+    }
 }
 
 impl TransactionExecute for TokenCreateTransactionData {
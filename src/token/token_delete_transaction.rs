@@ -20,7 +20,9 @@ use crate::transaction::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     TokenId,
+    TokenKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -56,7 +58,11 @@ impl TokenDeleteTransaction {
     }
 }
 
-impl TransactionData for TokenDeleteTransactionData {}
+impl TransactionData for TokenDeleteTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.token_id.map(|it| RequiredSigner::Token(it, TokenKeyKind::Admin)).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for TokenDeleteTransactionData {
     fn execute(
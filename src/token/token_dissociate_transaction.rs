@@ -21,6 +21,7 @@ use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     TokenId,
     Transaction,
     ValidateChecksums,
@@ -80,7 +81,11 @@ impl TokenDissociateTransaction {
     }
 }
 
-impl TransactionData for TokenDissociateTransactionData {}
+impl TransactionData for TokenDissociateTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.account_id.map(RequiredSigner::Account).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for TokenDissociateTransactionData {
     fn execute(
@@ -20,7 +20,9 @@ use crate::transaction::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     TokenId,
+    TokenKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -74,7 +76,14 @@ impl TokenFeeScheduleUpdateTransaction {
     }
 }
 
-impl TransactionData for TokenFeeScheduleUpdateTransactionData {}
+impl TransactionData for TokenFeeScheduleUpdateTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.token_id
+            .map(|it| RequiredSigner::Token(it, TokenKeyKind::FeeSchedule))
+            .into_iter()
+            .collect()
+    }
+}
 
 impl TransactionExecute for TokenFeeScheduleUpdateTransactionData {
     fn execute(
@@ -21,7 +21,9 @@ use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     TokenId,
+    TokenKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -75,7 +77,14 @@ impl TokenFreezeTransaction {
     }
 }
 
-impl TransactionData for TokenFreezeTransactionData {}
+impl TransactionData for TokenFreezeTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.token_id
+            .map(|it| RequiredSigner::Token(it, TokenKeyKind::Freeze))
+            .into_iter()
+            .collect()
+    }
+}
 
 impl TransactionExecute for TokenFreezeTransactionData {
     fn execute(
@@ -21,7 +21,9 @@ use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     TokenId,
+    TokenKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -74,7 +76,11 @@ impl TokenGrantKycTransaction {
     }
 }
 
-impl TransactionData for TokenGrantKycTransactionData {}
+impl TransactionData for TokenGrantKycTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.token_id.map(|it| RequiredSigner::Token(it, TokenKeyKind::Kyc)).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for TokenGrantKycTransactionData {
     fn execute(
@@ -20,7 +20,9 @@ use crate::transaction::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     TokenId,
+    TokenKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -100,7 +102,14 @@ impl TokenMintTransaction {
     }
 }
 
-impl TransactionData for TokenMintTransactionData {}
+impl TransactionData for TokenMintTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.token_id
+            .map(|it| RequiredSigner::Token(it, TokenKeyKind::Supply))
+            .into_iter()
+            .collect()
+    }
+}
 
 impl TransactionExecute for TokenMintTransactionData {
     fn execute(
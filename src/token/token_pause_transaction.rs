@@ -19,7 +19,9 @@ use crate::transaction::{
 };
 use crate::{
     BoxGrpcFuture,
+    RequiredSigner,
     TokenId,
+    TokenKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -56,7 +58,11 @@ impl TokenPauseTransaction {
     }
 }
 
-impl TransactionData for TokenPauseTransactionData {}
+impl TransactionData for TokenPauseTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.token_id.map(|it| RequiredSigner::Token(it, TokenKeyKind::Pause)).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for TokenPauseTransactionData {
     fn execute(
@@ -15,6 +15,7 @@ use crate::{
     Client,
     PrivateKey,
     PublicKey,
+    Signer,
     TransactionResponse,
 };
 
@@ -144,12 +145,20 @@ impl TokenRejectFlow {
         self
     }
 
+    /// Sets the signer for use in the ``TokenRejectTransaction``, with a [`Signer`].
+    ///
+    /// Important: Only *one* signer is allowed.
+    pub fn sign_with_signer<S: Signer>(&mut self, signer: S) -> &mut Self {
+        self.token_reject_data.signer = Some(AnySigner::signer(signer));
+
+        self
+    }
+
     /// Set the operator that this transaction will be signed with.
     pub fn sign_with_operator(&mut self, client: &Client) -> &mut Self {
         // todo: proper error
         let operator_key = client
-            .load_operator()
-            .as_deref()
+            .select_operator()
             .map(|it| it.signer.clone())
             .expect("Must call `Client.set_operator` to use token reject flow");
 
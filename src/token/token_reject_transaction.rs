@@ -21,6 +21,7 @@ use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     TokenId,
     Transaction,
     ValidateChecksums,
@@ -101,7 +102,12 @@ impl TokenRejectTransaction {
     }
 }
 
-impl TransactionData for TokenRejectTransactionData {}
+impl TransactionData for TokenRejectTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        // without an owner, the payer is the owner.
+        self.owner.map(RequiredSigner::Account).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for TokenRejectTransactionData {
     fn execute(
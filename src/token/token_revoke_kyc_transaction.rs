@@ -20,7 +20,9 @@ use crate::transaction::{
 use crate::{
     AccountId,
     BoxGrpcFuture,
+    RequiredSigner,
     TokenId,
+    TokenKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -74,7 +76,11 @@ impl TokenRevokeKycTransaction {
     }
 }
 
-impl TransactionData for TokenRevokeKycTransactionData {}
+impl TransactionData for TokenRevokeKycTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.token_id.map(|it| RequiredSigner::Token(it, TokenKeyKind::Kyc)).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for TokenRevokeKycTransactionData {
     fn execute(
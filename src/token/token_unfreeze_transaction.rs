@@ -20,7 +20,9 @@ use crate::transaction::{
 use crate::{
     AccountId,
     BoxGrpcFuture,
+    RequiredSigner,
     TokenId,
+    TokenKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -74,7 +76,14 @@ impl TokenUnfreezeTransaction {
     }
 }
 
-impl TransactionData for TokenUnfreezeTransactionData {}
+impl TransactionData for TokenUnfreezeTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.token_id
+            .map(|it| RequiredSigner::Token(it, TokenKeyKind::Freeze))
+            .into_iter()
+            .collect()
+    }
+}
 
 impl TransactionExecute for TokenUnfreezeTransactionData {
     fn execute(
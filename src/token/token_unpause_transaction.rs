@@ -19,7 +19,9 @@ use crate::transaction::{
 };
 use crate::{
     BoxGrpcFuture,
+    RequiredSigner,
     TokenId,
+    TokenKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -55,7 +57,11 @@ impl TokenUnpauseTransaction {
     }
 }
 
-impl TransactionData for TokenUnpauseTransactionData {}
+impl TransactionData for TokenUnpauseTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.token_id.map(|it| RequiredSigner::Token(it, TokenKeyKind::Pause)).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for TokenUnpauseTransactionData {
     fn execute(
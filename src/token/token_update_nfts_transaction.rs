@@ -20,7 +20,9 @@ use crate::transaction::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     TokenId,
+    TokenKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -85,7 +87,14 @@ impl TokenUpdateNftsTransaction {
     }
 }
 
-impl TransactionData for TokenUpdateNftsTransactionData {}
+impl TransactionData for TokenUpdateNftsTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.token_id
+            .map(|it| RequiredSigner::Token(it, TokenKeyKind::Metadata))
+            .into_iter()
+            .collect()
+    }
+}
 
 impl TransactionExecute for TokenUpdateNftsTransactionData {
     fn execute(
@@ -27,7 +27,9 @@ use crate::{
     BoxGrpcFuture,
     Error,
     Key,
+    RequiredSigner,
     TokenId,
+    TokenKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -369,7 +371,16 @@ impl TokenUpdateTransaction {
     }
 }
 
-impl TransactionData for TokenUpdateTransactionData {}
+impl TransactionData for TokenUpdateTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        let token = self.token_id.map(|it| RequiredSigner::Token(it, TokenKeyKind::Admin));
+        let treasury = self.treasury_account_id.map(RequiredSigner::Account);
+        let admin_key = self.admin_key.clone().map(RequiredSigner::Key);
+        let auto_renew = self.auto_renew_account_id.map(RequiredSigner::Account);
+
+        token.into_iter().chain(treasury).chain(admin_key).chain(auto_renew).collect()
+    }
+}
 
 impl TransactionExecute for TokenUpdateTransactionData {
     fn execute(
@@ -21,7 +21,9 @@ use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     TokenId,
+    TokenKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -123,7 +125,11 @@ impl TokenWipeTransaction {
     }
 }
 
-impl TransactionData for TokenWipeTransactionData {}
+impl TransactionData for TokenWipeTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.token_id.map(|it| RequiredSigner::Token(it, TokenKeyKind::Wipe)).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for TokenWipeTransactionData {
     fn execute(
@@ -25,6 +25,7 @@ use crate::{
     Error,
     Hbar,
     Key,
+    RequiredSigner,
     Transaction,
     ValidateChecksums,
 };
@@ -215,6 +216,13 @@ impl TransactionData for TopicCreateTransactionData {
     fn default_max_transaction_fee(&self) -> Hbar {
         Hbar::new(25)
     }
+
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        let admin_key = self.admin_key.clone().map(RequiredSigner::Key);
+        let auto_renew = self.auto_renew_account_id.map(RequiredSigner::Account);
+
+        admin_key.into_iter().chain(auto_renew).collect()
+    }
 }
 
 impl TransactionExecute for TopicCreateTransactionData {
@@ -20,7 +20,9 @@ use crate::transaction::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     TopicId,
+    TopicKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -54,7 +56,11 @@ impl TopicDeleteTransaction {
     }
 }
 
-impl TransactionData for TopicDeleteTransactionData {}
+impl TransactionData for TopicDeleteTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        self.topic_id.map(|it| RequiredSigner::Topic(it, TopicKeyKind::Admin)).into_iter().collect()
+    }
+}
 
 impl TransactionExecute for TopicDeleteTransactionData {
     fn execute(
@@ -26,7 +26,9 @@ use crate::transaction::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    RequiredSigner,
     TopicId,
+    TopicKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -84,6 +86,14 @@ impl TransactionData for TopicMessageSubmitTransactionData {
     fn wait_for_receipt(&self) -> bool {
         false
     }
+
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        // topics without a submit key accept anyone's messages, which `RequiredSigner::resolve` reports as `None`.
+        self.topic_id
+            .map(|it| RequiredSigner::Topic(it, TopicKeyKind::Submit))
+            .into_iter()
+            .collect()
+    }
 }
 
 impl ChunkedTransactionData for TopicMessageSubmitTransactionData {
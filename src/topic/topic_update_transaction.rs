@@ -27,7 +27,9 @@ use crate::{
     BoxGrpcFuture,
     Error,
     Key,
+    RequiredSigner,
     TopicId,
+    TopicKeyKind,
     Transaction,
     ValidateChecksums,
 };
@@ -247,7 +249,15 @@ impl TopicUpdateTransaction {
     }
 }
 
-impl TransactionData for TopicUpdateTransactionData {}
+impl TransactionData for TopicUpdateTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        let topic = self.topic_id.map(|it| RequiredSigner::Topic(it, TopicKeyKind::Admin));
+        let admin_key = self.admin_key.clone().map(RequiredSigner::Key);
+        let auto_renew = self.auto_renew_account_id.map(RequiredSigner::Account);
+
+        topic.into_iter().chain(admin_key).chain(auto_renew).collect()
+    }
+}
 
 impl TransactionExecute for TopicUpdateTransactionData {
     fn execute(
@@ -5,6 +5,7 @@ use tonic::transport::Channel;
 
 use super::chunked::ChunkInfo;
 use super::{
+    RequiredSigner,
     TransactionData,
     TransactionExecuteChunked,
 };
@@ -358,6 +359,60 @@ impl TransactionData for AnyTransactionData {
         }
     }
 
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        match self {
+            Self::Transfer(transaction) => transaction.required_signers(),
+            Self::AccountCreate(transaction) => transaction.required_signers(),
+            Self::AccountUpdate(transaction) => transaction.required_signers(),
+            Self::AccountDelete(transaction) => transaction.required_signers(),
+            Self::AccountAllowanceApprove(transaction) => transaction.required_signers(),
+            Self::AccountAllowanceDelete(transaction) => transaction.required_signers(),
+            Self::ContractCreate(transaction) => transaction.required_signers(),
+            Self::ContractUpdate(transaction) => transaction.required_signers(),
+            Self::ContractDelete(transaction) => transaction.required_signers(),
+            Self::ContractExecute(transaction) => transaction.required_signers(),
+            Self::FileAppend(transaction) => transaction.required_signers(),
+            Self::FileCreate(transaction) => transaction.required_signers(),
+            Self::FileUpdate(transaction) => transaction.required_signers(),
+            Self::FileDelete(transaction) => transaction.required_signers(),
+            Self::Prng(transaction) => transaction.required_signers(),
+            Self::TokenAssociate(transaction) => transaction.required_signers(),
+            Self::TokenBurn(transaction) => transaction.required_signers(),
+            Self::TokenCreate(transaction) => transaction.required_signers(),
+            Self::TokenDelete(transaction) => transaction.required_signers(),
+            Self::TokenDissociate(transaction) => transaction.required_signers(),
+            Self::TokenFeeScheduleUpdate(transaction) => transaction.required_signers(),
+            Self::TokenFreeze(transaction) => transaction.required_signers(),
+            Self::TokenGrantKyc(transaction) => transaction.required_signers(),
+            Self::TokenMint(transaction) => transaction.required_signers(),
+            Self::TokenPause(transaction) => transaction.required_signers(),
+            Self::TokenRevokeKyc(transaction) => transaction.required_signers(),
+            Self::TokenUnfreeze(transaction) => transaction.required_signers(),
+            Self::TokenUnpause(transaction) => transaction.required_signers(),
+            Self::TokenUpdate(transaction) => transaction.required_signers(),
+            Self::TokenWipe(transaction) => transaction.required_signers(),
+            Self::TopicCreate(transaction) => transaction.required_signers(),
+            Self::TopicUpdate(transaction) => transaction.required_signers(),
+            Self::TopicDelete(transaction) => transaction.required_signers(),
+            Self::TopicMessageSubmit(transaction) => transaction.required_signers(),
+            Self::SystemDelete(transaction) => transaction.required_signers(),
+            Self::SystemUndelete(transaction) => transaction.required_signers(),
+            Self::Freeze(transaction) => transaction.required_signers(),
+            Self::ScheduleCreate(transaction) => transaction.required_signers(),
+            Self::ScheduleSign(transaction) => transaction.required_signers(),
+            Self::ScheduleDelete(transaction) => transaction.required_signers(),
+            Self::Ethereum(transaction) => transaction.required_signers(),
+            Self::TokenUpdateNfts(transaction) => transaction.required_signers(),
+            Self::NodeCreate(transaction) => transaction.required_signers(),
+            Self::NodeUpdate(transaction) => transaction.required_signers(),
+            Self::NodeDelete(transaction) => transaction.required_signers(),
+            Self::TokenReject(transaction) => transaction.required_signers(),
+            Self::TokenAirdrop(transaction) => transaction.required_signers(),
+            Self::TokenClaimAirdrop(transaction) => transaction.required_signers(),
+            Self::TokenCancelAirdrop(transaction) => transaction.required_signers(),
+        }
+    }
+
     fn maybe_chunk_data(&self) -> Option<&super::ChunkData> {
         match self {
             Self::AccountCreate(it) => it.maybe_chunk_data(),
@@ -864,6 +919,7 @@ impl AnyTransaction {
                     .into_iter()
                     .map(CustomFeeLimit::from_protobuf)
                     .collect::<Result<Vec<_>, _>>()?,
+                request_observer: None,
             },
             signers: Vec::new(),
             sources: None,
@@ -1124,6 +1180,7 @@ macro_rules! impl_cast_any {
                             is_frozen: transaction.body.is_frozen,
                             regenerate_transaction_id: transaction.body.regenerate_transaction_id,
                             custom_fee_limits: transaction.body.custom_fee_limits,
+                            request_observer: transaction.body.request_observer,
                         },
                         signers: transaction.signers,
                         sources: transaction.sources,
@@ -0,0 +1,474 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use hedera_proto::sdk;
+use prost::Message;
+use time::OffsetDateTime;
+
+use super::{
+    TransactionExecute,
+    DEFAULT_TRANSACTION_VALID_DURATION,
+};
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::{
+    AnyTransaction,
+    Client,
+    Error,
+    Key,
+    LedgerId,
+    PrivateKey,
+    Transaction,
+};
+
+/// Transactions bundled up to be signed somewhere else, such as on an air-gapped machine.
+///
+/// Along with the transactions themselves, a bundle carries what the person signing them needs to know:
+/// a description of every transaction, the window in which it can be submitted, the keys that have to sign it,
+/// and the ledger it's for.
+///
+/// The usual flow is:
+/// 1. Online, [`add`](Self::add) frozen transactions to a bundle and export it with [`to_bytes`](Self::to_bytes).
+/// 2. Offline, import it with [`from_bytes`](Self::from_bytes), [`sign`](Self::sign) it, and export it again.
+/// 3. Online, import the signed copies, [`merge`](Self::merge) them together and execute the transactions.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionBundle {
+    ledger_id: Option<LedgerId>,
+    entries: Vec<TransactionBundleEntry>,
+}
+
+/// A transaction in a [`TransactionBundle`].
+#[derive(Debug, Clone)]
+pub struct TransactionBundleEntry {
+    description: String,
+    transaction: AnyTransaction,
+    valid_start: OffsetDateTime,
+    valid_until: OffsetDateTime,
+    required_keys: Vec<Key>,
+}
+
+impl TransactionBundleEntry {
+    fn new(
+        description: String,
+        transaction: AnyTransaction,
+        required_keys: Vec<Key>,
+    ) -> crate::Result<Self> {
+        let valid_start = transaction
+            .get_transaction_id()
+            .ok_or(Error::NoPayerAccountOrTransactionId)?
+            .valid_start;
+
+        let valid_until = valid_start
+            + transaction
+                .get_transaction_valid_duration()
+                .unwrap_or(DEFAULT_TRANSACTION_VALID_DURATION);
+
+        Ok(Self { description, transaction, valid_start, valid_until, required_keys })
+    }
+
+    /// Returns the description of the transaction.
+    #[must_use]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the transaction.
+    #[must_use]
+    pub fn transaction(&self) -> &AnyTransaction {
+        &self.transaction
+    }
+
+    /// Returns the start of the window in which the transaction can be submitted.
+    ///
+    /// For a chunked transaction, this is the window of its first chunk.
+    #[must_use]
+    pub fn valid_start(&self) -> OffsetDateTime {
+        self.valid_start
+    }
+
+    /// Returns the end of the window in which the transaction can be submitted.
+    #[must_use]
+    pub fn valid_until(&self) -> OffsetDateTime {
+        self.valid_until
+    }
+
+    /// Returns the keys that have to sign the transaction.
+    #[must_use]
+    pub fn required_keys(&self) -> &[Key] {
+        &self.required_keys
+    }
+}
+
+impl TransactionBundle {
+    /// The version of the format written by [`to_bytes`](Self::to_bytes).
+    const VERSION: u32 = 1;
+
+    /// Create a new, empty bundle.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the ledger ID of the network the transactions are for.
+    #[must_use]
+    pub fn get_ledger_id(&self) -> Option<&LedgerId> {
+        self.ledger_id.as_ref()
+    }
+
+    /// Sets the ledger ID of the network the transactions are for.
+    pub fn ledger_id(&mut self, ledger_id: LedgerId) -> &mut Self {
+        self.ledger_id = Some(ledger_id);
+        self
+    }
+
+    /// Returns the transactions in this bundle, in the order they were added.
+    #[must_use]
+    pub fn entries(&self) -> &[TransactionBundleEntry] {
+        &self.entries
+    }
+
+    /// Adds a copy of `transaction` to this bundle.
+    ///
+    /// `required_keys` are the keys that have to sign the transaction.
+    /// They're taken as they are, nothing checks them against the transaction,
+    /// use [`add_resolved`](Self::add_resolved) to look them up instead.
+    ///
+    /// # Errors
+    /// - If [`to_bytes`](Transaction::to_bytes) fails for `transaction`.
+    ///
+    /// # Panics
+    /// - If `transaction` isn't frozen.
+    pub fn add<D: TransactionExecute>(
+        &mut self,
+        description: impl Into<String>,
+        transaction: &Transaction<D>,
+        required_keys: impl IntoIterator<Item = Key>,
+    ) -> crate::Result<&mut Self> {
+        let transaction = AnyTransaction::from_bytes(&transaction.to_bytes()?)?;
+
+        self.entries.push(TransactionBundleEntry::new(
+            description.into(),
+            transaction,
+            required_keys.into_iter().collect(),
+        )?);
+
+        Ok(self)
+    }
+
+    /// Adds a copy of `transaction` to this bundle, along with the keys that have to sign it,
+    /// which are looked up with [`Transaction::resolve_required_signers`].
+    ///
+    /// Signers that refer to a key that isn't set are left out.
+    /// If this bundle doesn't have a ledger ID yet, it gets the ledger ID of `client`.
+    ///
+    /// # Errors
+    /// - If looking up any of the keys fails.
+    /// - If [`to_bytes`](Transaction::to_bytes) fails for `transaction`.
+    ///
+    /// # Panics
+    /// - If `transaction` isn't frozen.
+    pub async fn add_resolved<D: TransactionExecute>(
+        &mut self,
+        client: &Client,
+        description: impl Into<String>,
+        transaction: &Transaction<D>,
+    ) -> crate::Result<&mut Self> {
+        let description = description.into();
+
+        let mut required_keys = Vec::new();
+
+        for (_, key) in transaction.resolve_required_signers(client).await? {
+            if let Some(key) = key.filter(|it| !required_keys.contains(it)) {
+                required_keys.push(key);
+            }
+        }
+
+        self.add(description, transaction, required_keys)?;
+
+        if self.ledger_id.is_none() {
+            self.ledger_id = client.ledger_id_internal().as_deref().cloned();
+        }
+
+        Ok(self)
+    }
+
+    /// Returns an error if any of the transactions can no longer be submitted.
+    ///
+    /// # Errors
+    /// - [`Error::TransactionBundleExpired`] for the first transaction whose valid window has passed.
+    pub fn check_not_expired(&self) -> crate::Result<()> {
+        let now = OffsetDateTime::now_utc();
+
+        match self.entries.iter().find(|it| it.valid_until < now) {
+            Some(entry) => Err(Error::TransactionBundleExpired {
+                description: entry.description.clone(),
+                valid_until: entry.valid_until,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Signs every transaction in this bundle with `private_key`.
+    ///
+    /// Nothing is signed if any of the transactions has expired.
+    ///
+    /// # Errors
+    /// - [`Error::TransactionBundleExpired`] if any of the transactions can no longer be submitted.
+    pub fn sign(&mut self, private_key: PrivateKey) -> crate::Result<&mut Self> {
+        self.check_not_expired()?;
+
+        for entry in &mut self.entries {
+            entry.transaction.sign(private_key.clone());
+        }
+
+        Ok(self)
+    }
+
+    /// Adds the signatures from `other`, a copy of this bundle that was signed separately, to `self`.
+    ///
+    /// Nothing is merged if any of the transactions doesn't match.
+    ///
+    /// # Errors
+    /// - [`Error::TransactionMerge`] if `other` has different transactions or is for a different ledger,
+    ///   if a transaction has a different description or different required keys,
+    ///   or if the transactions can't be merged, see [`Transaction::merge`].
+    pub fn merge(&mut self, other: &Self) -> crate::Result<&mut Self> {
+        if self.ledger_id != other.ledger_id || self.entries.len() != other.entries.len() {
+            return Err(Error::transaction_merge(
+                "the bundles have different transactions or ledger IDs",
+            ));
+        }
+
+        // whoever signed `other` reviewed these, so a copy that says something else wasn't signed for the same thing.
+        for (entry, other) in self.entries.iter().zip(&other.entries) {
+            if entry.description != other.description || entry.required_keys != other.required_keys
+            {
+                return Err(Error::transaction_merge(format!(
+                    "the bundles describe the transaction `{}` differently",
+                    entry.description
+                )));
+            }
+        }
+
+        for (entry, other) in self.entries.iter_mut().zip(&other.entries) {
+            entry.transaction.merge(&other.transaction)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the transactions in this bundle, in the order they were added.
+    #[must_use]
+    pub fn into_transactions(self) -> Vec<AnyTransaction> {
+        self.entries.into_iter().map(|it| it.transaction).collect()
+    }
+
+    /// Convert `self` to protobuf encoded bytes.
+    ///
+    /// # Errors
+    /// - [`Error::SignerNotReady`] if a signer can't sign synchronously.
+    pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let transaction = sdk::TransactionList::decode(&*entry.transaction.to_bytes()?)
+                    .map_err(Error::from_protobuf)?;
+
+                Ok(sdk::TransactionBundleEntry {
+                    description: entry.description.clone(),
+                    transaction: Some(transaction),
+                    valid_start: Some(entry.valid_start.to_protobuf()),
+                    valid_until: Some(entry.valid_until.to_protobuf()),
+                    required_keys: entry.required_keys.to_protobuf(),
+                })
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(sdk::TransactionBundle {
+            version: Self::VERSION,
+            ledger_id: self.ledger_id.as_ref().map(LedgerId::to_bytes).unwrap_or_default(),
+            entries,
+        }
+        .encode_to_vec())
+    }
+
+    /// Parse a bundle from the bytes produced by [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if the bytes aren't a valid bundle, the bundle is of an unknown version,
+    ///   or the valid window of a transaction doesn't match the transaction itself.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let pb = sdk::TransactionBundle::decode(bytes).map_err(Error::from_protobuf)?;
+
+        if pb.version != Self::VERSION {
+            return Err(Error::from_protobuf(format!(
+                "unsupported transaction bundle version {}",
+                pb.version
+            )));
+        }
+
+        let ledger_id = (!pb.ledger_id.is_empty()).then(|| LedgerId::from_bytes(pb.ledger_id));
+
+        let entries = pb
+            .entries
+            .into_iter()
+            .map(|pb| {
+                let transaction =
+                    AnyTransaction::from_bytes(&pb_getf!(pb, transaction)?.encode_to_vec())?;
+
+                let entry = TransactionBundleEntry::new(
+                    pb.description,
+                    transaction,
+                    Vec::<Key>::from_protobuf(pb.required_keys)?,
+                )?;
+
+                // the window is only there for people to read, so it has to agree with the transaction.
+                let valid_start = OffsetDateTime::from(pb_getf!(pb, valid_start)?);
+                let valid_until = OffsetDateTime::from(pb_getf!(pb, valid_until)?);
+
+                if (valid_start, valid_until) != (entry.valid_start, entry.valid_until) {
+                    return Err(Error::from_protobuf(format!(
+                        "the valid window of `{}` doesn't match its transaction",
+                        entry.description
+                    )));
+                }
+
+                Ok(entry)
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self { ledger_id, entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use super::TransactionBundle;
+    use crate::{
+        AccountId,
+        Error,
+        Hbar,
+        Key,
+        LedgerId,
+        PrivateKey,
+        TransactionId,
+        TransferTransaction,
+    };
+
+    fn transfer(valid_start: OffsetDateTime) -> crate::Result<TransferTransaction> {
+        let transaction_id = TransactionId {
+            account_id: AccountId::new(0, 0, 101),
+            valid_start,
+            nonce: None,
+            scheduled: false,
+        };
+
+        let mut transaction = TransferTransaction::new();
+
+        transaction
+            .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(1))
+            .hbar_transfer(AccountId::new(0, 0, 101), Hbar::new(-1))
+            .transaction_id(transaction_id)
+            .node_account_ids([AccountId::new(0, 0, 6), AccountId::new(0, 0, 7)])
+            .freeze()?;
+
+        Ok(transaction)
+    }
+
+    #[test]
+    fn sign_offline_and_merge() -> crate::Result<()> {
+        let (key1, key2) = (PrivateKey::generate_ed25519(), PrivateKey::generate_ecdsa());
+        let now = OffsetDateTime::now_utc();
+
+        let mut bundle = TransactionBundle::new();
+        bundle
+            .ledger_id(LedgerId::testnet())
+            .add("pay 2", &transfer(now)?, [Key::from(key1.public_key())])?
+            .add("pay 2 again", &transfer(now + Duration::seconds(1))?, [])?;
+
+        let bytes = bundle.to_bytes()?;
+
+        let signed1 = TransactionBundle::from_bytes(&bytes)?.sign(key1.clone())?.to_bytes()?;
+        let signed2 = TransactionBundle::from_bytes(&bytes)?.sign(key2.clone())?.to_bytes()?;
+
+        let mut bundle = TransactionBundle::from_bytes(&signed1)?;
+        bundle.merge(&TransactionBundle::from_bytes(&signed2)?)?;
+
+        assert_eq!(bundle.get_ledger_id(), Some(&LedgerId::testnet()));
+        assert_eq!(bundle.entries()[0].description(), "pay 2");
+        assert_eq!(bundle.entries()[0].required_keys(), [Key::from(key1.public_key())]);
+        assert_eq!(bundle.entries()[1].valid_start(), now + Duration::seconds(1));
+        assert_eq!(bundle.entries()[1].valid_until(), now + Duration::seconds(121));
+
+        for mut transaction in bundle.into_transactions() {
+            key1.public_key().verify_transaction(&mut transaction)?;
+            key2.public_key().verify_transaction(&mut transaction)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_different_annotations() -> crate::Result<()> {
+        let key = PrivateKey::generate_ed25519();
+        let now = OffsetDateTime::now_utc();
+
+        let mut bundle = TransactionBundle::new();
+        bundle.add("pay 2", &transfer(now)?, [Key::from(key.public_key())])?;
+
+        let mut described = TransactionBundle::new();
+        described.add("pay 3", &transfer(now)?, [Key::from(key.public_key())])?;
+
+        let mut keyed = TransactionBundle::new();
+        keyed.add("pay 2", &transfer(now)?, [])?;
+
+        for other in [described, keyed] {
+            assert_matches!(bundle.merge(&other), Err(Error::TransactionMerge(_)));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn expired() -> crate::Result<()> {
+        let key = PrivateKey::generate_ed25519();
+        let now = OffsetDateTime::now_utc();
+
+        let mut bundle = TransactionBundle::new();
+        bundle.add("fresh", &transfer(now)?, [])?.add(
+            "stale",
+            &transfer(now - Duration::hours(1))?,
+            [],
+        )?;
+
+        let mut bundle = TransactionBundle::from_bytes(&bundle.to_bytes()?)?;
+
+        assert_matches!(
+            bundle.sign(key.clone()),
+            Err(Error::TransactionBundleExpired { description, .. }) if description == "stale"
+        );
+
+        // nothing was signed, not even the transaction that's still valid.
+        assert!(key.public_key().verify_transaction(&mut bundle.into_transactions()[0]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_version() {
+        let bytes = hedera_proto::sdk::TransactionBundle { version: 2, ..Default::default() };
+
+        assert_matches!(
+            TransactionBundle::from_bytes(&prost::Message::encode_to_vec(&bytes)),
+            Err(Error::FromProtobuf(_))
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use std::cmp;
 use std::num::NonZeroUsize;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
 
@@ -11,11 +12,14 @@ use super::{
 use crate::entity_id::ValidateChecksums;
 use crate::execute::Execute;
 use crate::ledger_id::RefLedgerId;
+use crate::request_observer::AnyRequestObserver;
 use crate::{
+    client,
     AccountId,
     BoxGrpcFuture,
     Error,
     Hbar,
+    RequestType,
     Transaction,
     TransactionHash,
     TransactionId,
@@ -155,22 +159,34 @@ where
         self.transaction.regenerate_transaction_id()
     }
 
+    fn observer(&self) -> Option<&AnyRequestObserver> {
+        self.transaction.body.request_observer.as_ref()
+    }
+
+    fn request_type(request: &Self::GrpcRequest) -> Option<RequestType> {
+        client::transaction_request_type(request)
+    }
+
     fn operator_account_id(&self) -> Option<&AccountId> {
         self.transaction.operator_account_id()
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         assert!(self.transaction.is_frozen());
 
-        Ok(self.transaction.make_request_inner(&ChunkInfo::initial(
-            self.total_chunks,
-            *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-            node_account_id,
-        )))
+        Box::pin(async move {
+            let chunk_info = ChunkInfo::initial(
+                self.total_chunks,
+                *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
+                node_account_id,
+            );
+
+            self.transaction.make_request_inner(&chunk_info).await
+        })
     }
 
     fn execute(
@@ -261,20 +277,33 @@ where
         self.transaction.regenerate_transaction_id()
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn observer(&self) -> Option<&AnyRequestObserver> {
+        self.transaction.body.request_observer.as_ref()
+    }
+
+    fn request_type(request: &Self::GrpcRequest) -> Option<RequestType> {
+        client::transaction_request_type(request)
+    }
+
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         assert!(self.transaction.is_frozen());
 
-        Ok(self.transaction.make_request_inner(&ChunkInfo {
-            total: self.total_chunks,
-            current: self.current_chunk,
-            initial_transaction_id: self.initial_transaction_id,
-            node_account_id,
-            current_transaction_id: *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-        }))
+        Box::pin(async move {
+            let chunk_info = ChunkInfo {
+                total: self.total_chunks,
+                current: self.current_chunk,
+                initial_transaction_id: self.initial_transaction_id,
+                node_account_id,
+                current_transaction_id: *transaction_id
+                    .ok_or(Error::NoPayerAccountOrTransactionId)?,
+            };
+
+            self.transaction.make_request_inner(&chunk_info).await
+        })
     }
 
     fn execute(
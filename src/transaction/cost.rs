@@ -38,6 +38,7 @@ impl<D: Clone> CostTransaction<D> {
                 is_frozen: transaction.body.is_frozen,
                 regenerate_transaction_id: transaction.body.regenerate_transaction_id,
                 custom_fee_limits: transaction.body.custom_fee_limits,
+                request_observer: transaction.body.request_observer,
             },
             // cost transactions have no signers
             signers: Vec::new(),
@@ -3,6 +3,7 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use prost::Message;
 use tonic::transport::Channel;
@@ -11,20 +12,26 @@ use super::chunked::ChunkInfo;
 use super::source::SourceChunk;
 use super::{
     ChunkData,
+    RequiredSigner,
     TransactionSources,
 };
 use crate::execute::Execute;
 use crate::ledger_id::RefLedgerId;
+use crate::protobuf::FromProtobuf;
+use crate::request_observer::AnyRequestObserver;
+use crate::signer::AnySigner;
 use crate::transaction::any::AnyTransactionData;
 use crate::transaction::protobuf::ToTransactionDataProtobuf;
 use crate::transaction::DEFAULT_TRANSACTION_VALID_DURATION;
 use crate::{
+    client,
     AccountId,
     BoxGrpcFuture,
     Client,
     Error,
     Hbar,
     PublicKey,
+    RequestType,
     ToProtobuf,
     Transaction,
     TransactionHash,
@@ -35,8 +42,8 @@ use crate::{
 
 #[derive(Debug)]
 pub(super) struct SignaturePair {
-    signature: Vec<u8>,
-    public: PublicKey,
+    pub(super) signature: Vec<u8>,
+    pub(super) public: PublicKey,
 }
 
 impl SignaturePair {
@@ -57,6 +64,23 @@ impl SignaturePair {
     }
 }
 
+impl FromProtobuf<services::SignaturePair> for SignaturePair {
+    fn from_protobuf(pb: services::SignaturePair) -> crate::Result<Self> {
+        // this only understands the signatures this SDK produces, which always have the whole public key as the prefix.
+        let (public, signature) = match pb_getf!(pb, signature)? {
+            services::signature_pair::Signature::Ed25519(it) => {
+                (PublicKey::from_bytes_ed25519(&pb.pub_key_prefix), it)
+            }
+            services::signature_pair::Signature::EcdsaSecp256k1(it) => {
+                (PublicKey::from_bytes_ecdsa(&pb.pub_key_prefix), it)
+            }
+            _ => return Err(Error::from_protobuf("unsupported signature kind")),
+        };
+
+        Ok(Self { signature, public: public.map_err(Error::from_protobuf)? })
+    }
+}
+
 impl From<(PublicKey, Vec<u8>)> for SignaturePair {
     fn from((public, signature): (PublicKey, Vec<u8>)) -> Self {
         Self { signature, public }
@@ -67,32 +91,37 @@ impl<D> Transaction<D>
 where
     D: TransactionData + ToTransactionDataProtobuf,
 {
-    pub(crate) fn make_request_inner(
+    pub(crate) async fn make_request_inner(
         &self,
         chunk_info: &ChunkInfo,
-    ) -> (services::Transaction, TransactionHash) {
+    ) -> crate::Result<(services::Transaction, TransactionHash)> {
         assert!(self.is_frozen());
 
         let transaction_body = self.to_transaction_body_protobuf(chunk_info);
 
         let body_bytes = transaction_body.encode_to_vec();
 
-        let mut signatures = Vec::with_capacity(1 + self.signers.len());
+        let mut signers: Vec<&AnySigner> = Vec::with_capacity(1 + self.signers.len());
 
         if let Some(operator) = &self.body.operator {
-            let operator_signature = operator.sign(&body_bytes);
-
-            signatures.push(SignaturePair::from(operator_signature).into_protobuf());
+            signers.push(&operator.signer);
         }
 
         for signer in &self.signers {
-            let public_key = signer.public_key().to_bytes();
-            if !signatures.iter().any(|it| public_key.starts_with(&it.pub_key_prefix)) {
-                let signature = signer.sign(&body_bytes);
-                signatures.push(SignaturePair::from(signature).into_protobuf());
+            let public_key = signer.public_key();
+            if !signers.iter().any(|it| it.public_key() == public_key) {
+                signers.push(signer);
             }
         }
 
+        // remote signers can be slow, so sign with all of them at once.
+        let signatures =
+            futures_util::future::try_join_all(signers.iter().map(|it| it.sign(&body_bytes)))
+                .await?
+                .into_iter()
+                .map(|it| SignaturePair::from(it).into_protobuf())
+                .collect();
+
         let signed_transaction = services::SignedTransaction {
             body_bytes,
             sig_map: Some(services::SignatureMap { sig_pair: signatures }),
@@ -105,7 +134,7 @@ where
         let transaction =
             services::Transaction { signed_transaction_bytes, ..services::Transaction::default() };
 
-        (transaction, transaction_hash)
+        Ok((transaction, transaction_hash))
     }
 }
 
@@ -135,6 +164,15 @@ pub trait TransactionData: Clone + Into<AnyTransactionData> {
     fn wait_for_receipt(&self) -> bool {
         false
     }
+
+    /// Returns the entities whose keys have to sign this transaction, other than the payer.
+    ///
+    /// Types that need nothing beyond the payer keep the default,
+    /// as do types whose other signers can't be told from the transaction,
+    /// see [`Transaction::required_signers`](crate::Transaction::required_signers).
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        Vec::new()
+    }
 }
 
 pub trait TransactionExecute:
@@ -179,17 +217,29 @@ where
         self.body.regenerate_transaction_id
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn observer(&self) -> Option<&AnyRequestObserver> {
+        self.body.request_observer.as_ref()
+    }
+
+    fn request_type(request: &Self::GrpcRequest) -> Option<RequestType> {
+        client::transaction_request_type(request)
+    }
+
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         assert!(self.is_frozen());
 
-        Ok(self.make_request_inner(&ChunkInfo::single(
-            *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-            node_account_id,
-        )))
+        Box::pin(async move {
+            let chunk_info = ChunkInfo::single(
+                *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
+                node_account_id,
+            );
+
+            self.make_request_inner(&chunk_info).await
+        })
     }
 
     fn execute(
@@ -293,11 +343,14 @@ pub(crate) struct SourceTransaction<'a, D> {
 }
 
 impl<'a, D> SourceTransaction<'a, D> {
-    pub(crate) fn new(transaction: &'a Transaction<D>, sources: &'a TransactionSources) -> Self {
+    pub(crate) async fn new(
+        transaction: &'a Transaction<D>,
+        sources: &'a TransactionSources,
+    ) -> crate::Result<Self> {
         // fixme: be way more lazy.
-        let sources = sources.sign_with(&transaction.signers);
+        let sources = sources.sign_with(&transaction.signers).await?;
 
-        Self { inner: transaction, sources }
+        Ok(Self { inner: transaction, sources })
     }
 
     pub(crate) async fn execute(
@@ -389,15 +442,26 @@ impl<'a, D: TransactionExecute> Execute for SourceTransactionExecuteView<'a, D>
         Some(false)
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn observer(&self) -> Option<&AnyRequestObserver> {
+        self.transaction.body.request_observer.as_ref()
+    }
+
+    fn request_type(request: &Self::GrpcRequest) -> Option<RequestType> {
+        client::transaction_request_type(request)
+    }
+
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         debug_assert_eq!(transaction_id, self.transaction_id().as_ref());
 
         let index = *self.indecies_by_node_id.get(&node_account_id).unwrap();
-        Ok((self.chunk.transactions()[index].clone(), self.chunk.transaction_hashes()[index]))
+        let request =
+            (self.chunk.transactions()[index].clone(), self.chunk.transaction_hashes()[index]);
+
+        Box::pin(std::future::ready(Ok(request)))
     }
 
     fn execute(
@@ -17,16 +17,24 @@ use triomphe::Arc;
 use crate::custom_fee_limit::CustomFeeLimit;
 use crate::downcast::DowncastOwned;
 use crate::execute::execute;
-use crate::signer::AnySigner;
+use crate::protobuf::FromProtobuf;
+use crate::request_observer::AnyRequestObserver;
+use crate::signer::{
+    sign_now,
+    AnySigner,
+};
 use crate::{
     AccountId,
     Client,
     Error,
     Hbar,
+    Key,
     Operator,
     PrivateKey,
     PublicKey,
+    RequestObserver,
     ScheduleCreateTransaction,
+    Signer,
     TransactionHash,
     TransactionId,
     TransactionResponse,
@@ -34,16 +42,22 @@ use crate::{
 };
 
 mod any;
+mod bundle;
 mod chunked;
 mod cost;
 mod execute;
 mod protobuf;
+mod required_signer;
 mod source;
 #[cfg(test)]
 mod tests;
 
 pub use any::AnyTransaction;
 pub(crate) use any::AnyTransactionData;
+pub use bundle::{
+    TransactionBundle,
+    TransactionBundleEntry,
+};
 pub(crate) use chunked::{
     ChunkData,
     ChunkInfo,
@@ -59,6 +73,11 @@ pub(crate) use protobuf::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
 };
+pub use required_signer::{
+    RequiredSigner,
+    TokenKeyKind,
+    TopicKeyKind,
+};
 pub(crate) use source::TransactionSources;
 
 const DEFAULT_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(120);
@@ -97,6 +116,9 @@ pub(crate) struct TransactionBody<D> {
     /// If left empty, the user is willing to pay any custom fee.
     /// If used with a transaction type that does not support custom fee limits, the transaction will fail.
     pub(crate) custom_fee_limits: Vec<CustomFeeLimit>,
+
+    /// Observes every attempt at executing this transaction, not part of the transaction itself.
+    pub(crate) request_observer: Option<AnyRequestObserver>,
 }
 
 impl<D> Default for Transaction<D>
@@ -116,6 +138,7 @@ where
                 is_frozen: false,
                 regenerate_transaction_id: None,
                 custom_fee_limits: Vec::new(),
+                request_observer: None,
             },
             signers: Vec::new(),
             sources: None,
@@ -163,8 +186,8 @@ impl<D> Transaction<D> {
         self.sources.as_ref()
     }
 
-    fn signed_sources(&self) -> Option<Cow<'_, TransactionSources>> {
-        self.sources().map(|it| it.sign_with(&self.signers))
+    fn signed_sources(&self) -> crate::Result<Option<Cow<'_, TransactionSources>>> {
+        self.sources().map(|it| sign_now(it.sign_with(&self.signers))).transpose()
     }
 
     /// # Panics
@@ -323,6 +346,24 @@ impl<D> Transaction<D> {
         self.sign_signer(AnySigner::arbitrary(Box::new(public_key), signer))
     }
 
+    /// Sign the transaction with a [`Signer`] (for instance, one backed by an HSM or a remote signing service).
+    ///
+    /// Signing happens when the transaction is executed.
+    /// Synchronous functions (such as [`to_bytes`](Self::to_bytes)) fail with [`Error::SignerNotReady`]
+    /// if the signer can't sign immediately.
+    pub fn sign_with_signer<S: Signer>(&mut self, signer: S) -> &mut Self {
+        self.sign_signer(AnySigner::signer(signer))
+    }
+
+    /// Sets an observer that's called for every attempt at executing this transaction.
+    ///
+    /// This is in addition to the observer set on the client (if any), see [`RequestObserver`] for details.
+    /// The observer isn't part of the transaction, so unlike most setters, this can be called after freezing.
+    pub fn request_observer<O: RequestObserver>(&mut self, observer: O) -> &mut Self {
+        self.body.request_observer = Some(AnyRequestObserver::new(observer));
+        self
+    }
+
     pub(crate) fn sign_signer(&mut self, signer: AnySigner) -> &mut Self {
         // We're _supposed_ to require frozen here, but really there's no reason I can think of to do that.
 
@@ -416,6 +457,22 @@ impl<D: ValidateChecksums> Transaction<D> {
         }
         let client: Option<&Client> = client.into();
 
+        self.freeze_with_operator(client, client.and_then(Client::select_operator))
+    }
+
+    /// Like [`freeze_with`](Self::freeze_with), but paid for by `operator` rather than one picked by the client.
+    ///
+    /// With an operator pool, picking an operator moves the pool on to the next payer,
+    /// so a caller that already picked one uses this to avoid picking again.
+    pub(crate) fn freeze_with_operator(
+        &mut self,
+        client: Option<&Client>,
+        operator: Option<Arc<Operator>>,
+    ) -> crate::Result<&mut Self> {
+        if self.is_frozen() {
+            return Ok(self);
+        }
+
         let node_account_ids = match &self.body.node_account_ids {
             // the clone here is the lesser of two evils.
             Some(it) => {
@@ -424,12 +481,9 @@ impl<D: ValidateChecksums> Transaction<D> {
             }
             #[allow(clippy::missing_panics_doc)]
             None => {
-                let nodes = client
-                    .ok_or(Error::FreezeUnsetNodeAccountIds)?
-                    .net()
-                    .0
-                    .load()
-                    .random_node_ids();
+                let client = client.ok_or(Error::FreezeUnsetNodeAccountIds)?;
+                let nodes =
+                    client.net().0.load().select_node_ids(&client.load_node_selection_strategy());
                 assert!(!nodes.is_empty(), "BUG: Client didn't give any nodes (all unhealthy)");
 
                 nodes
@@ -445,8 +499,6 @@ impl<D: ValidateChecksums> Transaction<D> {
 
         let custom_fee_limits = self.body.custom_fee_limits.clone();
 
-        let operator = client.and_then(Client::full_load_operator);
-
         // note: yes, there's an `Some(opt.unwrap())`, this is INTENTIONAL.
         self.body.node_account_ids = Some(node_account_ids);
         self.body.max_transaction_fee = max_transaction_fee;
@@ -470,15 +522,22 @@ impl<D: ValidateChecksums> Transaction<D> {
 
     /// Sign the transaction with the `client`'s operator.
     ///
+    /// A transaction that's already frozen with a payer from the client is signed by that same payer,
+    /// rather than by whichever payer the client's operator pool would pick next.
+    ///
     /// # Errors
     /// - If [`freeze_with`](Self::freeze_with) would error for this transaction.
     ///
     /// # Panics
     /// If `client` has no operator.
     pub fn sign_with_operator(&mut self, client: &Client) -> crate::Result<&mut Self> {
-        let Some(op) = client.full_load_operator() else { panic!("Client had no operator") };
+        let op = match self.body.operator.as_ref().filter(|_| self.is_frozen()) {
+            // the transaction ID was generated for this payer, so it's the one that has to sign.
+            Some(op) => Arc::clone(op),
+            None => client.select_operator().expect("Client had no operator"),
+        };
 
-        self.freeze_with(client)?;
+        self.freeze_with_operator(Some(client), Some(Arc::clone(&op)))?;
 
         self.sign_signer(op.signer.clone());
 
@@ -528,7 +587,7 @@ impl<D: TransactionExecute> Transaction<D> {
                     node_account_id,
                 };
 
-                transaction_list.push(self.make_request_inner(&chunk_info).0);
+                transaction_list.push(sign_now(self.make_request_inner(&chunk_info))?.0);
             }
         }
 
@@ -538,7 +597,7 @@ impl<D: TransactionExecute> Transaction<D> {
     pub(crate) fn make_sources(&self) -> crate::Result<Cow<'_, TransactionSources>> {
         assert!(self.is_frozen());
 
-        if let Some(sources) = self.signed_sources() {
+        if let Some(sources) = self.signed_sources()? {
             return Ok(sources);
         }
 
@@ -556,13 +615,13 @@ impl<D: TransactionExecute> Transaction<D> {
         assert!(self.is_frozen(), "Transaction must be frozen to call `to_bytes`");
 
         let transaction_list = self
-            .signed_sources()
+            .signed_sources()?
             .map_or_else(|| self.make_transaction_list(), |it| Ok(it.transactions().to_vec()))?;
 
         Ok(hedera_proto::sdk::TransactionList { transaction_list }.encode_to_vec())
     }
 
-    pub(crate) fn add_signature_signer(&mut self, signer: &AnySigner) -> Vec<u8> {
+    pub(crate) fn add_signature_signer(&mut self, signer: &AnySigner) -> crate::Result<Vec<u8>> {
         assert!(self.is_frozen());
 
         // note: the following pair of cheecks are for more detailed panic messages
@@ -582,34 +641,79 @@ impl<D: TransactionExecute> Transaction<D> {
             );
         }
 
-        let sources = self.make_sources().unwrap();
+        // fails if one of the signers we already have can't sign synchronously.
+        let sources = self.make_sources()?;
 
         // this is the only check that is for correctness rather than debugability.
         assert!(sources.transactions().len() == 1);
 
-        let sources = sources.sign_with(std::slice::from_ref(signer));
+        let sources = sign_now(sources.sign_with(std::slice::from_ref(signer)))?;
 
         // hack: I don't care about perf here.
-        let ret = signer.sign(&sources.signed_transactions()[0].body_bytes);
+        let ret = sign_now(signer.sign(&sources.signed_transactions()[0].body_bytes))?;
 
         // if we have a `Cow::Borrowed` that'd mean there was no modification
         if let Cow::Owned(sources) = sources {
             self.sources = Some(sources);
         }
 
-        ret.1
+        Ok(ret.1)
+    }
+
+    /// Adds the signatures from `other` to `self`.
+    ///
+    /// `other` has to be a copy of this same transaction, for instance one that went through
+    /// [`to_bytes`](Self::to_bytes) and [`from_bytes`](AnyTransaction::from_bytes) and was then signed by someone else.
+    ///
+    /// This forcibly disables transaction ID regeneration.
+    ///
+    /// # Errors
+    /// - [`Error::TransactionMerge`] if the transactions have different bodies (for any node or chunk),
+    ///   or if they have different signatures for the same key.
+    /// - [`Error::SignerNotReady`] if a signer of either transaction can't sign synchronously.
+    ///
+    /// # Panics
+    /// - If either transaction isn't frozen.
+    pub fn merge(&mut self, other: &Self) -> crate::Result<&mut Self> {
+        assert!(
+            self.is_frozen() && other.is_frozen(),
+            "Transactions must be frozen before calling `merge`"
+        );
+
+        let sources = self.make_sources()?.merge(&*other.make_sources()?)?;
+
+        self.sources = Some(sources);
+
+        Ok(self)
     }
 
-    // todo: should this return `Result<&mut Self>`?
     /// Adds a signature directly to `self`.
     ///
     /// Only use this as a last resort.
     ///
     /// This forcibly disables transaction ID regeneration.
+    ///
+    /// # Panics
+    /// - If a signer added with [`sign_with_signer`](Self::sign_with_signer) can't sign synchronously,
+    ///   use [`try_add_signature`](Self::try_add_signature) for transactions that might have one.
     pub fn add_signature(&mut self, pk: PublicKey, signature: Vec<u8>) -> &mut Self {
-        self.add_signature_signer(&AnySigner::arbitrary(Box::new(pk), move |_| signature.clone()));
+        self.try_add_signature(pk, signature)
+            .expect("a signer of the transaction can't sign synchronously, use `try_add_signature`")
+    }
 
-        self
+    /// Adds a signature directly to `self`, see [`add_signature`](Self::add_signature).
+    ///
+    /// # Errors
+    /// - [`Error::SignerNotReady`] if a signer added with [`sign_with_signer`](Self::sign_with_signer)
+    ///   can't sign synchronously.
+    pub fn try_add_signature(
+        &mut self,
+        pk: PublicKey,
+        signature: Vec<u8>,
+    ) -> crate::Result<&mut Self> {
+        self.add_signature_signer(&AnySigner::arbitrary(Box::new(pk), move |_| signature.clone()))?;
+
+        Ok(self)
     }
 
     /// # Panics
@@ -690,6 +794,117 @@ impl<D: TransactionExecute> Transaction<D> {
 
         Ok(iter.collect())
     }
+
+    /// Returns the signatures on this transaction, by node account ID, then by transaction ID (one per chunk),
+    /// then by the public key that made them.
+    ///
+    /// Note: Calling this function _disables_ transaction ID regeneration.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    /// - [`Error::SignerNotReady`] if a signer can't sign synchronously.
+    /// - [`Error::FromProtobuf`] if a signature wasn't made by this SDK and its public key can't be determined.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn get_signatures(
+        &mut self,
+    ) -> crate::Result<HashMap<AccountId, HashMap<TransactionId, HashMap<PublicKey, Vec<u8>>>>>
+    {
+        assert!(self.is_frozen(), "Transaction must be frozen before calling `get_signatures`");
+
+        let sources = self.make_sources()?;
+
+        let sources = match sources {
+            Cow::Borrowed(it) => it,
+            Cow::Owned(it) => &*self.sources.insert(it),
+        };
+
+        let mut signatures: HashMap<_, HashMap<_, HashMap<_, _>>> = HashMap::new();
+
+        for (node_account_id, transaction_id, sig_pair) in sources.signatures() {
+            let node_signatures =
+                signatures.entry(node_account_id).or_default().entry(transaction_id).or_default();
+
+            for pair in sig_pair {
+                let pair = self::execute::SignaturePair::from_protobuf(pair.clone())?;
+
+                node_signatures.insert(pair.public, pair.signature);
+            }
+        }
+
+        Ok(signatures)
+    }
+
+    /// Removes the signatures made by `public_key` from this transaction, and stops signing with it.
+    ///
+    /// Returns the removed signatures, one per node and chunk, or none if `public_key` didn't sign this transaction.
+    ///
+    /// Note: Calling this function _disables_ transaction ID regeneration.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    /// - [`Error::SignerNotReady`] if a signer can't sign synchronously.
+    /// - [`Error::FromProtobuf`] if a removed signature is of a kind this SDK doesn't make.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn remove_signature(&mut self, public_key: &PublicKey) -> crate::Result<Vec<Vec<u8>>> {
+        assert!(self.is_frozen(), "Transaction must be frozen before calling `remove_signature`");
+
+        let public_key_bytes = public_key.to_bytes_raw();
+
+        let (sources, removed) =
+            self.make_sources()?.remove_signatures(|it| it.pub_key_prefix == public_key_bytes);
+
+        let removed = removed
+            .into_iter()
+            .map(|it| Ok(self::execute::SignaturePair::from_protobuf(it)?.signature))
+            .collect::<crate::Result<_>>()?;
+
+        self.sources = Some(sources);
+        self.signers.retain(|it| it.public_key() != *public_key);
+
+        Ok(removed)
+    }
+
+    /// Removes every signature from this transaction, and stops signing with any of the signers it had.
+    ///
+    /// Returns the removed signatures by the public key that made them, each with one signature per node and chunk.
+    ///
+    /// Note: Calling this function _disables_ transaction ID regeneration.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    /// - [`Error::SignerNotReady`] if a signer can't sign synchronously.
+    /// - [`Error::FromProtobuf`] if a signature wasn't made by this SDK and its public key can't be determined.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn remove_all_signatures(&mut self) -> crate::Result<HashMap<PublicKey, Vec<Vec<u8>>>> {
+        assert!(
+            self.is_frozen(),
+            "Transaction must be frozen before calling `remove_all_signatures`"
+        );
+
+        let (sources, removed) = self.make_sources()?.remove_signatures(|_| true);
+
+        let mut signatures: HashMap<_, Vec<_>> = HashMap::new();
+
+        for pair in removed {
+            let pair = self::execute::SignaturePair::from_protobuf(pair)?;
+
+            signatures.entry(pair.public).or_default().push(pair.signature);
+        }
+
+        self.sources = Some(sources);
+        self.signers.clear();
+
+        Ok(signatures)
+    }
 }
 
 impl<D> Transaction<D>
@@ -706,6 +921,55 @@ where
     pub fn default_max_transaction_fee(&self) -> Hbar {
         self.data().default_max_transaction_fee()
     }
+
+    /// Returns the entities whose keys have to sign this transaction, starting with the payer.
+    ///
+    /// The payer is only known once there's a transaction ID or an operator (from [`freeze_with`](Self::freeze_with)).
+    ///
+    /// This only covers what can be told from the transaction itself:
+    /// for instance, a receiver of a transfer that requires receiver signatures isn't included.
+    /// Some signers aren't covered at all:
+    /// - the current admin key of a node, for a `NodeUpdateTransaction` or `NodeDeleteTransaction`,
+    ///   since there's no query to look it up.
+    /// - the keys a scheduled transaction still needs, for a `ScheduleSignTransaction`,
+    ///   see [`ScheduleInfo::scheduled_transaction`](crate::ScheduleInfo::scheduled_transaction) for those.
+    /// - the privileged accounts that system transactions (such as `FreezeTransaction`) have to be paid by.
+    #[must_use]
+    pub fn required_signers(&self) -> Vec<RequiredSigner> {
+        let payer = self
+            .body
+            .transaction_id
+            .map(|it| it.account_id)
+            .or_else(|| self.body.operator.as_ref().map(|it| it.account_id));
+
+        let mut signers: Vec<_> = payer.map(RequiredSigner::Account).into_iter().collect();
+
+        for signer in self.data().required_signers() {
+            if !signers.contains(&signer) {
+                signers.push(signer);
+            }
+        }
+
+        signers
+    }
+
+    /// Returns the [required signers](Self::required_signers) along with their keys, which are looked up on the network.
+    ///
+    /// A signer's key is `None` when it refers to a key that isn't set, see [`RequiredSigner::resolve`].
+    ///
+    /// # Errors
+    /// - If looking up any of the keys fails.
+    pub async fn resolve_required_signers(
+        &self,
+        client: &Client,
+    ) -> crate::Result<Vec<(RequiredSigner, Option<Key>)>> {
+        let signers = self.required_signers();
+
+        let keys =
+            futures_util::future::try_join_all(signers.iter().map(|it| it.resolve(client))).await?;
+
+        Ok(signers.into_iter().zip(keys).collect())
+    }
 }
 
 impl<D> Transaction<D>
@@ -751,6 +1015,7 @@ where
 
         if let Some(sources) = self.sources() {
             return self::execute::SourceTransaction::new(self, sources)
+                .await?
                 .execute(client, timeout)
                 .await;
         }
@@ -871,6 +1136,7 @@ where
         // fixme: dedup this with `execute_with_optional_timeout`
         if let Some(sources) = self.sources() {
             return self::execute::SourceTransaction::new(self, sources)
+                .await?
                 .execute_all(client, timeout_per_chunk)
                 .await;
         }
@@ -968,6 +1234,31 @@ impl AnyTransaction {
     }
 }
 
+/// Combines copies of the same transaction, each encoded with [`Transaction::to_bytes`], into one transaction with all of their signatures.
+///
+/// See [`Transaction::merge`].
+///
+/// # Errors
+/// - [`Error::FromProtobuf`] if any of the blobs isn't a valid transaction.
+/// - [`Error::TransactionMerge`] if there are no blobs, or if they can't be merged.
+pub fn merge_transaction_bytes<I>(blobs: I) -> crate::Result<AnyTransaction>
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    let mut blobs = blobs.into_iter();
+
+    let first = blobs.next().ok_or_else(|| Error::transaction_merge("no transactions to merge"))?;
+
+    let mut transaction = AnyTransaction::from_bytes(first.as_ref())?;
+
+    for blob in blobs {
+        transaction.merge(&AnyTransaction::from_bytes(blob.as_ref())?)?;
+    }
+
+    Ok(transaction)
+}
+
 /// Returns `true` if lhs == rhs other than `transaction_id` and `node_account_id`, `false` otherwise.
 #[allow(deprecated)]
 fn pb_transaction_body_eq(
@@ -1080,6 +1371,7 @@ where
             is_frozen,
             regenerate_transaction_id,
             custom_fee_limits,
+            request_observer,
         } = body;
 
         // not a `map().map_err()` because ownership.
@@ -1096,6 +1388,7 @@ where
                     is_frozen,
                     regenerate_transaction_id,
                     custom_fee_limits,
+                    request_observer,
                 },
                 signers,
                 sources,
@@ -1113,6 +1406,7 @@ where
                     is_frozen,
                     regenerate_transaction_id,
                     custom_fee_limits,
+                    request_observer,
                 },
                 signers,
                 sources,
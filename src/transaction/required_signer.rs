@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    AccountId,
+    AccountInfoQuery,
+    Client,
+    ContractId,
+    ContractInfoQuery,
+    FileId,
+    FileInfoQuery,
+    Key,
+    ScheduleId,
+    ScheduleInfoQuery,
+    TokenId,
+    TokenInfoQuery,
+    TopicId,
+    TopicInfoQuery,
+};
+
+/// Something whose key has to sign a transaction, see [`Transaction::required_signers`](crate::Transaction::required_signers).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RequiredSigner {
+    /// The key of an account.
+    Account(AccountId),
+
+    /// A key that's part of the transaction itself, for instance the new key of an account.
+    Key(Key),
+
+    /// One of the keys of a token.
+    Token(TokenId, TokenKeyKind),
+
+    /// One of the keys of a topic.
+    Topic(TopicId, TopicKeyKind),
+
+    /// The keys of a file.
+    ///
+    /// All of them have to sign to change the file, but any one of them is enough to delete it.
+    File(FileId),
+
+    /// The admin key of a contract.
+    Contract(ContractId),
+
+    /// The admin key of a schedule.
+    Schedule(ScheduleId),
+}
+
+/// Which of a token's keys a [`RequiredSigner::Token`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TokenKeyKind {
+    /// The admin key.
+    Admin,
+
+    /// The KYC key.
+    Kyc,
+
+    /// The freeze key.
+    Freeze,
+
+    /// The wipe key.
+    Wipe,
+
+    /// The supply key.
+    Supply,
+
+    /// The fee schedule key.
+    FeeSchedule,
+
+    /// The pause key.
+    Pause,
+
+    /// The metadata key.
+    Metadata,
+}
+
+/// Which of a topic's keys a [`RequiredSigner::Topic`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TopicKeyKind {
+    /// The admin key.
+    Admin,
+
+    /// The submit key.
+    Submit,
+}
+
+impl RequiredSigner {
+    /// Looks up the key this refers to.
+    ///
+    /// Returns `None` if it refers to a key that isn't set, such as the supply key of a token without one.
+    ///
+    /// # Errors
+    /// - If the info query for the entity this refers to fails, such as the [`AccountInfoQuery`] of an account.
+    pub async fn resolve(&self, client: &Client) -> crate::Result<Option<Key>> {
+        match self {
+            Self::Account(account_id) => {
+                let info = AccountInfoQuery::new().account_id(*account_id).execute(client).await?;
+
+                Ok(Some(info.key))
+            }
+
+            Self::Key(key) => Ok(Some(key.clone())),
+
+            Self::Token(token_id, kind) => {
+                let info = TokenInfoQuery::new().token_id(*token_id).execute(client).await?;
+
+                Ok(match kind {
+                    TokenKeyKind::Admin => info.admin_key,
+                    TokenKeyKind::Kyc => info.kyc_key,
+                    TokenKeyKind::Freeze => info.freeze_key,
+                    TokenKeyKind::Wipe => info.wipe_key,
+                    TokenKeyKind::Supply => info.supply_key,
+                    TokenKeyKind::FeeSchedule => info.fee_schedule_key,
+                    TokenKeyKind::Pause => info.pause_key,
+                    TokenKeyKind::Metadata => info.metadata_key,
+                })
+            }
+
+            Self::Topic(topic_id, kind) => {
+                let info = TopicInfoQuery::new().topic_id(*topic_id).execute(client).await?;
+
+                Ok(match kind {
+                    TopicKeyKind::Admin => info.admin_key,
+                    TopicKeyKind::Submit => info.submit_key,
+                })
+            }
+
+            Self::File(file_id) => {
+                let info = FileInfoQuery::new().file_id(*file_id).execute(client).await?;
+
+                Ok(Some(Key::KeyList(info.keys)))
+            }
+
+            Self::Contract(contract_id) => {
+                let info =
+                    ContractInfoQuery::new().contract_id(*contract_id).execute(client).await?;
+
+                Ok(info.admin_key)
+            }
+
+            Self::Schedule(schedule_id) => {
+                let info =
+                    ScheduleInfoQuery::new().schedule_id(*schedule_id).execute(client).await?;
+
+                Ok(info.admin_key)
+            }
+        }
+    }
+}
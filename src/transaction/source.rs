@@ -209,47 +209,169 @@ impl TransactionSources {
         })
     }
 
-    pub(crate) fn sign_with(&self, signers: &[AnySigner]) -> Cow<'_, Self> {
-        if signers.is_empty() {
-            return Cow::Borrowed(self);
-        }
+    pub(crate) async fn sign_with(&self, signers: &[AnySigner]) -> crate::Result<Cow<'_, Self>> {
+        // we need the first signed transaction for its signature list so that we know if we need to skip a given signer.
+        let existing = self.signed_transactions.first().and_then(|it| it.sig_map.as_ref());
 
-        let mut signed_transactions = Cow::Borrowed(&self.signed_transactions);
+        let mut new_signers: Vec<&AnySigner> = Vec::with_capacity(signers.len());
 
         for signer in signers {
-            let pk = signer.public_key().to_bytes_raw();
-
-            // we need the first signed transaction for its signature list so that we know if we need to skip a given signer.
-            if signed_transactions
-                .first()
-                .as_ref()
-                .and_then(|it| it.sig_map.as_ref())
-                .map_or(false, |it| it.sig_pair.iter().any(|it| pk.starts_with(&it.pub_key_prefix)))
+            let pk = signer.public_key();
+            let pk_bytes = pk.to_bytes_raw();
+
+            if existing.map_or(false, |it| {
+                it.sig_pair.iter().any(|it| pk_bytes.starts_with(&it.pub_key_prefix))
+            }) || new_signers.iter().any(|it| it.public_key() == pk)
             {
                 continue;
             }
 
-            for tx in signed_transactions.to_mut().iter_mut() {
-                let sig_map = tx.sig_map.get_or_insert_with(services::SignatureMap::default);
+            new_signers.push(signer);
+        }
+
+        // if there's nothing to sign with then no signatures would be added (all signers are duplicates).
+        if new_signers.is_empty() {
+            return Ok(Cow::Borrowed(self));
+        }
+
+        // every (node, chunk) has its own body, and remote signers can be slow, so sign them all concurrently.
+        let signatures =
+            futures_util::future::try_join_all(self.signed_transactions.iter().map(|tx| {
+                futures_util::future::try_join_all(
+                    new_signers.iter().map(|signer| signer.sign(&tx.body_bytes)),
+                )
+            }))
+            .await?;
+
+        let mut signed_transactions = self.signed_transactions.clone();
+
+        for (tx, signatures) in signed_transactions.iter_mut().zip(signatures) {
+            let sig_map = tx.sig_map.get_or_insert_with(services::SignatureMap::default);
+
+            sig_map.sig_pair.extend(signatures.into_iter().map(|it| {
                 // todo: reuse `pk_bytes` instead of re-serializing them.
-                let sig_pair = super::execute::SignaturePair::from(signer.sign(&tx.body_bytes));
+                super::execute::SignaturePair::from(it).into_protobuf()
+            }));
+        }
+
+        Ok(Cow::Owned(Self {
+            signed_transactions,
+            transactions: OnceCell::new(),
+            chunks: self.chunks.clone(),
+            transaction_ids: self.transaction_ids.clone(),
+            node_ids: self.node_ids.clone(),
+            // the hashes cover the signatures, so they have to be recomputed.
+            transaction_hashes: OnceCell::new(),
+        }))
+    }
+
+    /// Returns a copy of `self` with the signatures of both `self` and `other`,
+    /// which has to contain the exact same transaction bodies.
+    pub(crate) fn merge(&self, other: &Self) -> crate::Result<Self> {
+        if self.chunks != other.chunks
+            || self.transaction_ids != other.transaction_ids
+            || self.node_ids != other.node_ids
+        {
+            return Err(Error::transaction_merge(
+                "the transactions have different transaction IDs or node account IDs",
+            ));
+        }
+
+        let decode = |it: &services::SignedTransaction| {
+            services::TransactionBody::decode(it.body_bytes.as_slice())
+                .map_err(Error::from_protobuf)
+        };
+
+        // `chunks` is never empty, so neither are the signed transactions.
+        if !super::pb_transaction_body_eq(
+            &decode(&self.signed_transactions[0])?,
+            &decode(&other.signed_transactions[0])?,
+        ) {
+            return Err(Error::transaction_merge("the transactions have different bodies"));
+        }
+
+        let mut signed_transactions = self.signed_transactions.clone();
+
+        for (index, (tx, other)) in
+            signed_transactions.iter_mut().zip(other.signed_transactions.iter()).enumerate()
+        {
+            // signatures are over the exact bytes, so bodies that are merely equivalent aren't good enough.
+            if tx.body_bytes != other.body_bytes {
+                let chunk = self.chunks.iter().position(|it| it.contains(&index)).unwrap();
+                let node_id = self.node_ids[index - self.chunks[chunk].start];
+
+                return Err(Error::transaction_merge(format!(
+                    "the transaction bodies for node `{node_id}` in chunk {chunk} differ"
+                )));
+            }
+
+            let sig_map = tx.sig_map.get_or_insert_with(services::SignatureMap::default);
 
-                sig_map.sig_pair.push(sig_pair.into_protobuf());
+            for pair in other.sig_map.iter().flat_map(|it| &it.sig_pair) {
+                match sig_map.sig_pair.iter().find(|it| it.pub_key_prefix == pair.pub_key_prefix) {
+                    Some(existing) if existing.signature != pair.signature => {
+                        return Err(Error::transaction_merge(format!(
+                            "the transactions have different signatures for the key `{}`",
+                            hex::encode(&pair.pub_key_prefix)
+                        )));
+                    }
+                    Some(_) => {}
+                    None => sig_map.sig_pair.push(pair.clone()),
+                }
             }
         }
 
-        match signed_transactions {
-            // if it's still borrowed then no signatures have been added (all signers are duplicates).
-            Cow::Borrowed(_) => Cow::Borrowed(self),
-            Cow::Owned(signed_transactions) => Cow::Owned(Self {
-                signed_transactions,
-                transactions: OnceCell::new(),
-                chunks: self.chunks.clone(),
-                transaction_ids: self.transaction_ids.clone(),
-                node_ids: self.node_ids.clone(),
-                transaction_hashes: self.transaction_hashes.clone(),
-            }),
+        Ok(Self {
+            signed_transactions,
+            transactions: OnceCell::new(),
+            chunks: self.chunks.clone(),
+            transaction_ids: self.transaction_ids.clone(),
+            node_ids: self.node_ids.clone(),
+            transaction_hashes: OnceCell::new(),
+        })
+    }
+
+    /// Returns a copy of `self` without the signatures that `remove` returns `true` for, and the removed signatures.
+    pub(crate) fn remove_signatures(
+        &self,
+        mut remove: impl FnMut(&services::SignaturePair) -> bool,
+    ) -> (Self, Vec<services::SignaturePair>) {
+        let mut signed_transactions = self.signed_transactions.clone();
+        let mut removed = Vec::new();
+
+        for sig_map in signed_transactions.iter_mut().filter_map(|it| it.sig_map.as_mut()) {
+            let (matching, rest): (Vec<_>, Vec<_>) =
+                std::mem::take(&mut sig_map.sig_pair).into_iter().partition(&mut remove);
+
+            sig_map.sig_pair = rest;
+            removed.extend(matching);
         }
+
+        let sources = Self {
+            signed_transactions,
+            transactions: OnceCell::new(),
+            chunks: self.chunks.clone(),
+            transaction_ids: self.transaction_ids.clone(),
+            node_ids: self.node_ids.clone(),
+            transaction_hashes: OnceCell::new(),
+        };
+
+        (sources, removed)
+    }
+
+    /// Returns the node account ID, transaction ID and signatures of every signed transaction.
+    pub(crate) fn signatures(
+        &self,
+    ) -> impl Iterator<Item = (AccountId, TransactionId, &[services::SignaturePair])> {
+        self.chunks().flat_map(|chunk| {
+            let transaction_id = chunk.transaction_id();
+
+            chunk.node_ids().iter().zip(chunk.signed_transactions()).map(move |(node_id, it)| {
+                let sig_pair = it.sig_map.as_ref().map_or(&[][..], |it| it.sig_pair.as_slice());
+
+                (*node_id, transaction_id, sig_pair)
+            })
+        })
     }
 
     pub(crate) fn transactions(&self) -> &[services::Transaction] {
@@ -1,15 +1,38 @@
-use std::collections::HashSet;
+use std::collections::{
+    HashMap,
+    HashSet,
+};
 
 use assert_matches::assert_matches;
+use futures_core::future::BoxFuture;
 use hex_literal::hex;
 use time::OffsetDateTime;
 
 use crate::transaction::AnyTransactionData;
 use crate::{
+    merge_transaction_bytes,
+    AccountId,
+    AccountUpdateTransaction,
     AnyTransaction,
     Client,
+    ContractDeleteTransaction,
+    ContractId,
+    Error,
+    FileId,
+    FileUpdateTransaction,
     Hbar,
+    KeyList,
+    NftId,
+    PayerSelectionStrategy,
     PrivateKey,
+    PublicKey,
+    RequiredSigner,
+    ScheduleDeleteTransaction,
+    ScheduleId,
+    Signer,
+    TokenId,
+    TokenKeyKind,
+    TokenMintTransaction,
     TopicMessageSubmitTransaction,
     TransactionId,
     TransferTransaction,
@@ -119,3 +142,326 @@ async fn chunked_to_from_bytes() -> crate::Result<()> {
 
     Ok(())
 }
+
+/// A signer that has to wait before it can sign, like a remote signing service would.
+struct YieldingSigner(PrivateKey);
+
+impl Signer for YieldingSigner {
+    fn public_key(&self) -> PublicKey {
+        self.0.public_key()
+    }
+
+    fn sign<'a>(
+        &'a self,
+        message: &'a [u8],
+    ) -> BoxFuture<'a, Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>>> {
+        Box::pin(async move {
+            tokio::task::yield_now().await;
+            Ok(self.0.sign(message))
+        })
+    }
+}
+
+/// A frozen transfer of `amount` hbar from `0.0.101` to `0.0.2`.
+fn frozen_transfer(
+    amount: i64,
+    transaction_id: TransactionId,
+    node_account_ids: &[AccountId],
+) -> crate::Result<TransferTransaction> {
+    let mut tx = TransferTransaction::new();
+
+    tx.hbar_transfer(2.into(), Hbar::new(amount))
+        .hbar_transfer(101.into(), Hbar::new(-amount))
+        .transaction_id(transaction_id)
+        .node_account_ids(node_account_ids.iter().copied())
+        .freeze()?;
+
+    Ok(tx)
+}
+
+#[test]
+fn sign_with_ready_signer_to_bytes() -> crate::Result<()> {
+    let key = PrivateKey::generate_ed25519();
+
+    let bytes = frozen_transfer(2, TransactionId::generate(101.into()), &[6.into(), 7.into()])?
+        .sign_with_signer(key.clone())
+        .to_bytes()?;
+
+    let mut tx2 = AnyTransaction::from_bytes(&bytes)?;
+
+    key.public_key().verify_transaction(&mut tx2)?;
+
+    Ok(())
+}
+
+#[test]
+fn sign_with_pending_signer_to_bytes() -> crate::Result<()> {
+    let bytes = frozen_transfer(2, TransactionId::generate(101.into()), &[6.into(), 7.into()])?
+        .sign_with_signer(YieldingSigner(PrivateKey::generate_ed25519()))
+        .to_bytes();
+
+    assert_matches!(bytes, Err(Error::SignerNotReady));
+
+    Ok(())
+}
+
+#[test]
+fn add_signature_with_pending_signer() -> crate::Result<()> {
+    let key = PrivateKey::generate_ed25519();
+
+    let mut tx = frozen_transfer(2, TransactionId::generate(101.into()), &[6.into()])?;
+    tx.sign_with_signer(YieldingSigner(PrivateKey::generate_ed25519()));
+
+    assert_matches!(key.sign_transaction(&mut tx), Err(Error::SignerNotReady));
+    assert_matches!(
+        tx.try_add_signature(key.public_key(), vec![0; 64]),
+        Err(Error::SignerNotReady)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sources_sign_with_async_signer() -> crate::Result<()> {
+    let key = PrivateKey::generate_ed25519();
+
+    let bytes = frozen_transfer(2, TransactionId::generate(101.into()), &[6.into(), 7.into()])?
+        .to_bytes()?;
+
+    let mut tx = AnyTransaction::from_bytes(&bytes)?;
+    tx.sign_with_signer(YieldingSigner(key.clone()));
+
+    let sources = tx.sources().unwrap().sign_with(&tx.signers).await?;
+
+    // one body per node, every one of them signed.
+    assert_eq!(sources.signed_transactions().len(), 2);
+
+    for signed_transaction in sources.signed_transactions() {
+        let sig_pair = &signed_transaction.sig_map.as_ref().unwrap().sig_pair;
+
+        assert_eq!(sig_pair.len(), 1);
+        assert_eq!(sig_pair[0].pub_key_prefix, key.public_key().to_bytes_raw());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn merge_signed_copies() -> crate::Result<()> {
+    let (key1, key2) = (PrivateKey::generate_ed25519(), PrivateKey::generate_ecdsa());
+
+    let bytes = frozen_transfer(2, TransactionId::generate(101.into()), &[6.into(), 7.into()])?
+        .to_bytes()?;
+
+    let bytes1 = AnyTransaction::from_bytes(&bytes)?.sign(key1.clone()).to_bytes()?;
+    let bytes2 = AnyTransaction::from_bytes(&bytes)?.sign(key2.clone()).to_bytes()?;
+
+    // merging a copy that's already included changes nothing.
+    let mut tx = merge_transaction_bytes([&bytes1, &bytes2, &bytes1, &bytes])?;
+
+    for signed_transaction in tx.sources().unwrap().signed_transactions() {
+        assert_eq!(signed_transaction.sig_map.as_ref().unwrap().sig_pair.len(), 2);
+    }
+
+    key1.public_key().verify_transaction(&mut tx)?;
+    key2.public_key().verify_transaction(&mut tx)?;
+
+    Ok(())
+}
+
+#[test]
+fn merge_different_transactions() -> crate::Result<()> {
+    let transaction_id = TransactionId::generate(101.into());
+    let nodes = [6.into(), 7.into()];
+
+    let mut tx = frozen_transfer(2, transaction_id, &nodes)?;
+
+    let other = frozen_transfer(2, TransactionId::generate(101.into()), &nodes)?;
+    assert_matches!(tx.merge(&other), Err(Error::TransactionMerge(_)));
+
+    let other = frozen_transfer(2, transaction_id, &nodes[..1])?;
+    assert_matches!(tx.merge(&other), Err(Error::TransactionMerge(_)));
+
+    let other = frozen_transfer(3, transaction_id, &nodes)?;
+    assert_matches!(tx.merge(&other), Err(Error::TransactionMerge(_)));
+
+    assert_matches!(
+        merge_transaction_bytes(Vec::<Vec<u8>>::new()),
+        Err(Error::TransactionMerge(_))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn merge_conflicting_signatures() -> crate::Result<()> {
+    let key = PrivateKey::generate_ed25519();
+
+    let bytes = frozen_transfer(2, TransactionId::generate(101.into()), &[6.into()])?.to_bytes()?;
+
+    let mut tx = AnyTransaction::from_bytes(&bytes)?;
+    tx.sign(key.clone());
+
+    let mut other = AnyTransaction::from_bytes(&bytes)?;
+    other.add_signature(key.public_key(), vec![0; 64]);
+
+    assert_matches!(tx.merge(&other), Err(Error::TransactionMerge(_)));
+
+    Ok(())
+}
+
+#[test]
+fn get_and_remove_signatures() -> crate::Result<()> {
+    let (key1, key2) = (PrivateKey::generate_ed25519(), PrivateKey::generate_ecdsa());
+
+    let mut tx = frozen_transfer(2, TransactionId::generate(101.into()), &[6.into(), 7.into()])?;
+    tx.sign(key1.clone()).sign(key2.clone());
+
+    let transaction_id = tx.get_transaction_id().unwrap();
+
+    let signatures = tx.get_signatures()?;
+
+    assert_eq!(signatures.len(), 2);
+
+    for node_account_id in [AccountId::from(6), AccountId::from(7)] {
+        let node_signatures = &signatures[&node_account_id][&transaction_id];
+
+        assert_eq!(node_signatures.len(), 2);
+        assert!(node_signatures.contains_key(&key1.public_key()));
+        assert!(node_signatures.contains_key(&key2.public_key()));
+    }
+
+    assert_eq!(tx.remove_signature(&key1.public_key())?.len(), 2);
+    assert!(tx.remove_signature(&key1.public_key())?.is_empty());
+
+    // the removed signer doesn't come back when the transaction is serialized.
+    let mut tx2 = AnyTransaction::from_bytes(&tx.to_bytes()?)?;
+    let signatures = tx2.get_signatures()?;
+
+    assert_eq!(
+        signatures[&AccountId::from(6)][&transaction_id].keys().collect::<Vec<_>>(),
+        [&key2.public_key()]
+    );
+
+    let removed = tx.remove_all_signatures()?;
+
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed[&key2.public_key()].len(), 2);
+    assert!(tx.get_signatures()?.values().flat_map(HashMap::values).all(HashMap::is_empty));
+
+    Ok(())
+}
+
+#[test]
+fn required_signers() -> crate::Result<()> {
+    let payer = AccountId::new(0, 0, 101);
+    let key = PrivateKey::generate_ed25519().public_key();
+
+    let mut tx = AccountUpdateTransaction::new();
+    tx.account_id(AccountId::new(0, 0, 1001)).key(key);
+
+    // without a transaction ID (or operator) there's no payer yet.
+    assert_eq!(
+        tx.required_signers(),
+        [RequiredSigner::Account(AccountId::new(0, 0, 1001)), RequiredSigner::Key(key.into())]
+    );
+
+    let mut tx = TokenMintTransaction::new();
+    tx.token_id(TokenId::new(0, 0, 5005)).amount(10).transaction_id(TransactionId::generate(payer));
+
+    assert_eq!(
+        tx.required_signers(),
+        [
+            RequiredSigner::Account(payer),
+            RequiredSigner::Token(TokenId::new(0, 0, 5005), TokenKeyKind::Supply)
+        ]
+    );
+
+    // only senders sign, and the payer is only listed once.
+    let mut tx = TransferTransaction::new();
+    tx.hbar_transfer(payer, Hbar::new(-2))
+        .hbar_transfer(AccountId::new(0, 0, 1002), Hbar::new(-1))
+        .hbar_transfer(AccountId::new(0, 0, 1003), Hbar::new(3))
+        .approved_hbar_transfer(AccountId::new(0, 0, 1004), Hbar::new(-1))
+        .hbar_transfer(AccountId::new(0, 0, 1005), Hbar::new(1))
+        .nft_transfer(
+            NftId::from((TokenId::new(0, 0, 5005), 1)),
+            AccountId::new(0, 0, 1006),
+            AccountId::new(0, 0, 1007),
+        )
+        .transaction_id(TransactionId::generate(payer))
+        .node_account_ids([6.into()])
+        .freeze()?;
+
+    let expected = [
+        RequiredSigner::Account(payer),
+        RequiredSigner::Account(AccountId::new(0, 0, 1002)),
+        RequiredSigner::Account(AccountId::new(0, 0, 1006)),
+    ];
+
+    assert_eq!(tx.required_signers(), expected);
+
+    let tx = AnyTransaction::from_bytes(&tx.to_bytes()?)?;
+    assert_eq!(tx.required_signers().into_iter().collect::<HashSet<_>>(), HashSet::from(expected));
+
+    // the new keys of a file have to sign along with the old ones.
+    let mut tx = FileUpdateTransaction::new();
+    tx.file_id(FileId::new(0, 0, 150)).keys([key]);
+
+    assert_eq!(
+        tx.required_signers(),
+        [
+            RequiredSigner::File(FileId::new(0, 0, 150)),
+            RequiredSigner::Key(KeyList::from([key]).into())
+        ]
+    );
+
+    let mut tx = ContractDeleteTransaction::new();
+    tx.contract_id(ContractId::new(0, 0, 5006)).transfer_account_id(payer);
+
+    assert_eq!(tx.required_signers(), [RequiredSigner::Contract(ContractId::new(0, 0, 5006))]);
+
+    let mut tx = ScheduleDeleteTransaction::new();
+    tx.schedule_id(ScheduleId::new(0, 0, 5007));
+
+    assert_eq!(tx.required_signers(), [RequiredSigner::Schedule(ScheduleId::new(0, 0, 5007))]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sign_with_operator_rotates_payers() -> crate::Result<()> {
+    let client = Client::for_network(HashMap::from([(
+        "127.0.0.1:50211".to_owned(),
+        AccountId::new(0, 0, 3),
+    )]))?;
+
+    let payers = [AccountId::new(0, 0, 1001), AccountId::new(0, 0, 1002)];
+
+    client.set_operator_pool(
+        payers.map(|it| (it, Box::new(PrivateKey::generate_ed25519()) as Box<dyn Signer>)),
+        PayerSelectionStrategy::RoundRobin,
+    );
+
+    let payer = |_| -> crate::Result<AccountId> {
+        let mut tx = TransferTransaction::new();
+        tx.sign_with_operator(&client)?;
+
+        Ok(tx.body.operator.as_ref().unwrap().account_id)
+    };
+
+    assert_eq!(
+        (0..4).map(payer).collect::<crate::Result<Vec<_>>>()?,
+        [payers[0], payers[1], payers[0], payers[1]]
+    );
+
+    // a frozen transaction keeps the payer it was frozen with, and doesn't move the pool along.
+    let mut tx = TransferTransaction::new();
+    tx.freeze_with(&client)?;
+    tx.sign_with_operator(&client)?;
+
+    assert_eq!(tx.body.operator.as_ref().unwrap().account_id, payers[0]);
+    assert_eq!(payer(0)?, payers[1]);
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
 use std::fmt::{
     self,
     Debug,
@@ -9,6 +10,7 @@ use std::fmt::{
 use std::str::FromStr;
 
 use hedera_proto::services;
+use parking_lot::Mutex;
 use rand::{
     thread_rng,
     Rng,
@@ -56,8 +58,13 @@ impl TransactionId {
     /// Generates a new transaction ID for the given account ID.
     #[must_use]
     pub fn generate(account_id: AccountId) -> Self {
-        let valid_start = OffsetDateTime::now_utc()
-            - Duration::nanoseconds(thread_rng().gen_range(5_000_000_000..8_000_000_000));
+        Self::generate_at(account_id, OffsetDateTime::now_utc())
+    }
+
+    /// Generates a new transaction ID for the given account ID, as if the current time was `now`.
+    pub(crate) fn generate_at(account_id: AccountId, now: OffsetDateTime) -> Self {
+        let valid_start =
+            now - Duration::nanoseconds(thread_rng().gen_range(5_000_000_000..8_000_000_000));
 
         Self { account_id, valid_start, scheduled: false, nonce: None }
     }
@@ -78,6 +85,66 @@ impl TransactionId {
     }
 }
 
+/// Generates transaction IDs whose valid starts strictly increase for each payer.
+///
+/// [`TransactionId::generate`] picks a random valid start, so two IDs generated at the same time for the same payer can collide,
+/// in which case the second transaction fails with [`Status::DuplicateTransaction`](crate::Status::DuplicateTransaction).
+/// A generator bumps the valid start by a nanosecond whenever it would otherwise not be after the last one it handed out for that payer.
+///
+/// Every [`Client`](crate::Client) has a generator that it uses for the transactions it executes,
+/// see [`Client::transaction_id_generator`](crate::Client::transaction_id_generator).
+/// IDs generated ahead of time (for instance, to sign transactions offline) are only unique among IDs from the same generator.
+#[derive(Debug, Default)]
+pub struct TransactionIdGenerator {
+    last_valid_starts: Mutex<HashMap<AccountId, OffsetDateTime>>,
+}
+
+impl TransactionIdGenerator {
+    /// Create a new generator.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generates a new transaction ID for the given account ID.
+    #[must_use]
+    pub fn generate(&self, account_id: AccountId) -> TransactionId {
+        self.generate_at(account_id, OffsetDateTime::now_utc())
+    }
+
+    /// Generates a new transaction ID for the given account ID, as if the current time was `now`.
+    pub(crate) fn generate_at(&self, account_id: AccountId, now: OffsetDateTime) -> TransactionId {
+        let mut transaction_id = TransactionId::generate_at(account_id, now);
+
+        let mut last_valid_starts = self.last_valid_starts.lock();
+        let last_valid_start =
+            last_valid_starts.entry(account_id).or_insert(OffsetDateTime::UNIX_EPOCH);
+
+        if transaction_id.valid_start <= *last_valid_start {
+            transaction_id.valid_start = *last_valid_start + Duration::nanoseconds(1);
+        }
+
+        *last_valid_start = transaction_id.valid_start;
+
+        transaction_id
+    }
+
+    /// Forgets the valid starts handed out for `account_id` from `rejected` onward,
+    /// after the network rejected `rejected` for being in the future (`INVALID_TRANSACTION_START`).
+    ///
+    /// Otherwise every ID generated after the local clock is corrected would be bumped past `rejected`, into the future again.
+    /// None of the forgotten valid starts were accepted by the network, so they can't be duplicated.
+    pub(crate) fn rewind(&self, account_id: AccountId, rejected: OffsetDateTime) {
+        let mut last_valid_starts = self.last_valid_starts.lock();
+
+        if let Some(last_valid_start) = last_valid_starts.get_mut(&account_id) {
+            if *last_valid_start >= rejected {
+                *last_valid_start = rejected - Duration::nanoseconds(1);
+            }
+        }
+    }
+}
+
 impl ValidateChecksums for TransactionId {
     fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
         self.account_id.validate_checksums(ledger_id)
@@ -194,7 +261,10 @@ mod tests {
 
     use assert_matches::assert_matches;
     use expect_test::expect;
-    use time::OffsetDateTime;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
 
     use crate::protobuf::{
         FromProtobuf,
@@ -203,8 +273,60 @@ mod tests {
     use crate::{
         AccountId,
         TransactionId,
+        TransactionIdGenerator,
     };
 
+    #[test]
+    fn generator_is_strictly_increasing() {
+        let generator = TransactionIdGenerator::new();
+        let now = OffsetDateTime::now_utc();
+
+        let ids: Vec<_> =
+            (0..100).map(|num| generator.generate_at(AccountId::from(num % 2), now)).collect();
+
+        for pair in ids.windows(3) {
+            assert_eq!(pair[0].account_id, pair[2].account_id);
+            assert!(pair[0].valid_start < pair[2].valid_start);
+        }
+    }
+
+    #[test]
+    fn generator_rewind() {
+        let generator = TransactionIdGenerator::new();
+        let account_id = AccountId::new(0, 0, 1001);
+        let now = OffsetDateTime::now_utc();
+
+        // the local clock is a minute ahead of the network's.
+        let rejected = generator.generate_at(account_id, now + Duration::minutes(1));
+
+        generator.rewind(account_id, rejected.valid_start);
+
+        let corrected = generator.generate_at(account_id, now);
+        assert!(corrected.valid_start < now);
+    }
+
+    #[test]
+    fn generator_is_unique_across_threads() {
+        let generator = TransactionIdGenerator::new();
+        let account_id = AccountId::new(0, 0, 1001);
+
+        let ids: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| {
+                    scope.spawn(|| {
+                        (0..250).map(|_| generator.generate(account_id)).collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles.into_iter().flat_map(|it| it.join().unwrap()).collect()
+        });
+
+        let valid_starts: std::collections::HashSet<_> =
+            ids.iter().map(|it| it.valid_start).collect();
+        assert_eq!(valid_starts.len(), ids.len());
+    }
+
     #[test]
     fn from_str_wrong_field() {
         assert_matches!(TransactionId::from_str("0.0.31415?1641088801.2"), Err(_));
@@ -40,6 +40,15 @@ pub struct TransactionResponse {
 }
 
 impl TransactionResponse {
+    /// Returns the account that paid for the transaction.
+    ///
+    /// This is the account the transaction ID belongs to,
+    /// so with an operator pool it's whichever payer was picked for this transaction.
+    #[must_use]
+    pub fn payer_account_id(&self) -> AccountId {
+        self.transaction_id.account_id
+    }
+
     /// Whether the receipt/record status should be validated.
     pub fn validate_status(&mut self, validate: bool) -> &mut Self {
         self.validate_status = validate;
@@ -99,7 +108,11 @@ impl TransactionResponse {
     /// - if [`validate_status`](Self.validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
     pub async fn get_record(&self, client: &Client) -> crate::Result<TransactionRecord> {
-        self.get_record_query().execute(client).await
+        let record = self.get_record_query().execute(client).await?;
+
+        client.observe_record(&record);
+
+        Ok(record)
     }
 
     /// Get the record for this transaction.
@@ -113,6 +126,10 @@ impl TransactionResponse {
         client: &Client,
         timeout: std::time::Duration,
     ) -> crate::Result<TransactionRecord> {
-        self.get_record_query().execute_with_timeout(client, timeout).await
+        let record = self.get_record_query().execute_with_timeout(client, timeout).await?;
+
+        client.observe_record(&record);
+
+        Ok(record)
     }
 }
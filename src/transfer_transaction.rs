@@ -23,6 +23,7 @@ use crate::{
     Error,
     Hbar,
     NftId,
+    RequiredSigner,
     ToProtobuf,
     TokenId,
     TokenNftTransfer,
@@ -74,6 +75,28 @@ pub(crate) struct TokenTransfer {
     pub expected_decimals: Option<u32>,
 }
 
+/// Returns the accounts that send hbar, tokens or NFTs in `transfers` and `token_transfers`.
+///
+/// Approved transfers are left out, since those are authorized by the spender (the payer) instead.
+pub(crate) fn senders<'a>(
+    transfers: &'a [Transfer],
+    token_transfers: &'a [TokenTransfer],
+) -> impl Iterator<Item = AccountId> + 'a {
+    let fungible = transfers
+        .iter()
+        .chain(token_transfers.iter().flat_map(|it| &it.transfers))
+        .filter(|it| it.amount < 0 && !it.is_approval)
+        .map(|it| it.account_id);
+
+    let nft = token_transfers
+        .iter()
+        .flat_map(|it| &it.nft_transfers)
+        .filter(|it| !it.is_approved)
+        .map(|it| it.sender);
+
+    fungible.chain(nft)
+}
+
 impl TransferTransaction {
     fn _hbar_transfer(&mut self, account_id: AccountId, amount: Hbar, approved: bool) -> &mut Self {
         self.data_mut().transfers.push(Transfer {
@@ -286,7 +309,11 @@ impl TransactionExecute for TransferTransactionData {
     }
 }
 
-impl TransactionData for TransferTransactionData {}
+impl TransactionData for TransferTransactionData {
+    fn required_signers(&self) -> Vec<RequiredSigner> {
+        senders(&self.transfers, &self.token_transfers).map(RequiredSigner::Account).collect()
+    }
+}
 
 impl ValidateChecksums for TransferTransactionData {
     fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
@@ -1,18 +1,23 @@
 use std::borrow::Cow;
-use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
 
 use anyhow::Context;
 use hedera::{
     AccountId,
     Client,
+    MirrorEndpoint,
     PrivateKey,
 };
 use once_cell::sync::Lazy;
 
 mod keys {
+    /// Every variable [`Client::from_env_with_prefix`] reads, such as `TEST_MAX_ATTEMPTS`, starts with this.
+    pub(super) const PREFIX: &str = "TEST_";
+
     pub(super) const NETWORK: &str = "TEST_NETWORK_NAME";
 
+    pub(super) const NETWORK_NODES: &str = "TEST_NETWORK";
+
     pub(super) const OPERATOR_KEY: &str = "TEST_OPERATOR_KEY";
 
     pub(super) const OPERATOR_ID: &str = "TEST_OPERATOR_ID";
@@ -21,7 +26,6 @@ mod keys {
 }
 
 static CONFIG: Lazy<Config> = Lazy::new(Config::parse_env);
-static DEFAULT_LOCAL_NODE_ADDRESS: &str = "127.0.0.1:50211";
 static DEFAULT_LOCAL_MIRROR_NODE_ADDRESS: &str = "127.0.0.1:5600";
 
 /// Generates a client using the active config.
@@ -30,44 +34,46 @@ static DEFAULT_LOCAL_MIRROR_NODE_ADDRESS: &str = "127.0.0.1:5600";
 fn client() -> Client {
     let config = &*CONFIG;
 
-    let client = match &*config.network_name {
-        "mainnet" => Client::for_mainnet(),
-        "testnet" => Client::for_testnet(),
-        "previewnet" => Client::for_previewnet(),
-        "localhost" => for_local_node(),
-        _ => {
+    let client = Client::from_vars_with_prefix(keys::PREFIX, |name| {
+        dotenvy::var(name).ok().or_else(|| {
+            // default tests to testnet.
+            (name == keys::NETWORK && dotenvy::var(keys::NETWORK_NODES).is_err())
+                .then(|| "testnet".to_owned())
+        })
+    });
+
+    let client = match client {
+        Ok(client) => client,
+        Err(e) => {
             // to ensure we don't spam the logs with `Error creating client: ...`,
             // we just let an arbitrary thread win and log the "error".
             static LOGS_ONCE: AtomicBool = AtomicBool::new(false);
 
             // note: Relaxed is probably fine, AcqRel is *definitely* fine.
             if !LOGS_ONCE.swap(true, std::sync::atomic::Ordering::AcqRel) {
-                log::error!(
-                    "Error creating client: {}; creating one using `testnet`",
-                    &*config.network_name
-                );
+                log::error!("Error creating client: {e}; creating one using `testnet`");
+            }
+
+            let client = Client::for_testnet();
+
+            if let Some(op) = &config.operator {
+                client.set_operator(op.account_id, op.private_key.clone());
             }
 
-            Client::for_testnet()
+            return client;
         }
     };
 
-    if let Some(op) = &config.operator {
-        client.set_operator(op.account_id, op.private_key.clone());
+    if config.is_local {
+        // local node's mirror node doesn't speak TLS.
+        let mut mirror_endpoint = MirrorEndpoint::new(DEFAULT_LOCAL_MIRROR_NODE_ADDRESS);
+        mirror_endpoint.plaintext(true);
+        client.set_mirror_network_endpoints([mirror_endpoint]).unwrap();
     }
 
     client
 }
 
-fn for_local_node() -> Client {
-    let mut network: HashMap<String, AccountId> = HashMap::new();
-    network.insert(DEFAULT_LOCAL_NODE_ADDRESS.to_string(), AccountId::new(0, 0, 3));
-
-    let client = Client::for_network(network).unwrap();
-    client.set_mirror_network([DEFAULT_LOCAL_MIRROR_NODE_ADDRESS.to_string()]);
-    client
-}
-
 #[derive(Clone)]
 pub(crate) struct Operator {
     pub(crate) private_key: PrivateKey,
@@ -75,6 +81,7 @@ pub(crate) struct Operator {
 }
 
 impl Operator {
+    // the client reads these too, but tests sign with the operator's key directly, which the client doesn't hand out.
     fn try_parse_env() -> anyhow::Result<Option<Self>> {
         let key = dotenvy::var(keys::OPERATOR_KEY).ok();
         let account_id = dotenvy::var(keys::OPERATOR_ID).ok();
@@ -154,7 +161,6 @@ impl Config {
 
         let mut is_local = false;
 
-        // default tests to localhost
         let network_name = network_name.map_or_else(|| Cow::Borrowed("testnet"), Cow::Owned);
 
         if network_name == "localhost" {